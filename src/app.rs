@@ -11,10 +11,13 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use typed_builder::*;
 
+use alerts::*;
 use data_farmer::*;
 use data_harvester::{processes, temperature};
 use layout_manager::*;
+use query::ProcessQuery;
 pub use states::*;
+use watches::*;
 
 use crate::{
     canvas, constants,
@@ -26,12 +29,14 @@ use crate::{
     Pid,
 };
 
+pub mod alerts;
 pub mod data_farmer;
 pub mod data_harvester;
 pub mod layout_manager;
 mod process_killer;
 pub mod query;
 pub mod states;
+pub mod watches;
 
 const MAX_SEARCH_LENGTH: usize = 200;
 
@@ -46,6 +51,7 @@ pub enum AxisScaling {
 #[derive(Debug)]
 pub struct AppConfigFields {
     pub update_rate_in_milliseconds: u64,
+    pub collection_intervals: data_harvester::CollectionIntervals,
     pub temperature_type: temperature::TemperatureType,
     pub use_dot: bool,
     pub left_legend: bool,
@@ -62,10 +68,22 @@ pub struct AppConfigFields {
     pub no_write: bool,
     pub show_table_scroll_position: bool,
     pub is_advanced_kill: bool,
+    /// Whether to keep showing processes in the process widget for a few seconds after they exit.
+    pub show_exited_processes: bool,
+    /// Optional process columns that were enabled in the config.
+    pub extra_process_columns: Vec<processes::ProcessSorting>,
+    /// Which sensors to show in the temperature graph; all sensors are shown if this is not set.
+    pub temp_graph_filter: Option<Filter>,
+    /// Temperatures to draw threshold lines at in the temperature graph.
+    pub temp_graph_thresholds: Vec<f64>,
     // TODO: Remove these, move network details state-side.
     pub network_unit_type: DataUnit,
     pub network_scale_type: AxisScaling,
     pub network_use_binary_prefix: bool,
+    /// Where procfs is mounted, read by the Linux collectors.
+    pub proc_root: std::path::PathBuf,
+    /// Where sysfs is mounted, read by the Linux collectors.
+    pub sys_root: std::path::PathBuf,
 }
 
 /// For filtering out information
@@ -115,6 +133,18 @@ pub struct App {
     #[builder(default, setter(skip))]
     pub help_dialog_state: AppHelpDialogState,
 
+    #[builder(default, setter(skip))]
+    pub connections_dialog_state: AppConnectionsDialogState,
+
+    #[builder(default, setter(skip))]
+    pub errors_dialog_state: AppErrorsDialogState,
+
+    #[builder(default, setter(skip))]
+    pub process_events_dialog_state: AppProcessEventsDialogState,
+
+    #[builder(default, setter(skip))]
+    pub watch_events_dialog_state: AppWatchEventsDialogState,
+
     #[builder(default = false, setter(skip))]
     pub is_expanded: bool,
 
@@ -134,18 +164,38 @@ pub struct App {
     #[builder(default, setter(skip))]
     pub user_table: processes::UserTable,
 
+    /// The optional process details that the collection thread was last told to collect.
+    #[cfg(target_os = "linux")]
+    #[builder(default, setter(skip))]
+    pub process_details_to_collect: processes::ProcessDetailsToCollect,
+
+    /// The processes that the collection thread was last told to collect the connections of.
+    #[cfg(target_os = "linux")]
+    #[builder(default, setter(skip))]
+    pub connection_pids: Option<Vec<crate::Pid>>,
+
     pub cpu_state: CpuState,
     pub mem_state: MemState,
     pub net_state: NetState,
     pub proc_state: ProcState,
     pub temp_state: TempState,
     pub disk_state: DiskState,
+    pub socket_state: SocketState,
+    pub pressure_state: GraphState,
+    pub disk_util_state: GraphState,
+    pub disk_io_state: GraphState,
+    pub temp_graph_state: GraphState,
+    pub sensor_state: SensorState,
+    pub net_interface_state: NetInterfaceState,
+    pub cpu_freq_state: GraphState,
+    pub load_avg_state: GraphState,
     pub battery_state: BatteryState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
     pub app_config_fields: AppConfigFields,
     pub widget_map: HashMap<u64, BottomWidget>,
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
+    pub alerts: Alerts,
     pub filters: DataFilters,
     pub config: Config,
     pub config_path: Option<PathBuf>,
@@ -166,6 +216,10 @@ impl App {
         // Reset dialog state
         self.help_dialog_state.is_showing_help = false;
         self.delete_dialog_state.is_showing_dd = false;
+        self.connections_dialog_state.is_showing_connections = false;
+        self.errors_dialog_state.is_showing_errors = false;
+        self.process_events_dialog_state.is_showing_events = false;
+        self.watch_events_dialog_state.is_showing_events = false;
 
         // Close all searches and reset it
         self.proc_state
@@ -175,6 +229,12 @@ impl App {
                 state.process_search_state.search_state.reset();
             });
         self.proc_state.force_update_all = true;
+        self.socket_state
+            .widget_states
+            .values_mut()
+            .for_each(|state| {
+                state.search_state = AppSearchState::default();
+            });
 
         // Clear current delete list
         self.to_delete_process_list = None;
@@ -210,6 +270,24 @@ impl App {
             if self.help_dialog_state.is_showing_help {
                 self.help_dialog_state.is_showing_help = false;
                 self.help_dialog_state.scroll_state.current_scroll_index = 0;
+            } else if self.connections_dialog_state.is_showing_connections {
+                self.connections_dialog_state.is_showing_connections = false;
+                self.connections_dialog_state
+                    .scroll_state
+                    .current_scroll_index = 0;
+            } else if self.errors_dialog_state.is_showing_errors {
+                self.errors_dialog_state.is_showing_errors = false;
+                self.errors_dialog_state.scroll_state.current_scroll_index = 0;
+            } else if self.process_events_dialog_state.is_showing_events {
+                self.process_events_dialog_state.is_showing_events = false;
+                self.process_events_dialog_state
+                    .scroll_state
+                    .current_scroll_index = 0;
+            } else if self.watch_events_dialog_state.is_showing_events {
+                self.watch_events_dialog_state.is_showing_events = false;
+                self.watch_events_dialog_state
+                    .scroll_state
+                    .current_scroll_index = 0;
             } else {
                 self.close_dd();
             }
@@ -234,6 +312,30 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Socket => {
+                    if let Some(socket_widget_state) = self
+                        .socket_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        // Escape clears the filter, whether or not it is still being typed.
+                        if socket_widget_state.search_state.is_enabled
+                            || !socket_widget_state
+                                .search_state
+                                .current_search_query
+                                .is_empty()
+                        {
+                            socket_widget_state.search_state.is_enabled = false;
+                            socket_widget_state
+                                .search_state
+                                .current_search_query
+                                .clear();
+                            socket_widget_state.update_query();
+                            self.socket_state.force_update = Some(self.current_widget.widget_id);
+                            self.is_force_redraw = true;
+                            return;
+                        }
+                    }
+                }
                 BottomWidgetType::ProcSearch => {
                     if let Some(current_proc_state) = self
                         .proc_state
@@ -282,13 +384,30 @@ impl App {
         )
     }
 
+    /// Whether a socket widget is selected and its filter is being typed into.
+    pub fn is_searching_sockets(&self) -> bool {
+        if let BottomWidgetType::Socket = self.current_widget.widget_type {
+            self.socket_state
+                .get_widget_state(self.current_widget.widget_id)
+                .map(|socket_widget_state| socket_widget_state.search_state.is_enabled)
+                .unwrap_or(false)
+        } else {
+            false
+        }
+    }
+
     fn reset_multi_tap_keys(&mut self) {
         self.awaiting_second_char = false;
         self.second_char = None;
     }
 
     fn is_in_dialog(&self) -> bool {
-        self.help_dialog_state.is_showing_help || self.delete_dialog_state.is_showing_dd
+        self.help_dialog_state.is_showing_help
+            || self.delete_dialog_state.is_showing_dd
+            || self.connections_dialog_state.is_showing_connections
+            || self.errors_dialog_state.is_showing_errors
+            || self.process_events_dialog_state.is_showing_events
+            || self.watch_events_dialog_state.is_showing_events
     }
 
     fn ignore_normal_keybinds(&self) -> bool {
@@ -368,6 +487,91 @@ impl App {
         }
     }
 
+    /// Cycles what processes are grouped by, or turns on grouping if it is off.
+    pub fn cycle_grouping_type(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                if proc_widget_state.is_tree_mode {
+                    return;
+                }
+
+                if proc_widget_state.is_grouped {
+                    proc_widget_state.grouping_type = proc_widget_state.grouping_type.next();
+                    self.proc_state.force_update = Some(self.current_widget.widget_id);
+                } else {
+                    self.on_tab();
+                }
+            }
+        }
+    }
+
+    /// Determines which optional process details need to be collected, from the extra process
+    /// columns and what each process widget is grouped by.
+    #[cfg(target_os = "linux")]
+    pub fn get_process_details_to_collect(&self) -> processes::ProcessDetailsToCollect {
+        use crate::data_conversion::ProcessGroupingType;
+        use query::PrefixType;
+
+        let mut process_details_to_collect = processes::ProcessDetailsToCollect::from_columns(
+            &self.app_config_fields.extra_process_columns,
+        );
+
+        // Searches, alerts, and watches can filter on details that no column shows.
+        let queries = self
+            .proc_state
+            .widget_states
+            .values()
+            .filter_map(|proc_widget_state| {
+                proc_widget_state
+                    .process_search_state
+                    .search_state
+                    .query
+                    .as_ref()
+            })
+            .chain(self.alerts.get_process_queries());
+        for query in queries {
+            if query.uses_prefix(&|prefix_type| {
+                matches!(
+                    prefix_type,
+                    PrefixType::Pss | PrefixType::Uss | PrefixType::Shared | PrefixType::Swap
+                )
+            }) {
+                process_details_to_collect.mem_breakdown = true;
+            }
+            if query.uses_prefix(&|prefix_type| {
+                matches!(prefix_type, PrefixType::Fds | PrefixType::PFds)
+            }) {
+                process_details_to_collect.fds = true;
+            }
+        }
+
+        for proc_widget_state in self.proc_state.widget_states.values() {
+            if proc_widget_state.is_grouped && !proc_widget_state.is_tree_mode {
+                match proc_widget_state.grouping_type {
+                    ProcessGroupingType::ExePath => process_details_to_collect.exe_path = true,
+                    ProcessGroupingType::Cgroup => process_details_to_collect.cgroup = true,
+                    _ => {}
+                }
+            }
+        }
+
+        process_details_to_collect
+    }
+
+    /// Determines which processes' connections need to be collected, which is only while the
+    /// connections dialog is open.
+    #[cfg(target_os = "linux")]
+    pub fn get_connection_pids(&self) -> Option<Vec<crate::Pid>> {
+        if self.connections_dialog_state.is_showing_connections {
+            Some(self.connections_dialog_state.pids.clone())
+        } else {
+            None
+        }
+    }
+
     /// I don't like this, but removing it causes a bunch of breakage.
     /// Use ``proc_widget_state.is_grouped`` if possible!
     pub fn is_grouped(&self, widget_id: u64) -> bool {
@@ -398,6 +602,15 @@ impl App {
                         self.is_force_redraw = true;
                     }
                 }
+                BottomWidgetType::Socket => {
+                    if let Some(socket_widget_state) = self
+                        .socket_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        socket_widget_state.search_state.is_enabled = true;
+                        self.is_force_redraw = true;
+                    }
+                }
                 _ => {}
             }
         }
@@ -434,6 +647,15 @@ impl App {
 
                 self.is_force_redraw = true;
             }
+            BottomWidgetType::Socket => {
+                if let Some(socket_widget_state) = self
+                    .socket_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    socket_widget_state.sorting_type = socket_widget_state.sorting_type.next();
+                    self.socket_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
             _ => {}
         }
     }
@@ -455,6 +677,16 @@ impl App {
                     self.proc_state.force_update = Some(widget_id);
                 }
             }
+            BottomWidgetType::Socket => {
+                if let Some(socket_widget_state) = self
+                    .socket_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    socket_widget_state.is_sort_descending =
+                        !socket_widget_state.is_sort_descending;
+                    self.socket_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
             _ => {}
         }
     }
@@ -671,6 +903,32 @@ impl App {
         }
     }
 
+    pub fn toggle_following(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                proc_widget_state.toggle_following();
+                self.proc_state.force_update = Some(self.current_widget.widget_id);
+            }
+        }
+    }
+
+    pub fn toggle_tree_totals(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                if proc_widget_state.is_tree_mode {
+                    proc_widget_state.show_tree_totals = !proc_widget_state.show_tree_totals;
+                    self.proc_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
+        }
+    }
+
     pub fn toggle_tree_mode(&mut self) {
         if let Some(proc_widget_state) = self
             .proc_state
@@ -748,6 +1006,15 @@ impl App {
                     self.proc_state.force_update = Some(self.current_widget.widget_id - 2);
                     self.toggle_sort();
                 }
+            } else if self.is_searching_sockets() {
+                // Stop typing, but keep the filter.
+                if let Some(socket_widget_state) = self
+                    .socket_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    socket_widget_state.search_state.is_enabled = false;
+                    self.is_force_redraw = true;
+                }
             }
         }
     }
@@ -807,7 +1074,22 @@ impl App {
     }
 
     pub fn on_backspace(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
+        if self.is_searching_sockets() {
+            if let Some(socket_widget_state) = self
+                .socket_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                if socket_widget_state
+                    .search_state
+                    .current_search_query
+                    .pop()
+                    .is_some()
+                {
+                    socket_widget_state.update_query();
+                    self.socket_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
+        } else if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
             let is_in_search_widget = self.is_in_search_widget();
             if let Some(proc_widget_state) = self
                 .proc_state
@@ -909,6 +1191,14 @@ impl App {
             self.decrement_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_scroll_up();
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_scroll_up();
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_scroll_up();
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_scroll_up();
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_right_key();
@@ -930,6 +1220,14 @@ impl App {
             self.increment_position_count();
         } else if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_scroll_down();
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_scroll_down();
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_scroll_down();
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_scroll_down();
         } else if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_os = "windows")]
             self.on_left_key();
@@ -1341,6 +1639,10 @@ impl App {
                         if let Some(process) = &corresponding_filtered_process_list
                             .get(proc_widget_state.scroll_state.current_scroll_position)
                         {
+                            // Groups of processes whose key is unknown aren't killed as a whole.
+                            if process.is_disabled_entry {
+                                return;
+                            }
                             current_process = (process.name.to_string(), process.group_pids.clone())
                         } else {
                             return;
@@ -1349,6 +1651,11 @@ impl App {
                         let process = corresponding_filtered_process_list
                             [proc_widget_state.scroll_state.current_scroll_position]
                             .clone();
+
+                        // Its PID may already belong to something else.
+                        if process.is_exited {
+                            return;
+                        }
                         current_process = (process.name.clone(), vec![process.pid])
                     };
 
@@ -1360,6 +1667,121 @@ impl App {
         }
     }
 
+    /// Opens a dialog showing the network connections of the selected process, or of all
+    /// processes in the selected group.
+    pub fn open_connections_dialog(&mut self) {
+        self.reset_multi_tap_keys();
+
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .widget_states
+                .get(&self.current_widget.widget_id)
+            {
+                if let Some(process) = self
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&self.current_widget.widget_id)
+                    .and_then(|processes| {
+                        processes.get(proc_widget_state.scroll_state.current_scroll_position)
+                    })
+                {
+                    let connections_dialog_state = &mut self.connections_dialog_state;
+                    if proc_widget_state.is_grouped {
+                        connections_dialog_state.process_description =
+                            format!("{} ({} processes)", process.name, process.group_pids.len());
+                        connections_dialog_state.pids = process.group_pids.clone();
+                    } else {
+                        connections_dialog_state.process_description =
+                            format!("{} (PID {})", process.name, process.pid);
+                        connections_dialog_state.pids = vec![process.pid];
+                    }
+                    connections_dialog_state.scroll_state.current_scroll_index = 0;
+                    connections_dialog_state.start_collecting();
+                    connections_dialog_state.is_showing_connections = true;
+                    self.is_force_redraw = true;
+                }
+            }
+        }
+    }
+
+    /// Opens a dialog listing recent collector errors, newest first.
+    pub fn open_errors_dialog(&mut self) {
+        self.reset_multi_tap_keys();
+
+        let errors_dialog_state = &mut self.errors_dialog_state;
+        errors_dialog_state.scroll_state.current_scroll_index = 0;
+        errors_dialog_state.scroll_state.max_scroll_index =
+            self.data_collection.collector_errors.len() as u16;
+        errors_dialog_state.is_showing_errors = true;
+        self.is_force_redraw = true;
+    }
+
+    /// Opens a dialog listing recently started and exited processes, newest first.
+    pub fn open_process_events_dialog(&mut self) {
+        self.reset_multi_tap_keys();
+
+        let process_events_dialog_state = &mut self.process_events_dialog_state;
+        process_events_dialog_state
+            .scroll_state
+            .current_scroll_index = 0;
+        process_events_dialog_state.scroll_state.max_scroll_index =
+            self.data_collection.process_events.len() as u16;
+        process_events_dialog_state.is_showing_events = true;
+        self.is_force_redraw = true;
+    }
+
+    /// Opens a dialog listing the notifications from process watches, newest first.
+    pub fn open_watch_events_dialog(&mut self) {
+        self.reset_multi_tap_keys();
+
+        let watch_events_dialog_state = &mut self.watch_events_dialog_state;
+        watch_events_dialog_state.scroll_state.current_scroll_index = 0;
+        watch_events_dialog_state.scroll_state.max_scroll_index =
+            self.alerts.watches.events.len() as u16;
+        watch_events_dialog_state.is_showing_events = true;
+        self.is_force_redraw = true;
+    }
+
+    /// Watches the processes matching the current process widget's search, or stops watching them
+    /// if that search is already being watched.
+    pub fn toggle_watch(&mut self) {
+        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+            if let Some(proc_widget_state) = self
+                .proc_state
+                .get_mut_widget_state(self.current_widget.widget_id)
+            {
+                let query = if !proc_widget_state
+                    .process_search_state
+                    .search_state
+                    .is_invalid_or_blank_search()
+                {
+                    proc_widget_state.parse_query().ok()
+                } else {
+                    None
+                };
+
+                let notice = if let Some(query) = query {
+                    let query_text = proc_widget_state.get_current_search_query().clone();
+                    let watch = Watch::new(
+                        query_text.clone(),
+                        query,
+                        proc_widget_state.is_using_command,
+                    );
+                    if self.alerts.watches.toggle(watch) {
+                        format!("Watching \"{}\"", query_text)
+                    } else {
+                        format!("Stopped watching \"{}\"", query_text)
+                    }
+                } else {
+                    "Search for the processes to watch first".to_string()
+                };
+
+                proc_widget_state.notice = Some((notice, Instant::now()));
+            }
+        }
+    }
+
     pub fn on_char_key(&mut self, caught_char: char) {
         // Skip control code chars
         if caught_char.is_control() {
@@ -1432,6 +1854,27 @@ impl App {
                         return;
                     }
                 }
+            } else if self.is_searching_sockets() {
+                if let Some(socket_widget_state) = self
+                    .socket_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    if UnicodeWidthStr::width(
+                        socket_widget_state
+                            .search_state
+                            .current_search_query
+                            .as_str(),
+                    ) <= MAX_SEARCH_LENGTH
+                    {
+                        socket_widget_state
+                            .search_state
+                            .current_search_query
+                            .push(caught_char);
+                        socket_widget_state.update_query();
+                        self.socket_state.force_update = Some(self.current_widget.widget_id);
+                    }
+                }
+                return;
             }
             self.handle_char(caught_char);
         } else if self.help_dialog_state.is_showing_help {
@@ -1450,6 +1893,15 @@ impl App {
                 'j' | 'k' | 'g' | 'G' => self.handle_char(caught_char),
                 _ => {}
             }
+        } else if self.connections_dialog_state.is_showing_connections
+            || self.errors_dialog_state.is_showing_errors
+            || self.process_events_dialog_state.is_showing_events
+            || self.watch_events_dialog_state.is_showing_events
+        {
+            match caught_char {
+                'j' | 'k' | 'g' | 'G' => self.handle_char(caught_char),
+                _ => {}
+            }
         } else if self.delete_dialog_state.is_showing_dd {
             match caught_char {
                 'h' => self.on_left_key(),
@@ -1641,6 +2093,14 @@ impl App {
             'K' | 'W' => self.move_widget_selection(&WidgetDirection::Up),
             'J' | 'S' => self.move_widget_selection(&WidgetDirection::Down),
             't' => self.toggle_tree_mode(),
+            'F' => self.toggle_following(),
+            'o' => self.open_connections_dialog(),
+            'E' => self.open_errors_dialog(),
+            'R' => self.open_process_events_dialog(),
+            'w' => self.toggle_watch(),
+            'N' => self.open_watch_events_dialog(),
+            'b' => self.cycle_grouping_type(),
+            'T' => self.toggle_tree_totals(),
             '+' => self.on_plus(),
             '-' => self.on_minus(),
             '=' => self.reset_zoom(),
@@ -2220,6 +2680,27 @@ impl App {
                         temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
+                BottomWidgetType::Sensor => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        sensor_widget_state.scroll_state.current_scroll_position = 0;
+                        sensor_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
+                BottomWidgetType::NetInterface => {
+                    if let Some(net_interface_widget_state) = self
+                        .net_interface_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        net_interface_widget_state
+                            .scroll_state
+                            .current_scroll_position = 0;
+                        net_interface_widget_state.scroll_state.scroll_direction =
+                            ScrollDirection::Up;
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
@@ -2229,6 +2710,15 @@ impl App {
                         disk_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
+                BottomWidgetType::Socket => {
+                    if let Some(socket_widget_state) = self
+                        .socket_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        socket_widget_state.scroll_state.current_scroll_position = 0;
+                        socket_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .cpu_state
@@ -2244,6 +2734,20 @@ impl App {
             self.reset_multi_tap_keys();
         } else if self.help_dialog_state.is_showing_help {
             self.help_dialog_state.scroll_state.current_scroll_index = 0;
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_dialog_state
+                .scroll_state
+                .current_scroll_index = 0;
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_dialog_state.scroll_state.current_scroll_index = 0;
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_dialog_state
+                .scroll_state
+                .current_scroll_index = 0;
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_dialog_state
+                .scroll_state
+                .current_scroll_index = 0;
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Cancel;
         }
@@ -2293,6 +2797,34 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Sensor => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.canvas_data.sensor_data.is_empty() {
+                            sensor_widget_state.scroll_state.current_scroll_position =
+                                self.canvas_data.sensor_data.len() - 1;
+                            sensor_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
+                BottomWidgetType::NetInterface => {
+                    if let Some(net_interface_widget_state) = self
+                        .net_interface_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self.canvas_data.net_interface_data.is_empty() {
+                            net_interface_widget_state
+                                .scroll_state
+                                .current_scroll_position =
+                                self.canvas_data.net_interface_data.len() - 1;
+                            net_interface_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
@@ -2305,6 +2837,25 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Socket => {
+                    if let Some(socket_widget_state) = self
+                        .socket_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if let Some(socket_data) = self
+                            .canvas_data
+                            .socket_data_map
+                            .get(&self.current_widget.widget_id)
+                        {
+                            if !socket_data.is_empty() {
+                                socket_widget_state.scroll_state.current_scroll_position =
+                                    socket_data.len() - 1;
+                                socket_widget_state.scroll_state.scroll_direction =
+                                    ScrollDirection::Down;
+                            }
+                        }
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .cpu_state
@@ -2326,6 +2877,36 @@ impl App {
                 .scroll_state
                 .max_scroll_index
                 .saturating_sub(1);
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_dialog_state
+                .scroll_state
+                .current_scroll_index = self
+                .connections_dialog_state
+                .scroll_state
+                .max_scroll_index
+                .saturating_sub(1);
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_dialog_state.scroll_state.current_scroll_index = self
+                .errors_dialog_state
+                .scroll_state
+                .max_scroll_index
+                .saturating_sub(1);
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_dialog_state
+                .scroll_state
+                .current_scroll_index = self
+                .process_events_dialog_state
+                .scroll_state
+                .max_scroll_index
+                .saturating_sub(1);
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_dialog_state
+                .scroll_state
+                .current_scroll_index = self
+                .watch_events_dialog_state
+                .scroll_state
+                .max_scroll_index
+                .saturating_sub(1);
         } else if self.delete_dialog_state.is_showing_dd {
             self.delete_dialog_state.selected_signal = KillSignal::Kill(MAX_SIGNAL);
         }
@@ -2347,7 +2928,10 @@ impl App {
                 }
                 BottomWidgetType::ProcSort => self.change_process_sort_position(amount),
                 BottomWidgetType::Temp => self.change_temp_position(amount),
+                BottomWidgetType::Sensor => self.change_sensor_position(amount),
+                BottomWidgetType::NetInterface => self.change_net_interface_position(amount),
                 BottomWidgetType::Disk => self.change_disk_position(amount),
+                BottomWidgetType::Socket => self.change_socket_position(amount),
                 BottomWidgetType::CpuLegend => self.change_cpu_legend_position(amount),
                 _ => {}
             }
@@ -2425,6 +3009,30 @@ impl App {
         }
     }
 
+    fn change_sensor_position(&mut self, num_to_change_by: i64) {
+        if let Some(sensor_widget_state) = self
+            .sensor_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            sensor_widget_state
+                .scroll_state
+                .update_position(num_to_change_by, self.canvas_data.sensor_data.len());
+        }
+    }
+
+    fn change_net_interface_position(&mut self, num_to_change_by: i64) {
+        if let Some(net_interface_widget_state) = self
+            .net_interface_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            net_interface_widget_state
+                .scroll_state
+                .update_position(num_to_change_by, self.canvas_data.net_interface_data.len());
+        }
+    }
+
     fn change_disk_position(&mut self, num_to_change_by: i64) {
         if let Some(disk_widget_state) = self
             .disk_state
@@ -2437,6 +3045,24 @@ impl App {
         }
     }
 
+    fn change_socket_position(&mut self, num_to_change_by: i64) {
+        if let Some(socket_widget_state) = self
+            .socket_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            let num_entries = self
+                .canvas_data
+                .socket_data_map
+                .get(&self.current_widget.widget_id)
+                .map(|socket_data| socket_data.len())
+                .unwrap_or(0);
+            socket_widget_state
+                .scroll_state
+                .update_position(num_to_change_by, num_entries);
+        }
+    }
+
     fn help_scroll_up(&mut self) {
         if self.help_dialog_state.scroll_state.current_scroll_index > 0 {
             self.help_dialog_state.scroll_state.current_scroll_index -= 1;
@@ -2460,6 +3086,62 @@ impl App {
         }
     }
 
+    fn connections_scroll_up(&mut self) {
+        let scroll_state = &mut self.connections_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index > 0 {
+            scroll_state.current_scroll_index -= 1;
+        }
+    }
+
+    fn connections_scroll_down(&mut self) {
+        let scroll_state = &mut self.connections_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index + 1 < scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index += 1;
+        }
+    }
+
+    fn errors_scroll_up(&mut self) {
+        let scroll_state = &mut self.errors_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index > 0 {
+            scroll_state.current_scroll_index -= 1;
+        }
+    }
+
+    fn errors_scroll_down(&mut self) {
+        let scroll_state = &mut self.errors_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index + 1 < scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index += 1;
+        }
+    }
+
+    fn process_events_scroll_up(&mut self) {
+        let scroll_state = &mut self.process_events_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index > 0 {
+            scroll_state.current_scroll_index -= 1;
+        }
+    }
+
+    fn process_events_scroll_down(&mut self) {
+        let scroll_state = &mut self.process_events_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index + 1 < scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index += 1;
+        }
+    }
+
+    fn watch_events_scroll_up(&mut self) {
+        let scroll_state = &mut self.watch_events_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index > 0 {
+            scroll_state.current_scroll_index -= 1;
+        }
+    }
+
+    fn watch_events_scroll_down(&mut self) {
+        let scroll_state = &mut self.watch_events_dialog_state.scroll_state;
+        if scroll_state.current_scroll_index + 1 < scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index += 1;
+        }
+    }
+
     pub fn handle_scroll_up(&mut self) {
         if self.delete_dialog_state.is_showing_dd {
             #[cfg(target_family = "unix")]
@@ -2470,6 +3152,14 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_up();
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_scroll_up();
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_scroll_up();
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_scroll_up();
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_scroll_up();
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_in();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2487,6 +3177,14 @@ impl App {
         }
         if self.help_dialog_state.is_showing_help {
             self.help_scroll_down();
+        } else if self.connections_dialog_state.is_showing_connections {
+            self.connections_scroll_down();
+        } else if self.errors_dialog_state.is_showing_errors {
+            self.errors_scroll_down();
+        } else if self.process_events_dialog_state.is_showing_events {
+            self.process_events_scroll_down();
+        } else if self.watch_events_dialog_state.is_showing_events {
+            self.watch_events_scroll_down();
         } else if self.current_widget.widget_type.is_widget_graph() {
             self.zoom_out();
         } else if self.current_widget.widget_type.is_widget_table() {
@@ -2618,7 +3316,10 @@ impl App {
                     }
                 }
             }
-            _ => {}
+            _ => {
+                let time_interval = self.app_config_fields.time_interval;
+                self.zoom_graph(|current_display_time| current_display_time + time_interval);
+            }
         }
     }
 
@@ -2699,7 +3400,12 @@ impl App {
                     }
                 }
             }
-            _ => {}
+            _ => {
+                let time_interval = self.app_config_fields.time_interval;
+                self.zoom_graph(|current_display_time| {
+                    current_display_time.saturating_sub(time_interval)
+                });
+            }
         }
     }
 
@@ -2745,12 +3451,38 @@ impl App {
         }
     }
 
+    /// Returns the state of the graph widgets of the given type, if they use [`GraphState`].
+    fn get_mut_graph_state(&mut self, widget_type: &BottomWidgetType) -> Option<&mut GraphState> {
+        match widget_type {
+            BottomWidgetType::Pressure => Some(&mut self.pressure_state),
+            BottomWidgetType::DiskUtil => Some(&mut self.disk_util_state),
+            BottomWidgetType::DiskIo => Some(&mut self.disk_io_state),
+            BottomWidgetType::TempGraph => Some(&mut self.temp_graph_state),
+            BottomWidgetType::CpuFreq => Some(&mut self.cpu_freq_state),
+            BottomWidgetType::LoadAvg => Some(&mut self.load_avg_state),
+            _ => None,
+        }
+    }
+
+    /// Zooms the current widget if it is a graph using [`GraphState`].
+    fn zoom_graph(&mut self, get_new_time: impl FnOnce(u64) -> u64) {
+        let widget_id = self.current_widget.widget_id;
+        let widget_type = self.current_widget.widget_type.clone();
+        let autohide_time = self.app_config_fields.autohide_time;
+        if let Some(graph_state) = self.get_mut_graph_state(&widget_type) {
+            graph_state.zoom(widget_id, get_new_time, autohide_time);
+        }
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
-            _ => {}
+            _ => {
+                let default_time_value = self.app_config_fields.default_time_value;
+                self.zoom_graph(|_| default_time_value);
+            }
         }
     }
 
@@ -2902,7 +3634,10 @@ impl App {
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
                     | BottomWidgetType::Temp
-                    | BottomWidgetType::Disk => {
+                    | BottomWidgetType::Disk
+                    | BottomWidgetType::Socket
+                    | BottomWidgetType::Sensor
+                    | BottomWidgetType::NetInterface => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
                         // + 1 so we start at 0.
@@ -2989,6 +3724,36 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::Sensor => {
+                                    if let Some(sensor_widget_state) = self
+                                        .sensor_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            sensor_widget_state.scroll_state.table_state.selected()
+                                        {
+                                            self.change_sensor_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
+                                BottomWidgetType::NetInterface => {
+                                    if let Some(net_interface_widget_state) = self
+                                        .net_interface_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) = net_interface_widget_state
+                                            .scroll_state
+                                            .table_state
+                                            .selected()
+                                        {
+                                            self.change_net_interface_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 BottomWidgetType::Disk => {
                                     if let Some(disk_widget_state) = self
                                         .disk_state
@@ -3003,6 +3768,20 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::Socket => {
+                                    if let Some(socket_widget_state) = self
+                                        .socket_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            socket_widget_state.scroll_state.table_state.selected()
+                                        {
+                                            self.change_socket_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 _ => {}
                             }
                         } else {
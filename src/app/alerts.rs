@@ -0,0 +1,576 @@
+//! Alert rules from the config, which are checked against the collected data after every update,
+//! along with the actions taken when they fire.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+#[cfg(target_family = "unix")]
+use super::data_harvester::processes::UserTable;
+use super::{
+    data_farmer::DataCollection,
+    data_harvester::DataSource,
+    query::{Query, QueryComparison, QueryKind},
+    watches::Watches,
+};
+use crate::{
+    data_conversion::{convert_process_data, ConvertedProcessData},
+    options::AlertConfig,
+    utils::error::{BottomError, Result},
+    Pid,
+};
+
+/// A value that a threshold rule compares.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlertMetric {
+    /// `cpu.avg`, the average usage of all cores.
+    CpuAvg,
+    /// `cpu[N]`, the usage of a single core.
+    Cpu(usize),
+    /// `mem.percent`
+    MemPercent,
+    /// `swap.percent`
+    SwapPercent,
+    /// `temp["sensor"]`, in the configured temperature unit.
+    Temp(String),
+    /// `disk["mount point or name"].used_percent`
+    DiskUsedPercent(String),
+}
+
+impl AlertMetric {
+    fn parse(metric: &str) -> Option<Self> {
+        let metric = metric.trim();
+        match metric {
+            "cpu.avg" => Some(AlertMetric::CpuAvg),
+            "mem.percent" => Some(AlertMetric::MemPercent),
+            "swap.percent" => Some(AlertMetric::SwapPercent),
+            _ => {
+                if let Some(core) = strip_brackets(metric, "cpu[", "]") {
+                    core.trim().parse().ok().map(AlertMetric::Cpu)
+                } else if let Some(sensor) = strip_brackets(metric, "temp[", "]") {
+                    parse_quoted(sensor).map(AlertMetric::Temp)
+                } else if let Some(disk) = strip_brackets(metric, "disk[", "].used_percent") {
+                    parse_quoted(disk).map(AlertMetric::DiskUsedPercent)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn source(&self) -> DataSource {
+        match self {
+            AlertMetric::CpuAvg | AlertMetric::Cpu(_) => DataSource::Cpu,
+            AlertMetric::MemPercent | AlertMetric::SwapPercent => DataSource::Memory,
+            AlertMetric::Temp(_) => DataSource::Temperature,
+            AlertMetric::DiskUsedPercent(_) => DataSource::Disk,
+        }
+    }
+
+    /// Returns the metric's latest value, if it has been collected.
+    fn get_value(&self, data: &DataCollection) -> Option<f64> {
+        match self {
+            AlertMetric::CpuAvg => {
+                if let Some(avg) = data.cpu_harvest.iter().find(|cpu| cpu.cpu_count.is_none()) {
+                    Some(avg.cpu_usage)
+                } else {
+                    // The average isn't collected if it's hidden, so work it out from the cores.
+                    let cores = data.cpu_harvest.len();
+                    if cores > 0 {
+                        let total: f64 = data.cpu_harvest.iter().map(|cpu| cpu.cpu_usage).sum();
+                        Some(total / cores as f64)
+                    } else {
+                        None
+                    }
+                }
+            }
+            AlertMetric::Cpu(core) => data
+                .cpu_harvest
+                .iter()
+                .find(|cpu| cpu.cpu_count == Some(*core))
+                .map(|cpu| cpu.cpu_usage),
+            AlertMetric::MemPercent => data.memory_harvest.use_percent,
+            AlertMetric::SwapPercent => data.swap_harvest.use_percent,
+            AlertMetric::Temp(sensor) => data
+                .temp_harvest
+                .iter()
+                .find(|temp| &temp.name == sensor)
+                .map(|temp| f64::from(temp.temperature)),
+            AlertMetric::DiskUsedPercent(disk) => data
+                .disk_harvest
+                .iter()
+                .find(|harvest| &harvest.mount_point == disk || &harvest.name == disk)
+                .and_then(|harvest| match (harvest.used_space, harvest.total_space) {
+                    (Some(used), Some(total)) if total > 0 => {
+                        Some(used as f64 / total as f64 * 100.0)
+                    }
+                    _ => None,
+                }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AlertCondition {
+    /// Holds while a metric compares to a value as required, like `mem.percent > 95`.
+    Threshold {
+        metric: AlertMetric,
+        comparison: QueryComparison,
+        value: f64,
+    },
+    /// `process(query) exists`, which holds while any process matches the query.
+    ProcessExists(Query),
+}
+
+impl AlertCondition {
+    fn source(&self) -> DataSource {
+        match self {
+            AlertCondition::Threshold { metric, .. } => metric.source(),
+            AlertCondition::ProcessExists(_) => DataSource::Processes,
+        }
+    }
+
+    /// Returns whether the condition holds, along with the value it was checked against.  For
+    /// process rules, that is the number of matching processes.
+    fn check(
+        &self, data: &DataCollection, process_data: &HashMap<Pid, ConvertedProcessData>,
+    ) -> (bool, Option<f64>) {
+        match self {
+            AlertCondition::Threshold {
+                metric,
+                comparison,
+                value,
+            } => {
+                let current_value = metric.get_value(data);
+                let holds = match current_value {
+                    Some(current_value) => comparison.matches(current_value, *value),
+                    None => false,
+                };
+                (holds, current_value)
+            }
+            AlertCondition::ProcessExists(query) => {
+                let count = process_data
+                    .values()
+                    .filter(|process| query.check(*process, false))
+                    .count();
+                (count > 0, Some(count as f64))
+            }
+        }
+    }
+}
+
+/// An alert rule, along with what to do when it fires.
+#[derive(Debug)]
+pub struct Alert {
+    pub name: String,
+    /// The rule as written in the config.
+    pub rule: String,
+    condition: AlertCondition,
+    /// How long the condition must hold before the alert fires.
+    duration: Duration,
+    highlight: bool,
+    bell: bool,
+    log: Option<PathBuf>,
+    command: Option<String>,
+    /// When the condition started holding, if it currently does.
+    holding_since: Option<Instant>,
+    pub is_firing: bool,
+}
+
+impl Alert {
+    pub fn from_config(config: &AlertConfig) -> Result<Self> {
+        let (condition, duration) = parse_rule(&config.rule)?;
+
+        Ok(Alert {
+            name: config.name.clone().unwrap_or_else(|| config.rule.clone()),
+            rule: config.rule.clone(),
+            condition,
+            duration,
+            highlight: config.highlight.unwrap_or(true),
+            bell: config.bell.unwrap_or(false),
+            log: config.log.as_ref().map(PathBuf::from),
+            command: config.command.clone(),
+            holding_since: None,
+            is_firing: false,
+        })
+    }
+
+    /// The source of the data the rule checks, which needs to be collected.
+    pub fn source(&self) -> DataSource {
+        self.condition.source()
+    }
+
+    /// Rings the bell, appends to the log, and runs the command, as configured.  Only the log and
+    /// the command are used when the alert resolves.
+    fn take_actions(&self, is_firing: bool, value: Option<f64>) {
+        let state = if is_firing { "firing" } else { "resolved" };
+        let value = match value {
+            Some(value) => format!("{:.1}", value),
+            None => "N/A".to_string(),
+        };
+
+        if is_firing && self.bell {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07").and_then(|_| stdout.flush());
+        }
+
+        if let Some(log) = &self.log {
+            append_to_log(
+                log,
+                &format!(
+                    "[{}] {}: {} (value: {})",
+                    self.name, state, self.rule, value
+                ),
+            );
+        }
+
+        if let Some(command) = &self.command {
+            run_command(
+                command,
+                &[
+                    ("BTM_ALERT_NAME", &self.name),
+                    ("BTM_ALERT_RULE", &self.rule),
+                    ("BTM_ALERT_STATE", state),
+                    ("BTM_ALERT_VALUE", &value),
+                ],
+            );
+        }
+    }
+}
+
+/// Appends a timestamped line to a log file, creating it if needed.  Failures are ignored.
+pub fn append_to_log(log: &Path, message: &str) {
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(log) {
+        let timestamp = time::OffsetDateTime::now_utc()
+            .format(&time::macros::format_description!(
+                "[year]-[month]-[day] [hour]:[minute]:[second]"
+            ))
+            .unwrap_or_default();
+        let _ = writeln!(file, "{} UTC {}", timestamp, message);
+    }
+}
+
+/// Runs a command with the shell in the background, with the given environment variables.
+/// Failures are ignored.
+pub fn run_command(command: &str, env: &[(&str, &str)]) {
+    let mut shell = if cfg!(target_os = "windows") {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+
+    if let Ok(mut child) = shell
+        .arg(command)
+        .envs(env.iter().copied())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        // Wait on it elsewhere so it doesn't linger as a zombie once done.
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
+
+/// The alerts from the config, and what is needed to check them.
+#[derive(Debug, Default)]
+pub struct Alerts {
+    pub alerts: Vec<Alert>,
+    pub watches: Watches,
+    /// The processes that process rules and watches are checked against.  This is kept apart from
+    /// the process widgets' data, as that is not updated while frozen.
+    process_data: HashMap<Pid, ConvertedProcessData>,
+}
+
+impl Alerts {
+    pub fn new(alerts: Vec<Alert>, watches: Watches) -> Self {
+        Alerts {
+            alerts,
+            watches,
+            process_data: HashMap::default(),
+        }
+    }
+
+    /// Checks every alert and watch against the latest data, taking the actions of any alerts
+    /// that fire or resolve.
+    pub fn update(
+        &mut self, data: &DataCollection, #[cfg(target_family = "unix")] user_table: &mut UserTable,
+    ) {
+        if !self.watches.is_empty()
+            || self
+                .alerts
+                .iter()
+                .any(|alert| matches!(alert.condition, AlertCondition::ProcessExists(_)))
+        {
+            let prev_pids = self.process_data.keys().copied().collect();
+            convert_process_data(
+                data,
+                &mut self.process_data,
+                false,
+                #[cfg(target_family = "unix")]
+                user_table,
+            );
+            self.watches
+                .update(&self.process_data, &prev_pids, data.current_instant);
+        }
+
+        for alert in &mut self.alerts {
+            let (holds, value) = alert.condition.check(data, &self.process_data);
+
+            if holds {
+                let holding_since = *alert.holding_since.get_or_insert(data.current_instant);
+                if !alert.is_firing
+                    && data.current_instant.duration_since(holding_since) >= alert.duration
+                {
+                    alert.is_firing = true;
+                    alert.take_actions(true, value);
+                }
+            } else {
+                alert.holding_since = None;
+                if alert.is_firing {
+                    alert.is_firing = false;
+                    alert.take_actions(false, value);
+                }
+            }
+        }
+    }
+
+    /// Returns the process queries of every alert and watch.
+    pub fn get_process_queries(&self) -> impl Iterator<Item = &Query> {
+        self.alerts
+            .iter()
+            .filter_map(|alert| match &alert.condition {
+                AlertCondition::ProcessExists(query) => Some(query),
+                AlertCondition::Threshold { .. } => None,
+            })
+            .chain(self.watches.watches.iter().map(|watch| watch.get_query()))
+    }
+
+    /// Returns the sources whose widgets should be highlighted due to a firing alert.
+    pub fn get_highlighted_sources(&self) -> HashSet<DataSource> {
+        self.alerts
+            .iter()
+            .filter(|alert| alert.is_firing && alert.highlight)
+            .map(|alert| alert.source())
+            .collect()
+    }
+}
+
+/// Parses a rule into its condition and how long that must hold, like `cpu.avg > 90 for 30s`.
+fn parse_rule(rule: &str) -> Result<(AlertCondition, Duration)> {
+    let invalid = |reason: &str| {
+        BottomError::ConfigError(format!(
+            "\"{}\" is not a valid alert rule, {}.",
+            rule, reason
+        ))
+    };
+
+    let rule = rule.trim();
+    let (condition, duration) = match rule.rfind(" for ") {
+        // A " for " inside a process query isn't a duration.
+        Some(index) if !rule[index..].contains(')') => {
+            let duration = parse_duration(&rule[index + " for ".len()..]).ok_or_else(|| {
+                invalid("the duration after \"for\" should be like 500ms, 30s, 5m, or 1h")
+            })?;
+            (rule[..index].trim(), duration)
+        }
+        _ => (rule, Duration::from_secs(0)),
+    };
+
+    if let Some(query) = condition.strip_prefix("process(") {
+        let query = query
+            .strip_suffix("exists")
+            .and_then(|query| query.trim_end().strip_suffix(')'))
+            .ok_or_else(|| invalid("process rules should be like process(query) exists"))?;
+        let query = QueryKind::Process
+            .parse_query(query, false, true, false)
+            .map_err(|err| invalid(&format!("the process query is invalid ({})", err)))?;
+
+        return Ok((AlertCondition::ProcessExists(query), duration));
+    }
+
+    // Comparison characters inside quoted names aren't the comparison.
+    let mut is_in_quotes = false;
+    let comparison_index = condition
+        .char_indices()
+        .find(|(_, c)| {
+            if *c == '"' {
+                is_in_quotes = !is_in_quotes;
+            }
+            !is_in_quotes && matches!(c, '<' | '>' | '=')
+        })
+        .map(|(index, _)| index)
+        .ok_or_else(|| invalid("it is missing a comparison like > 90"))?;
+
+    let (metric, comparison) = condition.split_at(comparison_index);
+    let metric = AlertMetric::parse(metric)
+        .ok_or_else(|| invalid(&format!("\"{}\" is not a known value", metric.trim())))?;
+
+    let (comparison, value) = if let Some(value) = comparison.strip_prefix(">=") {
+        (QueryComparison::GreaterOrEqual, value)
+    } else if let Some(value) = comparison.strip_prefix("<=") {
+        (QueryComparison::LessOrEqual, value)
+    } else if let Some(value) = comparison.strip_prefix("==") {
+        (QueryComparison::Equal, value)
+    } else if let Some(value) = comparison.strip_prefix('>') {
+        (QueryComparison::Greater, value)
+    } else if let Some(value) = comparison.strip_prefix('<') {
+        (QueryComparison::Less, value)
+    } else {
+        (QueryComparison::Equal, &comparison[1..])
+    };
+    let value = value
+        .trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .map_err(|_| invalid(&format!("\"{}\" is not a number", value.trim())))?;
+
+    Ok((
+        AlertCondition::Threshold {
+            metric,
+            comparison,
+            value,
+        },
+        duration,
+    ))
+}
+
+/// Parses a duration like `500ms`, `30s`, `5m`, or `1h`.
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let duration = duration.trim();
+    let unit_index = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (value, unit) = duration.split_at(unit_index);
+    let value: u64 = value.parse().ok()?;
+
+    match unit {
+        "ms" => Some(Duration::from_millis(value)),
+        "s" => Some(Duration::from_secs(value)),
+        "m" => Some(Duration::from_secs(value.checked_mul(60)?)),
+        "h" => Some(Duration::from_secs(value.checked_mul(60 * 60)?)),
+        _ => None,
+    }
+}
+
+/// Returns what is between `prefix` and `suffix`, if `s` starts and ends with them.
+fn strip_brackets<'a>(s: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    s.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+}
+
+/// Parses a double-quoted name, like `"/"`.
+fn parse_quoted(s: &str) -> Option<String> {
+    s.trim()
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .map(|name| name.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_threshold(rule: &str) -> (AlertMetric, f64, Duration) {
+        match parse_rule(rule) {
+            Ok((
+                AlertCondition::Threshold {
+                    metric,
+                    comparison: _,
+                    value,
+                },
+                duration,
+            )) => (metric, value, duration),
+            other => panic!("{} was parsed as {:?}", rule, other),
+        }
+    }
+
+    #[test]
+    fn test_parse_threshold_rules() {
+        assert_eq!(
+            parse_threshold("cpu.avg > 90 for 30s"),
+            (AlertMetric::CpuAvg, 90.0, Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_threshold("mem.percent > 95"),
+            (AlertMetric::MemPercent, 95.0, Duration::from_secs(0))
+        );
+        assert_eq!(
+            parse_threshold("cpu[3] >= 99.5% for 1m"),
+            (AlertMetric::Cpu(3), 99.5, Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_threshold("temp[\"k10temp: Tctl\"] > 85"),
+            (
+                AlertMetric::Temp("k10temp: Tctl".to_string()),
+                85.0,
+                Duration::from_secs(0)
+            )
+        );
+        assert_eq!(
+            parse_threshold("disk[\"/\"].used_percent > 90 for 500ms"),
+            (
+                AlertMetric::DiskUsedPercent("/".to_string()),
+                90.0,
+                Duration::from_millis(500)
+            )
+        );
+    }
+
+    #[test]
+    fn test_cpu_avg_value() {
+        use crate::app::data_harvester::cpu::CpuData;
+
+        let cpu = |cpu_count: Option<usize>, cpu_usage: f64| CpuData {
+            cpu_prefix: "CPU".to_string(),
+            cpu_count,
+            cpu_usage,
+        };
+        let mut data = DataCollection::default();
+        assert_eq!(AlertMetric::CpuAvg.get_value(&data), None);
+
+        data.cpu_harvest = vec![cpu(None, 40.0), cpu(Some(0), 10.0), cpu(Some(1), 30.0)];
+        assert_eq!(AlertMetric::CpuAvg.get_value(&data), Some(40.0));
+
+        // With the average hidden, it's taken from the cores instead.
+        data.cpu_harvest = vec![cpu(Some(0), 10.0), cpu(Some(1), 30.0)];
+        assert_eq!(AlertMetric::CpuAvg.get_value(&data), Some(20.0));
+    }
+
+    #[test]
+    fn test_parse_process_rules() {
+        assert!(matches!(
+            parse_rule("process(cpu > 50 and ffmpeg) exists for 10s"),
+            Ok((AlertCondition::ProcessExists(_), duration)) if duration == Duration::from_secs(10)
+        ));
+        assert!(matches!(
+            parse_rule("process(waiting for input) exists"),
+            Ok((AlertCondition::ProcessExists(_), duration)) if duration == Duration::from_secs(0)
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_rules() {
+        for rule in [
+            "cpu.avg",
+            "cpu.max > 90",
+            "mem.percent > lots",
+            "temp[k10temp] > 85",
+            "cpu.avg > 90 for a while",
+            "cpu.avg > 90 for 18446744073709551615m",
+            "cpu.avg > 90 for 18446744073709551615h",
+            "process(ffmpeg)",
+        ] {
+            assert!(parse_rule(rule).is_err(), "{} should be invalid", rule);
+        }
+    }
+}
@@ -12,30 +12,55 @@
 /// call the purging function.  Failure to do so *will* result in a growing
 /// memory usage and higher CPU usage - you will be trying to process more and
 /// more points as this is used!
+use fxhash::FxHashMap;
 use once_cell::sync::Lazy;
 
-use std::{time::Instant, vec::Vec};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+    vec::Vec,
+};
 
 #[cfg(feature = "battery")]
 use crate::data_harvester::batteries;
 
 use crate::{
-    data_harvester::{cpu, disks, memory, network, processes, temperature, Data},
+    constants::EXITED_PROCESS_TIMEOUT_MILLISECONDS,
+    data_harvester::{
+        cpu, disks, memory, network, pressure, processes, sensors, temperature, CollectorError,
+        Data, DataSource,
+    },
     utils::gen_util::{get_decimal_bytes, GIGA_LIMIT},
+    Pid,
 };
 use regex::Regex;
 
 pub type TimeOffset = f64;
 pub type Value = f64;
 
+/// The graph points from one update.  As data sources can be collected at different intervals,
+/// anything not collected in that update is left empty.
 #[derive(Debug, Default)]
 pub struct TimedData {
-    pub rx_data: Value,
-    pub tx_data: Value,
+    pub rx_data: Option<Value>,
+    pub tx_data: Option<Value>,
     pub cpu_data: Vec<Value>,
-    pub load_avg_data: [f32; 3],
+    pub load_avg_data: Option<[f32; 3]>,
     pub mem_data: Option<Value>,
     pub swap_data: Option<Value>,
+    /// The current frequency of each core, keyed by core number.
+    pub cpu_freq_data: Vec<(usize, Value)>,
+    pub cpu_pressure_data: Option<Value>,
+    pub mem_pressure_data: Option<Value>,
+    pub io_pressure_data: Option<Value>,
+    /// The utilization percentage of each disk, keyed by disk name.
+    pub disk_util_data: Vec<(String, Value)>,
+    /// The read and write rates of each disk in bytes per second, keyed by disk name.
+    pub disk_io_data: Vec<(String, Value, Value)>,
+    /// The reading of each temperature sensor, keyed by sensor name.
+    pub temp_data: Vec<(String, Value)>,
+    /// The charge percentage and power draw in watts of each battery, by battery index.
+    pub battery_data: Vec<(Value, Value)>,
 }
 
 /// AppCollection represents the pooled data stored within the main app
@@ -57,14 +82,72 @@ pub struct DataCollection {
     pub swap_harvest: memory::MemHarvest,
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
+    pub cpu_freq_harvest: cpu::CpuFreqHarvest,
+    pub kernel_stat_harvest: Option<processes::KernelStatHarvest>,
+    pub kernel_stat_instant: Instant,
+    pub kernel_stat_rates: Option<processes::KernelStatRates>,
     pub process_harvest: Vec<processes::ProcessHarvest>,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IoHarvest,
+    pub io_instant: Instant,
     pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
+    /// The latest rates from `/proc/diskstats`, keyed by disk name.
+    pub io_stat_rates: HashMap<String, disks::DiskStatRates>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    pub socket_harvest: Vec<network::sockets::SocketHarvest>,
+    /// The latest connections of the processes shown in the connections dialog.
+    pub connections_harvest: Option<network::sockets::ConnectionsHarvest>,
+    pub pressure_harvest: pressure::PressureHarvest,
+    pub sensor_harvest: Vec<sensors::SensorHarvest>,
+    pub interface_harvest: Vec<network::interfaces::InterfaceHarvest>,
+    /// The interfaces whose error or drop counters rose since the previous harvest.
+    pub interfaces_with_new_drops: HashSet<String>,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryHarvest>,
+    /// Sources whose last collection timed out.
+    pub stale_sources: HashSet<DataSource>,
+    /// Sources whose last collection failed.
+    pub failing_sources: HashSet<DataSource>,
+    /// The most recent collector errors, oldest first.
+    pub collector_errors: Vec<RecentCollectorError>,
+    /// How many temperature sensors were filtered out in the last harvest.
+    pub temperatures_filtered_out: usize,
+    /// The most recently started and exited processes, oldest first.
+    pub process_events: Vec<ProcessEvent>,
+    /// Processes that exited recently, along with their last known stats.
+    pub exited_processes: Vec<ProcessEvent>,
+}
+
+/// The most recent occurrence of a collector error, along with how many times it has happened.
+#[derive(Debug)]
+pub struct RecentCollectorError {
+    pub error: CollectorError,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessEventKind {
+    Started,
+    Exited,
+}
+
+impl std::fmt::Display for ProcessEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessEventKind::Started => write!(f, "Started"),
+            ProcessEventKind::Exited => write!(f, "Exited"),
+        }
+    }
+}
+
+/// A process that started or exited between two process harvests.
+#[derive(Debug, Clone)]
+pub struct ProcessEvent {
+    pub kind: ProcessEventKind,
+    /// The first harvest of a started process, or the last harvest of an exited one.
+    pub process: processes::ProcessHarvest,
+    pub instant: Instant,
 }
 
 impl Default for DataCollection {
@@ -78,14 +161,32 @@ impl Default for DataCollection {
             swap_harvest: memory::MemHarvest::default(),
             cpu_harvest: cpu::CpuHarvest::default(),
             load_avg_harvest: cpu::LoadAvgHarvest::default(),
+            cpu_freq_harvest: cpu::CpuFreqHarvest::default(),
+            kernel_stat_harvest: None,
+            kernel_stat_instant: Instant::now(),
+            kernel_stat_rates: None,
             process_harvest: Vec::default(),
             disk_harvest: Vec::default(),
             io_harvest: disks::IoHarvest::default(),
+            io_instant: Instant::now(),
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
+            io_stat_rates: HashMap::default(),
             temp_harvest: Vec::default(),
+            socket_harvest: Vec::default(),
+            connections_harvest: None,
+            pressure_harvest: pressure::PressureHarvest::default(),
+            sensor_harvest: Vec::default(),
+            interface_harvest: Vec::default(),
+            interfaces_with_new_drops: HashSet::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
+            stale_sources: HashSet::default(),
+            failing_sources: HashSet::default(),
+            collector_errors: Vec::default(),
+            temperatures_filtered_out: 0,
+            process_events: Vec::default(),
+            exited_processes: Vec::default(),
         }
     }
 }
@@ -97,15 +198,28 @@ impl DataCollection {
         self.memory_harvest = memory::MemHarvest::default();
         self.swap_harvest = memory::MemHarvest::default();
         self.cpu_harvest = cpu::CpuHarvest::default();
+        self.cpu_freq_harvest = cpu::CpuFreqHarvest::default();
+        self.kernel_stat_harvest = None;
+        self.kernel_stat_rates = None;
         self.process_harvest = Vec::default();
+        self.process_events = Vec::default();
+        self.exited_processes = Vec::default();
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IoHarvest::default();
         self.io_labels_and_prev = Vec::default();
+        self.io_stat_rates = HashMap::default();
         self.temp_harvest = Vec::default();
+        self.socket_harvest = Vec::default();
+        self.connections_harvest = None;
+        self.pressure_harvest = pressure::PressureHarvest::default();
+        self.sensor_harvest = Vec::default();
+        self.interface_harvest = Vec::default();
+        self.interfaces_with_new_drops = HashSet::default();
         #[cfg(feature = "battery")]
         {
             self.battery_harvest = Vec::default();
         }
+        self.stale_sources = HashSet::default();
     }
 
     pub fn set_frozen_time(&mut self) {
@@ -137,6 +251,12 @@ impl DataCollection {
         // trace!("New current instant: {:?}", self.current_instant);
         let mut new_entry = TimedData::default();
 
+        self.stale_sources = harvested_data.stale_sources;
+        self.failing_sources = harvested_data.failing_sources;
+        for error in harvested_data.errors {
+            self.eat_error(error);
+        }
+
         // Network
         if let Some(network) = harvested_data.network {
             self.eat_network(network, &mut new_entry);
@@ -157,28 +277,64 @@ impl DataCollection {
             self.eat_load_avg(load_avg, &mut new_entry);
         }
 
+        // Kernel Activity
+        if let Some(kernel_stats) = harvested_data.kernel_stats {
+            self.eat_kernel_stats(kernel_stats, harvested_time);
+        }
+
+        // CPU Frequency
+        if let Some(cpu_freq) = harvested_data.cpu_freq {
+            self.eat_cpu_freq(cpu_freq, &mut new_entry);
+        }
+
         // Temp
         if let Some(temperature_sensors) = harvested_data.temperature_sensors {
-            self.eat_temp(temperature_sensors);
+            self.eat_temp(temperature_sensors, &mut new_entry);
+            self.temperatures_filtered_out = harvested_data.temperatures_filtered_out;
         }
 
         // Disks
         if let Some(disks) = harvested_data.disks {
             if let Some(io) = harvested_data.io {
-                self.eat_disks(disks, io, harvested_time);
+                self.eat_disks(disks, io, harvested_time, &mut new_entry);
             }
         }
 
         // Processes
         if let Some(list_of_processes) = harvested_data.list_of_processes {
-            self.eat_proc(list_of_processes);
+            self.eat_proc(list_of_processes, harvested_time);
+        }
+
+        // Sockets
+        if let Some(sockets) = harvested_data.sockets {
+            self.eat_sockets(sockets);
+        }
+
+        // Connections
+        if let Some(connections) = harvested_data.connections {
+            self.connections_harvest = Some(connections);
+        }
+
+        // Pressure
+        if let Some(pressure) = harvested_data.pressure {
+            self.eat_pressure(pressure, &mut new_entry);
+        }
+
+        // Sensors
+        if let Some(sensors) = harvested_data.sensors {
+            self.eat_sensors(sensors);
+        }
+
+        // Network Interfaces
+        if let Some(interfaces) = harvested_data.interfaces {
+            self.eat_interfaces(interfaces);
         }
 
         #[cfg(feature = "battery")]
         {
             // Battery
             if let Some(list_of_batteries) = harvested_data.list_of_batteries {
-                self.eat_battery(list_of_batteries);
+                self.eat_battery(list_of_batteries, &mut new_entry);
             }
         }
 
@@ -203,14 +359,10 @@ impl DataCollection {
 
     fn eat_network(&mut self, network: network::NetworkHarvest, new_entry: &mut TimedData) {
         // RX
-        if network.rx > 0 {
-            new_entry.rx_data = network.rx as f64;
-        }
+        new_entry.rx_data = Some(network.rx as f64);
 
         // TX
-        if network.tx > 0 {
-            new_entry.tx_data = network.tx as f64;
-        }
+        new_entry.tx_data = Some(network.tx as f64);
 
         // In addition copy over latest data for easy reference
         self.network_harvest = network;
@@ -227,38 +379,115 @@ impl DataCollection {
     }
 
     fn eat_load_avg(&mut self, load_avg: cpu::LoadAvgHarvest, new_entry: &mut TimedData) {
-        new_entry.load_avg_data = load_avg;
+        new_entry.load_avg_data = Some(load_avg);
 
         self.load_avg_harvest = load_avg;
     }
 
-    fn eat_temp(&mut self, temperature_sensors: Vec<temperature::TempHarvest>) {
-        // TODO: [PO] To implement
+    fn eat_kernel_stats(
+        &mut self, kernel_stats: processes::KernelStatHarvest, harvested_time: Instant,
+    ) {
+        if let Some(prev_kernel_stats) = &self.kernel_stat_harvest {
+            let time_since_last_harvest = harvested_time
+                .duration_since(self.kernel_stat_instant)
+                .as_secs_f64();
+            self.kernel_stat_rates =
+                Some(kernel_stats.rates_since(prev_kernel_stats, time_since_last_harvest));
+        }
+
+        self.kernel_stat_harvest = Some(kernel_stats);
+        self.kernel_stat_instant = harvested_time;
+    }
+
+    fn eat_cpu_freq(&mut self, cpu_freq: cpu::CpuFreqHarvest, new_entry: &mut TimedData) {
+        new_entry.cpu_freq_data = cpu_freq
+            .iter()
+            .map(|freq| (freq.cpu, freq.current))
+            .collect();
+
+        self.cpu_freq_harvest = cpu_freq;
+    }
+
+    fn eat_sockets(&mut self, sockets: Vec<network::sockets::SocketHarvest>) {
+        self.socket_harvest = sockets;
+    }
+
+    fn eat_pressure(&mut self, pressure: pressure::PressureHarvest, new_entry: &mut TimedData) {
+        new_entry.cpu_pressure_data = pressure.cpu.map(|cpu| cpu.some.avg10);
+        new_entry.mem_pressure_data = pressure.memory.map(|memory| memory.some.avg10);
+        new_entry.io_pressure_data = pressure.io.map(|io| io.some.avg10);
+
+        self.pressure_harvest = pressure;
+    }
+
+    fn eat_sensors(&mut self, sensors: Vec<sensors::SensorHarvest>) {
+        self.sensor_harvest = sensors;
+    }
+
+    fn eat_interfaces(&mut self, interfaces: Vec<network::interfaces::InterfaceHarvest>) {
+        self.interfaces_with_new_drops = interfaces
+            .iter()
+            .filter(|interface| {
+                self.interface_harvest
+                    .iter()
+                    .find(|prev| prev.name == interface.name)
+                    .map(|prev| {
+                        let (prev, curr) = (&prev.counters, &interface.counters);
+                        curr.rx_errors > prev.rx_errors
+                            || curr.tx_errors > prev.tx_errors
+                            || curr.rx_drops > prev.rx_drops
+                            || curr.tx_drops > prev.tx_drops
+                    })
+                    .unwrap_or(false)
+            })
+            .map(|interface| interface.name.clone())
+            .collect();
+        self.interface_harvest = interfaces;
+    }
+
+    fn eat_temp(
+        &mut self, temperature_sensors: Vec<temperature::TempHarvest>, new_entry: &mut TimedData,
+    ) {
+        new_entry.temp_data = temperature_sensors
+            .iter()
+            .map(|sensor| (sensor.name.clone(), sensor.temperature as Value))
+            .collect();
+
         self.temp_harvest = temperature_sensors.to_vec();
     }
 
     fn eat_disks(
         &mut self, disks: Vec<disks::DiskHarvest>, io: disks::IoHarvest, harvested_time: Instant,
+        new_entry: &mut TimedData,
     ) {
         // TODO: [PO] To implement
 
-        let time_since_last_harvest = harvested_time
-            .duration_since(self.current_instant)
-            .as_secs_f64();
+        let time_since_last_harvest = harvested_time.duration_since(self.io_instant).as_secs_f64();
+        let mut io_stat_rates = HashMap::default();
 
         for (itx, device) in disks.iter().enumerate() {
             if let Some(trim) = device.name.split('/').last() {
-                let io_device = if cfg!(target_os = "macos") {
+                let io_key = if cfg!(target_os = "macos") {
                     // Must trim one level further for macOS!
                     static DISK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"disk\d+").unwrap());
-                    if let Some(disk_trim) = DISK_REGEX.find(trim) {
-                        io.get(disk_trim.as_str())
-                    } else {
-                        None
-                    }
+                    DISK_REGEX.find(trim).map(|disk_trim| disk_trim.as_str())
                 } else {
-                    io.get(trim)
+                    Some(trim)
                 };
+                let io_device = io_key.and_then(|io_key| io.get(io_key));
+
+                // The previous harvest is still stored, so compare against that.
+                let stat_rates = io_key.and_then(|io_key| {
+                    let curr_stats = io.get(io_key)?.as_ref()?.stats?;
+                    let prev_stats = self.io_harvest.get(io_key)?.as_ref()?.stats?;
+                    Some(curr_stats.rates_since(&prev_stats, time_since_last_harvest))
+                });
+                if let Some(stat_rates) = stat_rates {
+                    new_entry
+                        .disk_util_data
+                        .push((device.name.clone(), stat_rates.util_percent));
+                    io_stat_rates.insert(device.name.clone(), stat_rates);
+                }
 
                 if let Some(io_device) = io_device {
                     let (io_r_pt, io_w_pt) = if let Some(io) = io_device {
@@ -285,6 +514,11 @@ impl DataCollection {
 
                         *io_curr = (r_rate, w_rate);
                         *io_prev = (io_r_pt, io_w_pt);
+                        new_entry.disk_io_data.push((
+                            device.name.clone(),
+                            r_rate as f64,
+                            w_rate as f64,
+                        ));
 
                         if let Some(io_labels) = self.io_labels.get_mut(itx) {
                             let converted_read = get_decimal_bytes(r_rate);
@@ -317,14 +551,179 @@ impl DataCollection {
 
         self.disk_harvest = disks;
         self.io_harvest = io;
+        self.io_stat_rates = io_stat_rates;
+        self.io_instant = harvested_time;
     }
 
-    fn eat_proc(&mut self, list_of_processes: Vec<processes::ProcessHarvest>) {
-        self.process_harvest = list_of_processes;
+    /// Records a collector error.  Repeats of a recent error are counted rather than listed again.
+    fn eat_error(&mut self, error: CollectorError) {
+        const MAX_COLLECTOR_ERRORS: usize = 50;
+
+        let repeat_index = self.collector_errors.iter().position(|recent| {
+            recent.error.source == error.source
+                && recent.error.kind == error.kind
+                && recent.error.message == error.message
+        });
+
+        let count = match repeat_index {
+            Some(index) => self.collector_errors.remove(index).count + 1,
+            None => 1,
+        };
+        self.collector_errors
+            .push(RecentCollectorError { error, count });
+
+        if self.collector_errors.len() > MAX_COLLECTOR_ERRORS {
+            self.collector_errors.remove(0);
+        }
+    }
+
+    /// Stores the new process list, comparing it with the previous one to find which processes
+    /// started and exited in between.
+    fn eat_proc(
+        &mut self, list_of_processes: Vec<processes::ProcessHarvest>, harvested_time: Instant,
+    ) {
+        const MAX_PROCESS_EVENTS: usize = 200;
+
+        let prev_processes = std::mem::replace(&mut self.process_harvest, list_of_processes);
+
+        // The first list has nothing to compare with, so don't report everything in it as started.
+        if !prev_processes.is_empty() {
+            let get_parents = |processes: &[processes::ProcessHarvest]| {
+                processes
+                    .iter()
+                    .map(|process| (process.pid, process.parent_pid))
+                    .collect::<FxHashMap<Pid, Option<Pid>>>()
+            };
+            let prev_parents = get_parents(&prev_processes);
+            let parents = get_parents(&self.process_harvest);
+
+            // Like the process table, a PID with a different parent is treated as reused.
+            let is_in = |process: &processes::ProcessHarvest,
+                         parents: &FxHashMap<Pid, Option<Pid>>| {
+                matches!(parents.get(&process.pid), Some(parent_pid) if *parent_pid == process.parent_pid)
+            };
+
+            for process in prev_processes {
+                if !is_in(&process, &parents) {
+                    let event = ProcessEvent {
+                        kind: ProcessEventKind::Exited,
+                        process,
+                        instant: harvested_time,
+                    };
+                    self.exited_processes.push(event.clone());
+                    self.process_events.push(event);
+                }
+            }
+
+            for process in &self.process_harvest {
+                if !is_in(process, &prev_parents) {
+                    self.process_events.push(ProcessEvent {
+                        kind: ProcessEventKind::Started,
+                        process: process.clone(),
+                        instant: harvested_time,
+                    });
+                }
+            }
+
+            if self.process_events.len() > MAX_PROCESS_EVENTS {
+                let excess = self.process_events.len() - MAX_PROCESS_EVENTS;
+                self.process_events.drain(0..excess);
+            }
+        }
+
+        self.exited_processes.retain(|exited| {
+            harvested_time.duration_since(exited.instant).as_millis()
+                < EXITED_PROCESS_TIMEOUT_MILLISECONDS.into()
+        });
     }
 
     #[cfg(feature = "battery")]
-    fn eat_battery(&mut self, list_of_batteries: Vec<batteries::BatteryHarvest>) {
+    fn eat_battery(
+        &mut self, list_of_batteries: Vec<batteries::BatteryHarvest>, new_entry: &mut TimedData,
+    ) {
+        new_entry.battery_data = list_of_batteries
+            .iter()
+            .map(|battery| (battery.charge_percent, battery.power_consumption_rate_watts))
+            .collect();
         self.battery_harvest = list_of_batteries;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn process(pid: Pid, parent_pid: Option<Pid>) -> processes::ProcessHarvest {
+        processes::ProcessHarvest {
+            pid,
+            parent_pid,
+            name: format!("proc_{}", pid),
+            ..processes::ProcessHarvest::default()
+        }
+    }
+
+    fn get_events(data: &DataCollection) -> Vec<(ProcessEventKind, Pid)> {
+        data.process_events
+            .iter()
+            .map(|event| (event.kind, event.process.pid))
+            .collect()
+    }
+
+    #[test]
+    fn test_process_events() {
+        let mut data = DataCollection::default();
+        let start = Instant::now();
+
+        // Nothing is reported as started from the first list.
+        data.eat_proc(vec![process(1, None), process(2, Some(1))], start);
+        assert!(data.process_events.is_empty());
+
+        // PID 2 is reused by a process with another parent, and PID 3 is new.
+        data.eat_proc(
+            vec![process(1, None), process(2, Some(3)), process(3, Some(1))],
+            start + Duration::from_secs(1),
+        );
+        assert_eq!(
+            get_events(&data),
+            vec![
+                (ProcessEventKind::Exited, 2),
+                (ProcessEventKind::Started, 2),
+                (ProcessEventKind::Started, 3),
+            ]
+        );
+        assert_eq!(data.exited_processes.len(), 1);
+        assert_eq!(data.exited_processes[0].process.parent_pid, Some(1));
+
+        data.eat_proc(vec![process(1, None)], start + Duration::from_secs(2));
+        assert_eq!(
+            get_events(&data)[3..],
+            [(ProcessEventKind::Exited, 2), (ProcessEventKind::Exited, 3)]
+        );
+        assert_eq!(data.exited_processes.len(), 3);
+    }
+
+    #[test]
+    fn test_exited_process_expiry() {
+        let mut data = DataCollection::default();
+        let start = Instant::now();
+        let timeout = Duration::from_millis(EXITED_PROCESS_TIMEOUT_MILLISECONDS);
+
+        data.eat_proc(vec![process(1, None), process(2, Some(1))], start);
+        data.eat_proc(vec![process(1, None)], start + Duration::from_secs(1));
+        assert_eq!(data.exited_processes.len(), 1);
+
+        // Exited processes are kept until the timeout has passed since they exited.
+        data.eat_proc(vec![process(1, None)], start + timeout);
+        assert_eq!(data.exited_processes.len(), 1);
+        data.eat_proc(
+            vec![process(1, None)],
+            start + Duration::from_secs(1) + timeout,
+        );
+        assert!(data.exited_processes.is_empty());
+
+        // Only the process table's history expires; the events are kept.
+        assert_eq!(get_events(&data), vec![(ProcessEventKind::Exited, 2)]);
+    }
+}
@@ -1,6 +1,14 @@
 //! This is the main file to house data collection functions.
 
-use std::time::Instant;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 #[cfg(target_os = "linux")]
 use fxhash::FxHashMap;
@@ -8,12 +16,14 @@ use fxhash::FxHashMap;
 #[cfg(not(target_os = "linux"))]
 use sysinfo::{System, SystemExt};
 
-#[cfg(feature = "battery")]
-use battery::{Battery, Manager};
-
-use crate::app::layout_manager::UsedWidgets;
+use crate::{
+    app::layout_manager::UsedWidgets, constants::MINIMUM_COLLECTOR_TIMEOUT_IN_MILLISECONDS,
+};
 
-use futures::join;
+use futures::{
+    future::{Either, OptionFuture},
+    join,
+};
 
 use super::DataFilters;
 
@@ -23,7 +33,9 @@ pub mod cpu;
 pub mod disks;
 pub mod memory;
 pub mod network;
+pub mod pressure;
 pub mod processes;
+pub mod sensors;
 pub mod temperature;
 
 #[derive(Clone, Debug)]
@@ -31,15 +43,30 @@ pub struct Data {
     pub last_collection_time: Instant,
     pub cpu: Option<cpu::CpuHarvest>,
     pub load_avg: Option<cpu::LoadAvgHarvest>,
+    pub cpu_freq: Option<cpu::CpuFreqHarvest>,
+    pub kernel_stats: Option<processes::KernelStatHarvest>,
     pub memory: Option<memory::MemHarvest>,
     pub swap: Option<memory::MemHarvest>,
     pub temperature_sensors: Option<Vec<temperature::TempHarvest>>,
+    /// How many temperature sensors were filtered out.
+    pub temperatures_filtered_out: usize,
     pub network: Option<network::NetworkHarvest>,
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IoHarvest>,
+    pub sockets: Option<Vec<network::sockets::SocketHarvest>>,
+    pub connections: Option<network::sockets::ConnectionsHarvest>,
+    pub pressure: Option<pressure::PressureHarvest>,
+    pub sensors: Option<Vec<sensors::SensorHarvest>>,
+    pub interfaces: Option<Vec<network::interfaces::InterfaceHarvest>>,
     #[cfg(feature = "battery")]
     pub list_of_batteries: Option<Vec<batteries::BatteryHarvest>>,
+    /// Sources whose last collection timed out, so whatever was last collected for them is stale.
+    pub stale_sources: HashSet<DataSource>,
+    /// Sources whose last collection failed.
+    pub failing_sources: HashSet<DataSource>,
+    /// Errors from collecting this data.
+    pub errors: Vec<CollectorError>,
 }
 
 impl Default for Data {
@@ -48,15 +75,26 @@ impl Default for Data {
             last_collection_time: Instant::now(),
             cpu: None,
             load_avg: None,
+            cpu_freq: None,
+            kernel_stats: None,
             memory: None,
             swap: None,
             temperature_sensors: None,
+            temperatures_filtered_out: 0,
             list_of_processes: None,
             disks: None,
             io: None,
             network: None,
+            sockets: None,
+            connections: None,
+            pressure: None,
+            sensors: None,
+            interfaces: None,
             #[cfg(feature = "battery")]
             list_of_batteries: None,
+            stale_sources: HashSet::new(),
+            failing_sources: HashSet::new(),
+            errors: Vec::new(),
         }
     }
 }
@@ -71,6 +109,13 @@ impl Data {
         self.swap = None;
         self.cpu = None;
         self.load_avg = None;
+        self.cpu_freq = None;
+        self.kernel_stats = None;
+        self.sockets = None;
+        self.connections = None;
+        self.pressure = None;
+        self.sensors = None;
+        self.interfaces = None;
 
         if let Some(network) = &mut self.network {
             network.first_run_cleanup();
@@ -78,6 +123,160 @@ impl Data {
     }
 }
 
+/// A source of data that is collected at its own interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataSource {
+    /// CPU usage, load average, frequency, kernel activity, and pressure.
+    Cpu,
+    Memory,
+    /// Network usage, sockets, and interfaces.
+    Network,
+    Processes,
+    /// Temperatures and sensors.
+    Temperature,
+    Disk,
+    Battery,
+}
+
+impl std::fmt::Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DataSource::Cpu => "CPU",
+            DataSource::Memory => "Memory",
+            DataSource::Network => "Network",
+            DataSource::Processes => "Processes",
+            DataSource::Temperature => "Temperature",
+            DataSource::Disk => "Disk",
+            DataSource::Battery => "Battery",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// What went wrong while collecting a [`DataSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectorErrorKind {
+    Failed,
+    TimedOut,
+    /// Some of the data couldn't be read due to a lack of permissions.
+    PermissionDenied,
+}
+
+impl std::fmt::Display for CollectorErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            CollectorErrorKind::Failed => "Error",
+            CollectorErrorKind::TimedOut => "Timeout",
+            CollectorErrorKind::PermissionDenied => "Permission",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// An error from collecting a [`DataSource`].
+#[derive(Clone, Debug)]
+pub struct CollectorError {
+    pub source: DataSource,
+    pub kind: CollectorErrorKind,
+    pub message: String,
+    /// When the error happened.
+    pub instant: Instant,
+}
+
+impl CollectorError {
+    pub fn new(source: DataSource, kind: CollectorErrorKind, message: String) -> Self {
+        CollectorError {
+            source,
+            kind,
+            message,
+            instant: Instant::now(),
+        }
+    }
+}
+
+/// How often each [`DataSource`] is collected, in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollectionIntervals {
+    pub cpu: u64,
+    pub memory: u64,
+    pub network: u64,
+    pub processes: u64,
+    pub temperature: u64,
+    pub disk: u64,
+    pub battery: u64,
+}
+
+impl CollectionIntervals {
+    /// Collects every source at the same interval.
+    pub fn uniform(interval: u64) -> Self {
+        CollectionIntervals {
+            cpu: interval,
+            memory: interval,
+            network: interval,
+            processes: interval,
+            temperature: interval,
+            disk: interval,
+            battery: interval,
+        }
+    }
+
+    pub fn get(&self, source: DataSource) -> u64 {
+        match source {
+            DataSource::Cpu => self.cpu,
+            DataSource::Memory => self.memory,
+            DataSource::Network => self.network,
+            DataSource::Processes => self.processes,
+            DataSource::Temperature => self.temperature,
+            DataSource::Disk => self.disk,
+            DataSource::Battery => self.battery,
+        }
+    }
+
+    /// The shortest interval, which is how often the collection thread has to wake up.
+    pub fn shortest(&self) -> u64 {
+        [
+            self.cpu,
+            self.memory,
+            self.network,
+            self.processes,
+            self.temperature,
+            self.disk,
+            self.battery,
+        ]
+        .iter()
+        .copied()
+        .min()
+        .unwrap_or(self.cpu)
+    }
+
+    /// How long collecting a source may take before it is given up on.  This is the source's
+    /// interval, since taking any longer than that means it can't keep up anyway, but never less
+    /// than [`MINIMUM_COLLECTOR_TIMEOUT_IN_MILLISECONDS`].
+    pub fn timeout(&self, source: DataSource) -> Duration {
+        Duration::from_millis(
+            self.get(source)
+                .max(MINIMUM_COLLECTOR_TIMEOUT_IN_MILLISECONDS),
+        )
+    }
+
+    /// Whether a source last collected `elapsed_ms` ago is due again.  Sources are only checked
+    /// every [`CollectionIntervals::shortest`] milliseconds, so this rounds to the nearest check
+    /// rather than letting a little timer jitter push a source back a whole check.
+    fn is_due(&self, source: DataSource, elapsed_ms: u64) -> bool {
+        elapsed_ms + self.shortest() / 2 >= self.get(source)
+    }
+}
+
+/// What collecting processes hands back: the processes, and the state to collect them with next
+/// time.
+#[cfg(target_os = "linux")]
+type ProcessCollection = (
+    crate::utils::error::Result<Vec<processes::ProcessHarvest>>,
+    f64,
+    f64,
+    FxHashMap<crate::Pid, processes::PrevProcDetails>,
+);
+
 #[derive(Debug)]
 pub struct DataCollector {
     pub data: Data,
@@ -91,18 +290,58 @@ pub struct DataCollector {
     prev_idle: f64,
     #[cfg(target_os = "linux")]
     prev_non_idle: f64,
+    #[cfg(target_os = "linux")]
+    process_details_to_collect: processes::ProcessDetailsToCollect,
+    #[cfg(target_os = "linux")]
+    socket_owner_cache: network::sockets::SocketOwnerCache,
+    #[cfg(target_os = "linux")]
+    process_collector: BlockingCollector<ProcessCollection>,
+    #[cfg(target_os = "linux")]
+    sockets_collector: BlockingCollector<(
+        Vec<network::sockets::SocketHarvest>,
+        network::sockets::SocketOwnerCache,
+    )>,
+    /// The processes to collect the connections of, if the connections dialog is open.
+    #[cfg(target_os = "linux")]
+    connection_pids: Option<Vec<crate::Pid>>,
+    #[cfg(target_os = "linux")]
+    connections_collector: BlockingCollector<network::sockets::ConnectionsHarvest>,
+    #[cfg(target_os = "linux")]
+    pressure_collector: BlockingCollector<std::io::Result<Option<pressure::PressureHarvest>>>,
+    #[cfg(target_os = "linux")]
+    sensors_collector: BlockingCollector<std::io::Result<Vec<sensors::SensorHarvest>>>,
+    #[cfg(target_os = "linux")]
+    interfaces_collector:
+        BlockingCollector<std::io::Result<Vec<network::interfaces::InterfaceHarvest>>>,
+    #[cfg(target_os = "linux")]
+    kernel_stats_collector: BlockingCollector<std::io::Result<processes::KernelStatHarvest>>,
+    #[cfg(target_os = "linux")]
+    cpu_freq_collector: BlockingCollector<std::io::Result<cpu::CpuFreqHarvest>>,
+    #[cfg(feature = "battery")]
+    battery_collector: BlockingCollector<battery::Result<Vec<batteries::BatteryHarvest>>>,
     mem_total_kb: u64,
     temperature_type: temperature::TemperatureType,
+    /// Where procfs is mounted; `/proc` unless monitoring another root, like a host from a container.
+    #[cfg(target_os = "linux")]
+    proc_root: std::path::PathBuf,
+    /// Where sysfs is mounted; `/sys` unless monitoring another root, like a host from a container.
+    #[cfg(target_os = "linux")]
+    sys_root: std::path::PathBuf,
     use_current_cpu_total: bool,
     last_collection_time: Instant,
     total_rx: u64,
     total_tx: u64,
     show_average_cpu: bool,
     widgets_to_harvest: UsedWidgets,
-    #[cfg(feature = "battery")]
-    battery_manager: Option<Manager>,
-    #[cfg(feature = "battery")]
-    battery_list: Option<Vec<Battery>>,
+    collection_intervals: CollectionIntervals,
+    /// When each source was last collected.  A source missing from here is always due.
+    last_collected: HashMap<DataSource, Instant>,
+    /// Mounts whose usage query timed out.
+    hung_mounts: HashMap<String, disks::HungMount>,
+    /// Sources whose last collection timed out.
+    stale_sources: HashSet<DataSource>,
+    /// Sources whose last collection failed.
+    failing_sources: HashSet<DataSource>,
     filters: DataFilters,
 }
 
@@ -120,18 +359,49 @@ impl DataCollector {
             prev_idle: 0_f64,
             #[cfg(target_os = "linux")]
             prev_non_idle: 0_f64,
+            #[cfg(target_os = "linux")]
+            process_details_to_collect: processes::ProcessDetailsToCollect::default(),
+            #[cfg(target_os = "linux")]
+            socket_owner_cache: network::sockets::SocketOwnerCache::default(),
+            #[cfg(target_os = "linux")]
+            process_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            sockets_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            connection_pids: None,
+            #[cfg(target_os = "linux")]
+            connections_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            pressure_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            sensors_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            interfaces_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            kernel_stats_collector: BlockingCollector::default(),
+            #[cfg(target_os = "linux")]
+            cpu_freq_collector: BlockingCollector::default(),
+            #[cfg(feature = "battery")]
+            battery_collector: BlockingCollector::default(),
             mem_total_kb: 0,
             temperature_type: temperature::TemperatureType::Celsius,
+            #[cfg(target_os = "linux")]
+            proc_root: std::path::PathBuf::from("/proc"),
+            #[cfg(target_os = "linux")]
+            sys_root: std::path::PathBuf::from("/sys"),
             use_current_cpu_total: false,
             last_collection_time: Instant::now(),
             total_rx: 0,
             total_tx: 0,
             show_average_cpu: false,
             widgets_to_harvest: UsedWidgets::default(),
-            #[cfg(feature = "battery")]
-            battery_manager: None,
-            #[cfg(feature = "battery")]
-            battery_list: None,
+            collection_intervals: CollectionIntervals::uniform(
+                crate::constants::DEFAULT_REFRESH_RATE_IN_MILLISECONDS,
+            ),
+            last_collected: HashMap::default(),
+            hung_mounts: HashMap::default(),
+            stale_sources: HashSet::default(),
+            failing_sources: HashSet::default(),
             filters,
         }
     }
@@ -158,27 +428,16 @@ impl DataCollector {
             }
         }
 
-        #[cfg(feature = "battery")]
-        {
-            if self.widgets_to_harvest.use_battery {
-                if let Ok(battery_manager) = Manager::new() {
-                    if let Ok(batteries) = battery_manager.batteries() {
-                        let battery_list: Vec<Battery> = batteries.filter_map(Result::ok).collect();
-                        if !battery_list.is_empty() {
-                            self.battery_list = Some(battery_list);
-                            self.battery_manager = Some(battery_manager);
-                        }
-                    }
-                }
-            }
-        }
-
         futures::executor::block_on(self.update_data());
 
         std::thread::sleep(std::time::Duration::from_millis(250));
 
         self.data.cleanup();
 
+        // Everything was just collected to get a baseline, so let the first real update collect
+        // everything again rather than waiting out each interval.
+        self.last_collected.clear();
+
         // trace!("Enabled widgets to harvest: {:#?}", self.widgets_to_harvest);
     }
 
@@ -191,6 +450,29 @@ impl DataCollector {
         };
     }
 
+    pub fn set_collection_intervals(&mut self, collection_intervals: CollectionIntervals) {
+        self.collection_intervals = collection_intervals;
+    }
+
+    /// If `source` is due to be collected at `now`, marks it as collected and returns when it was
+    /// previously collected.
+    fn take_due(&mut self, source: DataSource, now: Instant) -> Option<Instant> {
+        let previous = self.last_collected.get(&source).copied();
+        let is_due = previous
+            .map(|previous| {
+                let elapsed_ms = now.duration_since(previous).as_millis() as u64;
+                self.collection_intervals.is_due(source, elapsed_ms)
+            })
+            .unwrap_or(true);
+
+        if is_due {
+            self.last_collected.insert(source, now);
+            Some(previous.unwrap_or(self.last_collection_time))
+        } else {
+            None
+        }
+    }
+
     pub fn set_collected_data(&mut self, used_widgets: UsedWidgets) {
         self.widgets_to_harvest = used_widgets;
     }
@@ -207,124 +489,321 @@ impl DataCollector {
         self.show_average_cpu = show_average_cpu;
     }
 
+    #[cfg(target_os = "linux")]
+    pub fn set_process_details_to_collect(
+        &mut self, process_details_to_collect: processes::ProcessDetailsToCollect,
+    ) {
+        self.process_details_to_collect = process_details_to_collect;
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn set_connection_pids(&mut self, connection_pids: Option<Vec<crate::Pid>>) {
+        self.connection_pids = connection_pids;
+    }
+
+    /// Sets where procfs is mounted.  Heim only allows this to be set once, so this must be
+    /// called before anything is collected.
+    #[cfg(target_os = "linux")]
+    pub fn set_proc_root(&mut self, proc_root: std::path::PathBuf) {
+        heim::os::linux::set_procfs_root(proc_root.clone());
+        self.proc_root = proc_root;
+    }
+
+    /// Sets where sysfs is mounted.  Like [`DataCollector::set_proc_root`], this must be called
+    /// before anything is collected.
+    #[cfg(target_os = "linux")]
+    pub fn set_sys_root(&mut self, sys_root: std::path::PathBuf) {
+        heim::os::linux::set_sysfs_root(sys_root.clone());
+        self.sys_root = sys_root;
+    }
+
     pub async fn update_data(&mut self) {
+        let current_instant = std::time::Instant::now();
+        let collection_intervals = self.collection_intervals;
+        let timeout = |source| collection_intervals.timeout(source);
+
+        // Each of these is when the source was previously collected, if it's due now.
+        let cpu_due = self.take_due(DataSource::Cpu, current_instant);
+        let mem_due = self.take_due(DataSource::Memory, current_instant);
+        let net_due = self.take_due(DataSource::Network, current_instant);
+        let proc_due = self.take_due(DataSource::Processes, current_instant);
+        let temp_due = self.take_due(DataSource::Temperature, current_instant);
+        let disk_due = self.take_due(DataSource::Disk, current_instant);
+        let battery_due = self.take_due(DataSource::Battery, current_instant);
+
         #[cfg(not(target_os = "linux"))]
         {
-            if self.widgets_to_harvest.use_proc {
+            if self.widgets_to_harvest.use_proc && proc_due.is_some() {
                 self.sys.refresh_cpu();
                 self.sys.refresh_processes();
             }
-            if self.widgets_to_harvest.use_temp {
+            if self.widgets_to_harvest.use_temp && temp_due.is_some() {
                 self.sys.refresh_components();
             }
 
-            if cfg!(target_os = "windows") && self.widgets_to_harvest.use_net {
+            if cfg!(target_os = "windows") && self.widgets_to_harvest.use_net && net_due.is_some() {
                 self.sys.refresh_networks();
             }
         }
 
-        let current_instant = std::time::Instant::now();
-
-        // CPU
-        if self.widgets_to_harvest.use_cpu {
-            if let Ok(cpu_data) = cpu::get_cpu_data_list(
-                self.show_average_cpu,
-                &mut self.previous_cpu_times,
-                &mut self.previous_average_cpu_time,
-            )
-            .await
-            {
-                self.data.cpu = Some(cpu_data);
-            }
-
-            #[cfg(target_family = "unix")]
-            {
-                // Load Average
-                if let Ok(load_avg_data) = cpu::get_load_avg().await {
-                    self.data.load_avg = Some(load_avg_data);
-                }
-            }
-        }
-
-        // Batteries
-        #[cfg(feature = "battery")]
+        #[cfg(not(target_os = "linux"))]
         {
-            if let Some(battery_manager) = &self.battery_manager {
-                if let Some(battery_list) = &mut self.battery_list {
-                    self.data.list_of_batteries =
-                        Some(batteries::refresh_batteries(battery_manager, battery_list));
+            if self.widgets_to_harvest.use_proc && proc_due.is_some() {
+                if let Ok(process_list) = processes::get_process_data(
+                    &self.sys,
+                    self.use_current_cpu_total,
+                    current_instant
+                        .duration_since(proc_due.unwrap_or(self.last_collection_time))
+                        .as_secs_f64(),
+                    self.mem_total_kb,
+                ) {
+                    self.data.list_of_processes = Some(process_list);
                 }
             }
         }
 
-        if self.widgets_to_harvest.use_proc {
-            if let Ok(process_list) = {
-                #[cfg(target_os = "linux")]
-                {
-                    processes::get_process_data(
-                        &mut self.prev_idle,
-                        &mut self.prev_non_idle,
-                        &mut self.pid_mapping,
-                        self.use_current_cpu_total,
-                        current_instant
-                            .duration_since(self.last_collection_time)
-                            .as_secs(),
-                        self.mem_total_kb,
-                    )
+        // Everything below runs concurrently, each with its own timeout, so that one hung source
+        // only leaves that source stale rather than stalling every widget.  Collectors that only
+        // read files without going through heim are run on the blocking thread pool so that
+        // they can be timed out as well.
+        #[cfg(target_os = "linux")]
+        let linux_fut = {
+            let process_fut =
+                OptionFuture::from(if self.widgets_to_harvest.use_proc && proc_due.is_some() {
+                    let mut prev_idle = self.prev_idle;
+                    let mut prev_non_idle = self.prev_non_idle;
+                    // This is taken rather than borrowed so it can be moved to the blocking thread
+                    // pool.  It comes back with the processes, even if they come late.
+                    let mut pid_mapping = std::mem::take(&mut self.pid_mapping);
+                    let use_current_cpu_total = self.use_current_cpu_total;
+                    let time_difference_in_secs = current_instant
+                        .duration_since(proc_due.unwrap_or(self.last_collection_time))
+                        .as_secs_f64();
+                    let mem_total_kb = self.mem_total_kb;
+                    let process_details_to_collect = self.process_details_to_collect;
+                    let proc_root = self.proc_root.clone();
+
+                    Some(self.process_collector.run(
+                        move || {
+                            let process_list = processes::get_process_data(
+                                &mut prev_idle,
+                                &mut prev_non_idle,
+                                &mut pid_mapping,
+                                use_current_cpu_total,
+                                time_difference_in_secs,
+                                mem_total_kb,
+                                process_details_to_collect,
+                                &proc_root,
+                            );
+                            (process_list, prev_idle, prev_non_idle, pid_mapping)
+                        },
+                        timeout(DataSource::Processes),
+                    ))
+                } else {
+                    None
+                });
+
+            let sockets_fut =
+                OptionFuture::from(if self.widgets_to_harvest.use_socket && net_due.is_some() {
+                    let proc_root = self.proc_root.clone();
+                    // Like the PID mapping, this comes back with the sockets.
+                    let mut socket_owner_cache = std::mem::take(&mut self.socket_owner_cache);
+                    Some(self.sockets_collector.run(
+                        move || {
+                            let sockets = network::sockets::get_all_sockets(
+                                &proc_root,
+                                &mut socket_owner_cache,
+                            );
+                            (sockets, socket_owner_cache)
+                        },
+                        timeout(DataSource::Network),
+                    ))
+                } else {
+                    None
+                });
+
+            let connections_fut = OptionFuture::from(match &self.connection_pids {
+                Some(pids) if net_due.is_some() => {
+                    let proc_root = self.proc_root.clone();
+                    let pids = pids.clone();
+                    Some(self.connections_collector.run(
+                        move || {
+                            let connections =
+                                network::sockets::get_process_sockets(&pids, &proc_root)
+                                    .map_err(|err| err.to_string());
+                            network::sockets::ConnectionsHarvest { pids, connections }
+                        },
+                        timeout(DataSource::Network),
+                    ))
                 }
-                #[cfg(not(target_os = "linux"))]
+                _ => None,
+            });
+
+            let pressure_fut = OptionFuture::from(
+                if self.widgets_to_harvest.use_pressure && cpu_due.is_some() {
+                    let proc_root = self.proc_root.clone();
+                    Some(self.pressure_collector.run(
+                        move || pressure::get_pressure_data(&proc_root),
+                        timeout(DataSource::Cpu),
+                    ))
+                } else {
+                    None
+                },
+            );
+
+            let sensors_fut = OptionFuture::from(
+                if self.widgets_to_harvest.use_sensor && temp_due.is_some() {
+                    let sys_root = self.sys_root.clone();
+                    Some(self.sensors_collector.run(
+                        move || sensors::get_sensor_data(&sys_root),
+                        timeout(DataSource::Temperature),
+                    ))
+                } else {
+                    None
+                },
+            );
+
+            let interfaces_fut = OptionFuture::from(
+                if self.widgets_to_harvest.use_net_interface && net_due.is_some() {
+                    let proc_root = self.proc_root.clone();
+                    let sys_root = self.sys_root.clone();
+                    let net_filter = self.filters.net_filter.clone();
+                    Some(self.interfaces_collector.run(
+                        move || {
+                            network::interfaces::get_interface_data(
+                                &proc_root,
+                                &sys_root,
+                                &net_filter,
+                            )
+                        },
+                        timeout(DataSource::Network),
+                    ))
+                } else {
+                    None
+                },
+            );
+
+            let kernel_stats_fut =
+                OptionFuture::from(if self.widgets_to_harvest.use_cpu && cpu_due.is_some() {
+                    let proc_root = self.proc_root.clone();
+                    Some(self.kernel_stats_collector.run(
+                        move || processes::get_kernel_stats(&proc_root),
+                        timeout(DataSource::Cpu),
+                    ))
+                } else {
+                    None
+                });
+
+            let cpu_freq_fut = OptionFuture::from(
+                if (self.widgets_to_harvest.use_cpu || self.widgets_to_harvest.use_cpu_freq)
+                    && cpu_due.is_some()
                 {
-                    processes::get_process_data(
-                        &self.sys,
-                        self.use_current_cpu_total,
-                        self.mem_total_kb,
-                    )
-                }
-            } {
-                self.data.list_of_processes = Some(process_list);
+                    let sys_root = self.sys_root.clone();
+                    Some(self.cpu_freq_collector.run(
+                        move || cpu::freq::get_cpu_freq_data(&sys_root),
+                        timeout(DataSource::Cpu),
+                    ))
+                } else {
+                    None
+                },
+            );
+
+            async {
+                join!(
+                    process_fut,
+                    sockets_fut,
+                    connections_fut,
+                    pressure_fut,
+                    sensors_fut,
+                    interfaces_fut,
+                    kernel_stats_fut,
+                    cpu_freq_fut
+                )
             }
-        }
+        };
+
+        // CPU; the load average graph also needs this for the core count
+        let collect_cpu = (self.widgets_to_harvest.use_cpu || self.widgets_to_harvest.use_load_avg)
+            && cpu_due.is_some();
+        let cpu_data_fut = OptionFuture::from(if collect_cpu {
+            Some(with_timeout(
+                cpu::get_cpu_data_list(
+                    self.show_average_cpu,
+                    &mut self.previous_cpu_times,
+                    &mut self.previous_average_cpu_time,
+                ),
+                timeout(DataSource::Cpu),
+            ))
+        } else {
+            None
+        });
+        #[cfg(target_family = "unix")]
+        let load_avg_fut = OptionFuture::from(if collect_cpu {
+            Some(with_timeout(cpu::get_load_avg(), timeout(DataSource::Cpu)))
+        } else {
+            None
+        });
+        #[cfg(not(target_family = "unix"))]
+        let load_avg_fut = futures::future::ready(
+            None::<Option<crate::utils::error::Result<cpu::LoadAvgHarvest>>>,
+        );
 
+        let previous_net_time = net_due.unwrap_or(self.last_collection_time);
         let network_data_fut = {
             #[cfg(target_os = "windows")]
             {
                 network::get_network_data(
                     &self.sys,
-                    self.last_collection_time,
+                    previous_net_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
                     current_instant,
-                    self.widgets_to_harvest.use_net,
+                    self.widgets_to_harvest.use_net && net_due.is_some(),
                     &self.filters.net_filter,
                 )
             }
             #[cfg(not(target_os = "windows"))]
             {
                 network::get_network_data(
-                    self.last_collection_time,
+                    previous_net_time,
                     &mut self.total_rx,
                     &mut self.total_tx,
                     current_instant,
-                    self.widgets_to_harvest.use_net,
+                    self.widgets_to_harvest.use_net && net_due.is_some(),
                     &self.filters.net_filter,
                 )
             }
         };
-        let mem_data_fut = memory::get_mem_data(self.widgets_to_harvest.use_mem);
+        let mem_data_fut = memory::get_mem_data(
+            self.widgets_to_harvest.use_mem && mem_due.is_some(),
+            #[cfg(target_os = "linux")]
+            &self.proc_root,
+        );
+        // Disk usage times out each mount on its own, so it isn't wrapped in a timeout here.
         let disk_data_fut = disks::get_disk_usage(
-            self.widgets_to_harvest.use_disk,
+            self.widgets_to_harvest.use_disk && disk_due.is_some(),
             &self.filters.disk_filter,
             &self.filters.mount_filter,
+            &mut self.hung_mounts,
+            timeout(DataSource::Disk),
+            #[cfg(target_os = "linux")]
+            &self.proc_root,
         );
-        let disk_io_usage_fut = disks::get_io_usage(self.widgets_to_harvest.use_disk);
+        let disk_io_usage_fut = disks::get_io_usage(
+            self.widgets_to_harvest.use_disk && disk_due.is_some(),
+            #[cfg(target_os = "linux")]
+            &self.proc_root,
+        );
+        let mut temperatures_filtered_out = 0;
         let temp_data_fut = {
             #[cfg(not(target_os = "linux"))]
             {
                 temperature::get_temperature_data(
                     &self.sys,
                     &self.temperature_type,
-                    self.widgets_to_harvest.use_temp,
+                    self.widgets_to_harvest.use_temp && temp_due.is_some(),
                     &self.filters.temp_filter,
+                    &mut temperatures_filtered_out,
                 )
             }
 
@@ -332,21 +811,153 @@ impl DataCollector {
             {
                 temperature::get_temperature_data(
                     &self.temperature_type,
-                    self.widgets_to_harvest.use_temp,
+                    self.widgets_to_harvest.use_temp && temp_due.is_some(),
                     &self.filters.temp_filter,
+                    &mut temperatures_filtered_out,
                 )
             }
         };
 
-        let (net_data, mem_res, disk_res, io_res, temp_res) = join!(
-            network_data_fut,
-            mem_data_fut,
-            disk_data_fut,
-            disk_io_usage_fut,
-            temp_data_fut
+        #[cfg(feature = "battery")]
+        let battery_fut = OptionFuture::from(
+            if self.widgets_to_harvest.use_battery && battery_due.is_some() {
+                Some(
+                    self.battery_collector
+                        .run(batteries::get_batteries, timeout(DataSource::Battery)),
+                )
+            } else {
+                None
+            },
         );
+        #[cfg(not(feature = "battery"))]
+        let battery_fut = futures::future::ready(None::<Option<()>>);
+
+        let common_fut = async {
+            join!(
+                cpu_data_fut,
+                load_avg_fut,
+                with_timeout(network_data_fut, timeout(DataSource::Network)),
+                with_timeout(mem_data_fut, timeout(DataSource::Memory)),
+                disk_data_fut,
+                with_timeout(disk_io_usage_fut, timeout(DataSource::Disk)),
+                with_timeout(temp_data_fut, timeout(DataSource::Temperature)),
+                battery_fut
+            )
+        };
 
-        if let Ok(net_data) = net_data {
+        #[cfg(target_os = "linux")]
+        let (
+            (cpu_res, load_avg_res, net_res, mem_res, disk_res, io_res, temp_res, battery_res),
+            linux_res,
+        ) = join!(common_fut, linux_fut);
+        #[cfg(not(target_os = "linux"))]
+        let (cpu_res, load_avg_res, net_res, mem_res, disk_res, io_res, temp_res, battery_res) =
+            common_fut.await;
+
+        let mut errors = Vec::new();
+
+        #[cfg(target_os = "linux")]
+        {
+            let (
+                process_res,
+                sockets_res,
+                connections_res,
+                pressure_res,
+                sensors_res,
+                interfaces_res,
+                kernel_stats_res,
+                cpu_freq_res,
+            ) = linux_res;
+
+            if let Some((process_list, prev_idle, prev_non_idle, pid_mapping)) = process_res
+                .and_then(|res| note_timeout(res, DataSource::Processes, "processes", &mut errors))
+            {
+                self.prev_idle = prev_idle;
+                self.prev_non_idle = prev_non_idle;
+                self.pid_mapping = pid_mapping;
+                if let Some(process_list) = note_error(
+                    process_list,
+                    DataSource::Processes,
+                    "processes",
+                    &mut errors,
+                ) {
+                    if process_list.iter().any(|process| process.io_denied) {
+                        errors.push(CollectorError::new(
+                            DataSource::Processes,
+                            CollectorErrorKind::PermissionDenied,
+                            "Could not read the I/O of some processes; run as root to see them"
+                                .to_string(),
+                        ));
+                    }
+                    self.data.list_of_processes = Some(process_list);
+                }
+            }
+
+            if let Some((sockets, socket_owner_cache)) = sockets_res
+                .and_then(|res| note_timeout(res, DataSource::Network, "sockets", &mut errors))
+            {
+                if socket_owner_cache.is_denied() {
+                    errors.push(CollectorError::new(
+                        DataSource::Network,
+                        CollectorErrorKind::PermissionDenied,
+                        "Could not find the owners of some sockets; run as root to see them"
+                            .to_string(),
+                    ));
+                }
+                self.data.sockets = Some(sockets);
+                self.socket_owner_cache = socket_owner_cache;
+            }
+
+            if let Some(connections) = connections_res
+                .and_then(|res| note_timeout(res, DataSource::Network, "connections", &mut errors))
+            {
+                self.data.connections = Some(connections);
+            }
+
+            if let Some(pressure) = pressure_res
+                .and_then(|res| note_result(res, DataSource::Cpu, "pressure", &mut errors))
+            {
+                self.data.pressure = pressure;
+            }
+
+            if let Some(sensors) = sensors_res
+                .and_then(|res| note_result(res, DataSource::Temperature, "sensors", &mut errors))
+            {
+                self.data.sensors = Some(sensors);
+            }
+
+            if let Some(interfaces) = interfaces_res.and_then(|res| {
+                note_result(res, DataSource::Network, "network interfaces", &mut errors)
+            }) {
+                self.data.interfaces = Some(interfaces);
+            }
+
+            if let Some(kernel_stats) = kernel_stats_res
+                .and_then(|res| note_result(res, DataSource::Cpu, "kernel activity", &mut errors))
+            {
+                self.data.kernel_stats = Some(kernel_stats);
+            }
+
+            if let Some(cpu_freq) = cpu_freq_res
+                .and_then(|res| note_result(res, DataSource::Cpu, "CPU frequency", &mut errors))
+            {
+                self.data.cpu_freq = Some(cpu_freq);
+            }
+        }
+
+        if let Some(cpu_data) =
+            cpu_res.and_then(|res| note_result(res, DataSource::Cpu, "CPU usage", &mut errors))
+        {
+            self.data.cpu = Some(cpu_data);
+        }
+
+        if let Some(load_avg_data) = load_avg_res
+            .and_then(|res| note_result(res, DataSource::Cpu, "load average", &mut errors))
+        {
+            self.data.load_avg = Some(load_avg_data);
+        }
+
+        if let Some(net_data) = note_result(net_res, DataSource::Network, "network", &mut errors) {
             if let Some(net_data) = &net_data {
                 self.total_rx = net_data.total_rx;
                 self.total_tx = net_data.total_tx;
@@ -354,28 +965,305 @@ impl DataCollector {
             self.data.network = net_data;
         }
 
-        if let Ok(memory) = mem_res.0 {
-            self.data.memory = memory;
-        }
+        if let Some(mem_res) = note_timeout(mem_res, DataSource::Memory, "memory", &mut errors) {
+            if let Some(memory) = note_error(mem_res.0, DataSource::Memory, "memory", &mut errors) {
+                self.data.memory = memory;
+            }
 
-        if let Ok(swap) = mem_res.1 {
-            self.data.swap = swap;
+            if let Some(swap) = note_error(mem_res.1, DataSource::Memory, "swap", &mut errors) {
+                self.data.swap = swap;
+            }
         }
 
-        if let Ok(disks) = disk_res {
+        if let Some(disks) = note_error(disk_res, DataSource::Disk, "disk usage", &mut errors) {
             self.data.disks = disks;
         }
 
-        if let Ok(io) = io_res {
+        if let Some(io) = note_result(io_res, DataSource::Disk, "disk I/O", &mut errors) {
             self.data.io = io;
         }
 
-        if let Ok(temp) = temp_res {
+        if let Some(temp) = note_result(
+            temp_res,
+            DataSource::Temperature,
+            "temperatures",
+            &mut errors,
+        ) {
             self.data.temperature_sensors = temp;
+            self.data.temperatures_filtered_out = temperatures_filtered_out;
+        }
+
+        #[cfg(feature = "battery")]
+        if let Some(batteries) = battery_res
+            .and_then(|res| note_timeout(res, DataSource::Battery, "batteries", &mut errors))
+            .and_then(|res| note_error(res, DataSource::Battery, "batteries", &mut errors))
+        {
+            self.data.list_of_batteries = Some(batteries);
+        }
+        #[cfg(not(feature = "battery"))]
+        let _ = battery_res;
+
+        // A source that was collected this time is only stale or failing if something in it timed
+        // out or failed this time; one that wasn't due keeps whatever it was before.
+        for (source, due) in [
+            (DataSource::Cpu, cpu_due),
+            (DataSource::Memory, mem_due),
+            (DataSource::Network, net_due),
+            (DataSource::Processes, proc_due),
+            (DataSource::Temperature, temp_due),
+            (DataSource::Disk, disk_due),
+            (DataSource::Battery, battery_due),
+        ] {
+            if due.is_some() {
+                let has_error = |kind| {
+                    errors
+                        .iter()
+                        .any(|error: &CollectorError| error.source == source && error.kind == kind)
+                };
+
+                if has_error(CollectorErrorKind::TimedOut) {
+                    self.stale_sources.insert(source);
+                } else {
+                    self.stale_sources.remove(&source);
+                }
+
+                if has_error(CollectorErrorKind::Failed) {
+                    self.failing_sources.insert(source);
+                } else {
+                    self.failing_sources.remove(&source);
+                }
+            }
         }
+        self.data.stale_sources = self.stale_sources.clone();
+        self.data.failing_sources = self.failing_sources.clone();
+        self.data.errors.append(&mut errors);
 
         // Update time
         self.data.last_collection_time = current_instant;
         self.last_collection_time = current_instant;
     }
 }
+
+/// Awaits `fut`, giving up on it after `timeout`.  Returns [`None`] if it timed out.
+///
+/// This can only give up on a future while it is waiting on something, so anything that blocks
+/// has to be run on the blocking thread pool, like heim's file reads on Linux or
+/// [`unblock_with_timeout`].  That work keeps blocking its thread, but nothing waits on it
+/// anymore.
+pub async fn with_timeout<F: Future>(fut: F, timeout: Duration) -> Option<F::Output> {
+    futures::pin_mut!(fut);
+    match futures::future::select(fut, futures_timer::Delay::new(timeout)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Runs a blocking collector on the blocking thread pool, giving up on it after `timeout`.
+pub async fn unblock_with_timeout<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static, timeout: Duration,
+) -> Option<T> {
+    with_timeout(smol::unblock(f), timeout).await
+}
+
+/// A blocking collector that is run on the blocking thread pool, like [`unblock_with_timeout`].
+/// If a run times out, it is left to finish rather than started again, so that a collector that
+/// stays hung doesn't tie up another thread every time it is due, and whatever it hands back
+/// isn't lost.
+pub struct BlockingCollector<T> {
+    /// The run that timed out, and whether it is still blocking its thread.
+    in_flight: Option<(smol::Task<T>, Arc<AtomicBool>)>,
+}
+
+impl<T> Default for BlockingCollector<T> {
+    fn default() -> Self {
+        BlockingCollector { in_flight: None }
+    }
+}
+
+impl<T> std::fmt::Debug for BlockingCollector<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingCollector")
+            .field("in_flight", &self.in_flight.is_some())
+            .finish()
+    }
+}
+
+impl<T: Send + 'static> BlockingCollector<T> {
+    /// Runs `f`, giving up on it after `timeout`.  If the previous run timed out, `f` is dropped
+    /// instead; that run's result is returned once it has finished, and until then this returns
+    /// [`None`] right away, as if it had timed out again.
+    pub async fn run(
+        &mut self, f: impl FnOnce() -> T + Send + 'static, timeout: Duration,
+    ) -> Option<T> {
+        let (mut task, is_querying) = match self.in_flight.take() {
+            Some((task, is_querying)) if is_querying.load(Ordering::Acquire) => {
+                self.in_flight = Some((task, is_querying));
+                return None;
+            }
+            Some(in_flight) => in_flight,
+            None => {
+                let is_querying = Arc::new(AtomicBool::new(true));
+                let task = smol::unblock({
+                    let is_querying = is_querying.clone();
+                    move || {
+                        let result = f();
+                        is_querying.store(false, Ordering::Release);
+                        result
+                    }
+                });
+                (task, is_querying)
+            }
+        };
+
+        let result = with_timeout(&mut task, timeout).await;
+        if result.is_none() {
+            self.in_flight = Some((task, is_querying));
+        }
+        result
+    }
+}
+
+/// Passes through a collector's result, recording an error if there is no result because the
+/// collector timed out.
+fn note_timeout<T>(
+    result: Option<T>, source: DataSource, collector: &str, errors: &mut Vec<CollectorError>,
+) -> Option<T> {
+    if result.is_none() {
+        errors.push(CollectorError::new(
+            source,
+            CollectorErrorKind::TimedOut,
+            format!("Collecting {} timed out", collector),
+        ));
+    }
+    result
+}
+
+/// Passes through a collector's result, recording an error if it failed.
+fn note_error<T, E: std::fmt::Display>(
+    result: Result<T, E>, source: DataSource, collector: &str, errors: &mut Vec<CollectorError>,
+) -> Option<T> {
+    match result {
+        Ok(result) => Some(result),
+        Err(err) => {
+            errors.push(CollectorError::new(
+                source,
+                CollectorErrorKind::Failed,
+                format!("Could not collect {}: {}", collector, err),
+            ));
+            None
+        }
+    }
+}
+
+/// Combines [`note_timeout`] and [`note_error`] for a collector that can both time out and fail.
+fn note_result<T, E: std::fmt::Display>(
+    result: Option<Result<T, E>>, source: DataSource, collector: &str,
+    errors: &mut Vec<CollectorError>,
+) -> Option<T> {
+    note_timeout(result, source, collector, errors)
+        .and_then(|result| note_error(result, source, collector, errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_intervals_due() {
+        let intervals = CollectionIntervals {
+            cpu: 500,
+            processes: 2000,
+            disk: 10000,
+            ..CollectionIntervals::uniform(1000)
+        };
+        assert_eq!(intervals.shortest(), 500);
+
+        // Checks land every 500ms, give or take a little jitter.
+        assert!(intervals.is_due(DataSource::Cpu, 498));
+        assert!(!intervals.is_due(DataSource::Processes, 1503));
+        assert!(intervals.is_due(DataSource::Processes, 1998));
+        assert!(intervals.is_due(DataSource::Processes, 2004));
+        assert!(!intervals.is_due(DataSource::Disk, 9500));
+        assert!(intervals.is_due(DataSource::Disk, 9999));
+    }
+
+    #[test]
+    fn test_collection_intervals_timeout() {
+        let intervals = CollectionIntervals {
+            cpu: 100,
+            disk: 10000,
+            ..CollectionIntervals::uniform(1000)
+        };
+
+        assert_eq!(
+            intervals.timeout(DataSource::Cpu),
+            Duration::from_millis(MINIMUM_COLLECTOR_TIMEOUT_IN_MILLISECONDS)
+        );
+        assert_eq!(
+            intervals.timeout(DataSource::Memory),
+            Duration::from_millis(1000)
+        );
+        assert_eq!(
+            intervals.timeout(DataSource::Disk),
+            Duration::from_millis(10000)
+        );
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        let timeout = Duration::from_millis(10);
+
+        assert_eq!(
+            futures::executor::block_on(with_timeout(async { 1 }, timeout)),
+            Some(1)
+        );
+        assert_eq!(
+            futures::executor::block_on(with_timeout(futures::future::pending::<()>(), timeout)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_blocking_collector_in_flight() {
+        let timeout = Duration::from_millis(10);
+        let mut collector = BlockingCollector::default();
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+
+        // The first run hangs, so it times out and is left running.
+        assert_eq!(
+            futures::executor::block_on(collector.run(
+                move || {
+                    wait.recv().unwrap();
+                    1
+                },
+                timeout
+            )),
+            None
+        );
+
+        // While it is still running, nothing else is started.
+        assert_eq!(
+            futures::executor::block_on(collector.run(|| 2, timeout)),
+            None
+        );
+
+        // Once it has finished, its result is handed back rather than running again.
+        release.send(()).unwrap();
+        while collector
+            .in_flight
+            .as_ref()
+            .map(|(_, is_querying)| is_querying.load(Ordering::Acquire))
+            .unwrap_or(false)
+        {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(
+            futures::executor::block_on(collector.run(|| 3, timeout)),
+            Some(1)
+        );
+        assert_eq!(
+            futures::executor::block_on(collector.run(|| 4, timeout)),
+            Some(4)
+        );
+    }
+}
@@ -10,10 +10,47 @@
 //! For more information, see https://github.com/svartalf/rust-battery
 
 use battery::{
-    units::{power::watt, ratio::percent, time::second},
-    Battery, Manager,
+    units::{
+        electric_potential::volt, energy::watt_hour, power::watt, ratio::percent, time::second,
+    },
+    Manager, State,
 };
 
+/// Whether a battery is charging, discharging, or neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChargeState {
+    Charging,
+    Discharging,
+    Full,
+    Empty,
+    Unknown,
+}
+
+impl From<State> for ChargeState {
+    fn from(state: State) -> Self {
+        match state {
+            State::Charging => ChargeState::Charging,
+            State::Discharging => ChargeState::Discharging,
+            State::Full => ChargeState::Full,
+            State::Empty => ChargeState::Empty,
+            _ => ChargeState::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for ChargeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            ChargeState::Charging => "Charging",
+            ChargeState::Discharging => "Discharging",
+            ChargeState::Full => "Full",
+            ChargeState::Empty => "Empty",
+            ChargeState::Unknown => "Unknown",
+        };
+        write!(f, "{}", state)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BatteryHarvest {
     pub charge_percent: f64,
@@ -21,29 +58,46 @@ pub struct BatteryHarvest {
     pub secs_until_empty: Option<i64>,
     pub power_consumption_rate_watts: f64,
     pub health_percent: f64,
+    pub state: ChargeState,
+    pub voltage_volts: f64,
+    pub cycle_count: Option<u32>,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+    pub energy_wh: f64,
+    pub energy_full_wh: f64,
+    pub energy_full_design_wh: f64,
 }
 
-pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<BatteryHarvest> {
-    batteries
-        .iter_mut()
-        .filter_map(|battery| {
-            if manager.refresh(battery).is_ok() {
-                Some(BatteryHarvest {
-                    secs_until_full: {
-                        let optional_time = battery.time_to_full();
-                        optional_time.map(|time| f64::from(time.get::<second>()) as i64)
-                    },
-                    secs_until_empty: {
-                        let optional_time = battery.time_to_empty();
-                        optional_time.map(|time| f64::from(time.get::<second>()) as i64)
-                    },
-                    charge_percent: f64::from(battery.state_of_charge().get::<percent>()),
-                    power_consumption_rate_watts: f64::from(battery.energy_rate().get::<watt>()),
-                    health_percent: f64::from(battery.state_of_health().get::<percent>()),
-                })
-            } else {
-                None
-            }
+/// Finds and reads every battery.  The battery crate's handles can't be sent between threads on
+/// every platform, so rather than being kept around, they are looked up again each time so that
+/// this can run on the blocking thread pool.
+pub fn get_batteries() -> battery::Result<Vec<BatteryHarvest>> {
+    let manager = Manager::new()?;
+    let batteries = manager
+        .batteries()?
+        .filter_map(Result::ok)
+        .map(|battery| BatteryHarvest {
+            secs_until_full: {
+                let optional_time = battery.time_to_full();
+                optional_time.map(|time| f64::from(time.get::<second>()) as i64)
+            },
+            secs_until_empty: {
+                let optional_time = battery.time_to_empty();
+                optional_time.map(|time| f64::from(time.get::<second>()) as i64)
+            },
+            charge_percent: f64::from(battery.state_of_charge().get::<percent>()),
+            power_consumption_rate_watts: f64::from(battery.energy_rate().get::<watt>()),
+            health_percent: f64::from(battery.state_of_health().get::<percent>()),
+            state: battery.state().into(),
+            voltage_volts: f64::from(battery.voltage().get::<volt>()),
+            cycle_count: battery.cycle_count(),
+            vendor: battery.vendor().map(|vendor| vendor.trim().to_string()),
+            model: battery.model().map(|model| model.trim().to_string()),
+            energy_wh: f64::from(battery.energy().get::<watt_hour>()),
+            energy_full_wh: f64::from(battery.energy_full().get::<watt_hour>()),
+            energy_full_design_wh: f64::from(battery.energy_full_design().get::<watt_hour>()),
         })
-        .collect::<Vec<_>>()
+        .collect();
+
+    Ok(batteries)
 }
@@ -0,0 +1,110 @@
+//! Linux-specific functions regarding CPU frequency.
+
+use std::path::Path;
+
+use super::{CpuFreqData, CpuFreqHarvest};
+
+/// Reads a cpufreq attribute, which is in kHz, as MHz.
+fn read_mhz(path: &Path) -> Option<f64> {
+    let khz: f64 = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    Some(khz / 1000.0)
+}
+
+/// Returns the core number of a directory name like `cpu12`, ignoring entries like `cpufreq`
+/// and `cpuidle`.
+fn parse_cpu_dir_name(dir_name: &str) -> Option<usize> {
+    dir_name.strip_prefix("cpu")?.parse().ok()
+}
+
+/// Reads the frequency of each core from `<sys_root>/devices/system/cpu/cpu*/cpufreq`.  Cores
+/// without cpufreq support (like in most VMs) are skipped.
+pub fn get_cpu_freq_data(sys_root: &Path) -> std::io::Result<CpuFreqHarvest> {
+    let cpu_dir = sys_root.join("devices/system/cpu");
+    let mut freq_harvest: CpuFreqHarvest = match std::fs::read_dir(&cpu_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let cpu = parse_cpu_dir_name(&entry.file_name().into_string().ok()?)?;
+                let cpufreq_dir = entry.path().join("cpufreq");
+                let read_attribute = |attributes: &[&str]| {
+                    attributes
+                        .iter()
+                        .find_map(|attribute| read_mhz(&cpufreq_dir.join(attribute)))
+                };
+
+                Some(CpuFreqData {
+                    cpu,
+                    current: read_attribute(&["scaling_cur_freq", "cpuinfo_cur_freq"])?,
+                    min: read_attribute(&["cpuinfo_min_freq", "scaling_min_freq"]),
+                    max: read_attribute(&["cpuinfo_max_freq", "scaling_max_freq"]),
+                })
+            })
+            .collect(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(err),
+    };
+    freq_harvest.sort_by_key(|freq| freq.cpu);
+
+    Ok(freq_harvest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::TempDir;
+
+    #[test]
+    fn test_cpufreq_fixture() {
+        let sys_root = TempDir::new("cpufreq");
+        let cpu_dir = sys_root.path().join("devices/system/cpu");
+        for (cpu, files) in [
+            (
+                "cpu0",
+                vec![
+                    ("scaling_cur_freq", "800000\n"),
+                    ("cpuinfo_min_freq", "400000\n"),
+                    ("cpuinfo_max_freq", "4700000\n"),
+                ],
+            ),
+            (
+                "cpu10",
+                vec![
+                    ("cpuinfo_cur_freq", "3200000\n"),
+                    ("scaling_max_freq", "3600000\n"),
+                ],
+            ),
+            ("cpu2", vec![("cpuinfo_max_freq", "4700000\n")]),
+            ("cpufreq", vec![("scaling_cur_freq", "1000\n")]),
+        ] {
+            let cpufreq_dir = cpu_dir.join(cpu).join("cpufreq");
+            std::fs::create_dir_all(&cpufreq_dir).unwrap();
+            for (file, contents) in files {
+                std::fs::write(cpufreq_dir.join(file), contents).unwrap();
+            }
+        }
+
+        let freq_harvest = get_cpu_freq_data(sys_root.path()).unwrap();
+
+        assert_eq!(
+            freq_harvest,
+            vec![
+                CpuFreqData {
+                    cpu: 0,
+                    current: 800.0,
+                    min: Some(400.0),
+                    max: Some(4700.0),
+                },
+                CpuFreqData {
+                    cpu: 10,
+                    current: 3200.0,
+                    min: None,
+                    max: Some(3600.0),
+                },
+            ]
+        );
+
+        assert!(get_cpu_freq_data(Path::new("/nonexistent"))
+            .unwrap()
+            .is_empty());
+    }
+}
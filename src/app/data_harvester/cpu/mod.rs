@@ -3,6 +3,8 @@
 //! For CPU usage, Linux, macOS, and Windows are handled by Heim.
 //!
 //! For load average, macOS and Linux are supported through Heim.
+//!
+//! For CPU frequency, only Linux is supported, through cpufreq in sysfs.
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))] {
@@ -11,4 +13,20 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(target_os = "linux")]
+pub mod freq;
+
 pub type LoadAvgHarvest = [f32; 3];
+
+/// The frequency of a single core, in MHz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CpuFreqData {
+    pub cpu: usize,
+    pub current: f64,
+    /// The lowest frequency the core can run at, if known.
+    pub min: Option<f64>,
+    /// The highest frequency the core can run at, if known.
+    pub max: Option<f64>,
+}
+
+pub type CpuFreqHarvest = Vec<CpuFreqData>;
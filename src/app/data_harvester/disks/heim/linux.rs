@@ -1,6 +1,92 @@
 //! Linux-specific things for Heim disk data collection.
 
-use heim::disk::Partition;
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+use super::DiskStats;
+
+/// A mounted partition.  This mirrors heim's, which can't be built from another procfs root.
+#[derive(Debug, Clone)]
+pub struct Partition {
+    device: String,
+    mount_point: PathBuf,
+}
+
+impl Partition {
+    pub fn device(&self) -> Option<&OsStr> {
+        Some(OsStr::new(&self.device))
+    }
+
+    pub fn mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+}
+
+/// Parses the contents of a `mounts` file, keeping only partitions with a device and a file
+/// system from `filesystems` that needs one, like heim's `partitions_physical`.
+fn parse_physical_partitions(mounts: &str, filesystems: &str) -> Vec<Partition> {
+    let physical_filesystems = filesystems
+        .lines()
+        .filter_map(|line| {
+            let (nodev, filesystem) = line.split_once('\t')?;
+            if nodev != "nodev" || filesystem == "zfs" {
+                Some(filesystem)
+            } else {
+                None
+            }
+        })
+        .collect::<HashSet<_>>();
+
+    mounts
+        .lines()
+        .filter_map(|line| {
+            // Example: `/dev/sda3 /home ext4 rw,relatime,data=ordered 0 0`
+            let mut parts = line.split(' ');
+            let device = parts.next()?;
+            let mount_point = parts.next()?;
+            let filesystem = parts.next()?;
+
+            if device != "none" && physical_filesystems.contains(filesystem) {
+                Some(Partition {
+                    device: device.to_string(),
+                    mount_point: PathBuf::from(mount_point),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the physical partitions that are mounted.  If `proc_root` isn't our own procfs, like
+/// a host's procfs mounted in a container, the mounts of PID 1 are read rather than our own, so
+/// that the host's partitions are found.
+pub fn get_partitions(proc_root: &Path) -> std::io::Result<Vec<Partition>> {
+    let mounts = if proc_root == Path::new("/proc") {
+        std::fs::read_to_string(proc_root.join("self/mounts"))?
+    } else {
+        std::fs::read_to_string(proc_root.join("1/mounts"))
+            .or_else(|_| std::fs::read_to_string(proc_root.join("self/mounts")))?
+    };
+    let filesystems = std::fs::read_to_string(proc_root.join("filesystems"))?;
+
+    Ok(parse_physical_partitions(&mounts, &filesystems))
+}
+
+/// Returns where a mount point can be reached from here.  If `proc_root` isn't our own procfs,
+/// the mount point is found through the root of PID 1 instead.
+pub fn get_usage_path(mount_point: &Path, proc_root: &Path) -> PathBuf {
+    if proc_root == Path::new("/proc") {
+        mount_point.to_path_buf()
+    } else {
+        proc_root
+            .join("1/root")
+            .join(mount_point.strip_prefix("/").unwrap_or(mount_point))
+    }
+}
 
 pub fn get_device_name(partition: &Partition) -> String {
     if let Some(device) = partition.device() {
@@ -32,3 +118,128 @@ pub fn get_device_name(partition: &Partition) -> String {
         "Name Unavailable".to_string()
     }
 }
+
+/// Parses a line of `/proc/diskstats`, returning the device name and its counters.  See
+/// <https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats> for the format.
+fn parse_disk_stats_line(line: &str) -> Option<(&str, DiskStats)> {
+    let mut fields = line.split_whitespace().skip(2);
+    let name = fields.next()?;
+    let mut values = [0_u64; 11];
+    for value in values.iter_mut() {
+        *value = fields.next()?.parse().ok()?;
+    }
+
+    Some((
+        name,
+        DiskStats {
+            reads_completed: values[0],
+            reads_merged: values[1],
+            read_time: values[3],
+            writes_completed: values[4],
+            writes_merged: values[5],
+            write_time: values[7],
+            io_time: values[9],
+            weighted_io_time: values[10],
+        },
+    ))
+}
+
+/// Returns the `diskstats` counters of each device, keyed by device name.
+pub fn get_disk_stats(proc_root: &Path) -> HashMap<String, DiskStats> {
+    std::fs::read_to_string(proc_root.join("diskstats"))
+        .map(|contents| {
+            contents
+                .lines()
+                .filter_map(parse_disk_stats_line)
+                .map(|(name, stats)| (name.to_string(), stats))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_stats_parse() {
+        let (name, stats) = parse_disk_stats_line(
+            " 259       0 nvme0n1 84316 21874 6457154 26418 179218 110535 9471722 211296 0 98644 251498 0 0 0 0 6542 13783",
+        )
+        .unwrap();
+        assert_eq!(name, "nvme0n1");
+        assert_eq!(
+            stats,
+            DiskStats {
+                reads_completed: 84316,
+                reads_merged: 21874,
+                read_time: 26418,
+                writes_completed: 179218,
+                writes_merged: 110535,
+                write_time: 211296,
+                io_time: 98644,
+                weighted_io_time: 251498,
+            }
+        );
+
+        assert!(parse_disk_stats_line("   8       0 sda 1 2 3").is_none());
+    }
+
+    #[test]
+    fn test_physical_partitions_parse() {
+        let partitions = parse_physical_partitions(
+            "/dev/nvme0n1p2 / ext4 rw,relatime 0 0\n\
+             proc /proc proc rw,nosuid,nodev,noexec,relatime 0 0\n\
+             tmpfs /tmp tmpfs rw,nosuid,nodev 0 0\n\
+             /dev/nvme0n1p1 /boot/efi vfat rw,relatime 0 0\n\
+             none /mnt ext4 rw 0 0\n",
+            "nodev\tproc\nnodev\ttmpfs\n\text4\n\tvfat\n",
+        );
+
+        assert_eq!(partitions.len(), 2);
+        assert_eq!(partitions[0].device(), Some(OsStr::new("/dev/nvme0n1p2")));
+        assert_eq!(partitions[0].mount_point(), Path::new("/"));
+        assert_eq!(partitions[1].mount_point(), Path::new("/boot/efi"));
+    }
+
+    #[test]
+    fn test_usage_path() {
+        assert_eq!(
+            get_usage_path(Path::new("/home"), Path::new("/proc")),
+            PathBuf::from("/home")
+        );
+        assert_eq!(
+            get_usage_path(Path::new("/home"), Path::new("/host/proc")),
+            PathBuf::from("/host/proc/1/root/home")
+        );
+    }
+
+    #[test]
+    fn test_disk_stat_rates() {
+        let prev = DiskStats::default();
+        let curr = DiskStats {
+            reads_completed: 150,
+            writes_completed: 50,
+            read_time: 300,
+            write_time: 500,
+            io_time: 1500,
+            weighted_io_time: 4000,
+            ..DiskStats::default()
+        };
+
+        let rates = curr.rates_since(&prev, 2.0);
+        assert_eq!(rates.iops, 100.0);
+        assert_eq!(rates.await_ms, 4.0);
+        assert_eq!(rates.util_percent, 75.0);
+        assert_eq!(rates.queue_size, 2.0);
+
+        // No operations means no await, and utilization never goes above 100%.
+        let rates = DiskStats {
+            io_time: 5000,
+            ..DiskStats::default()
+        }
+        .rates_since(&prev, 2.0);
+        assert_eq!(rates.await_ms, 0.0);
+        assert_eq!(rates.util_percent, 100.0);
+    }
+}
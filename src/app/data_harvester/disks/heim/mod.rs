@@ -1,4 +1,16 @@
-use crate::app::Filter;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    app::{data_harvester::unblock_with_timeout, Filter},
+    constants::HUNG_MOUNT_RETRY_IN_MILLISECONDS,
+};
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
@@ -17,17 +29,97 @@ pub struct DiskHarvest {
     pub free_space: Option<u64>,
     pub used_space: Option<u64>,
     pub total_space: Option<u64>,
+    /// Whether querying the mount's usage timed out, like a network mount whose server went away.
+    pub is_hung: bool,
+}
+
+/// A mount whose usage query timed out.
+#[derive(Debug)]
+pub struct HungMount {
+    hung_since: Instant,
+    /// Whether the query that timed out is still blocking its thread.
+    is_querying: Arc<AtomicBool>,
+}
+
+impl HungMount {
+    /// Whether to query the mount again.  This waits for the previous query to finish, so that a
+    /// mount that stays hung doesn't tie up another thread every time it is retried.
+    fn is_retry_due(&self) -> bool {
+        !self.is_querying.load(Ordering::Acquire)
+            && self.hung_since.elapsed() >= Duration::from_millis(HUNG_MOUNT_RETRY_IN_MILLISECONDS)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct IoData {
     pub read_bytes: u64,
     pub write_bytes: u64,
+    /// Extra counters from `/proc/diskstats`, only available on Linux.
+    pub stats: Option<DiskStats>,
+}
+
+/// Cumulative operation and timing counters for a device, as reported by `/proc/diskstats`.
+/// All times are in milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskStats {
+    pub reads_completed: u64,
+    pub reads_merged: u64,
+    pub read_time: u64,
+    pub writes_completed: u64,
+    pub writes_merged: u64,
+    pub write_time: u64,
+    /// Time spent with at least one I/O in flight.
+    pub io_time: u64,
+    /// Time spent doing I/O, weighted by the number of I/Os in flight.
+    pub weighted_io_time: u64,
+}
+
+/// Rates derived from two [`DiskStats`] readings.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiskStatRates {
+    /// Completed reads and writes per second.
+    pub iops: f64,
+    /// Average time for an operation to be served, including queueing, in milliseconds.
+    pub await_ms: f64,
+    /// The percentage of time the device was busy.
+    pub util_percent: f64,
+    /// The average number of operations queued or in flight.
+    pub queue_size: f64,
+}
+
+impl DiskStats {
+    /// Computes rates over the `elapsed_secs` seconds since `prev` was read.
+    pub fn rates_since(&self, prev: &DiskStats, elapsed_secs: f64) -> DiskStatRates {
+        if elapsed_secs <= 0.0 {
+            return DiskStatRates::default();
+        }
+
+        let ops = (self.reads_completed + self.writes_completed)
+            .saturating_sub(prev.reads_completed + prev.writes_completed);
+        let op_time =
+            (self.read_time + self.write_time).saturating_sub(prev.read_time + prev.write_time);
+        let elapsed_ms = elapsed_secs * 1000.0;
+
+        DiskStatRates {
+            iops: ops as f64 / elapsed_secs,
+            await_ms: if ops > 0 {
+                op_time as f64 / ops as f64
+            } else {
+                0.0
+            },
+            util_percent: (self.io_time.saturating_sub(prev.io_time) as f64 / elapsed_ms * 100.0)
+                .min(100.0),
+            queue_size: self.weighted_io_time.saturating_sub(prev.weighted_io_time) as f64
+                / elapsed_ms,
+        }
+    }
 }
 
 pub type IoHarvest = std::collections::HashMap<String, Option<IoData>>;
 
-pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Option<IoHarvest>> {
+pub async fn get_io_usage(
+    actually_get: bool, #[cfg(target_os = "linux")] proc_root: &std::path::Path,
+) -> crate::utils::error::Result<Option<IoHarvest>> {
     if !actually_get {
         return Ok(None);
     }
@@ -37,6 +129,9 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
     let mut io_hash: std::collections::HashMap<String, Option<IoData>> =
         std::collections::HashMap::new();
 
+    #[cfg(target_os = "linux")]
+    let mut disk_stats = get_disk_stats(proc_root);
+
     let counter_stream = heim::disk::io_counters().await?;
     futures::pin_mut!(counter_stream);
 
@@ -49,6 +144,16 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
                 Some(IoData {
                     read_bytes: io.read_bytes().get::<heim::units::information::byte>(),
                     write_bytes: io.write_bytes().get::<heim::units::information::byte>(),
+                    stats: {
+                        #[cfg(target_os = "linux")]
+                        {
+                            disk_stats.remove(mount_point)
+                        }
+                        #[cfg(not(target_os = "linux"))]
+                        {
+                            None
+                        }
+                    },
                 }),
             );
         }
@@ -57,43 +162,77 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
     Ok(Some(io_hash))
 }
 
+/// Gets the usage of each disk.  Each mount's usage is queried concurrently and given up on after
+/// `timeout`, so one hung mount can't hold up the others.  Hung mounts
+/// are recorded in `hung_mounts`, and aren't queried again until their last query has finished
+/// and [`HUNG_MOUNT_RETRY_IN_MILLISECONDS`] has passed.
 pub async fn get_disk_usage(
     actually_get: bool, disk_filter: &Option<Filter>, mount_filter: &Option<Filter>,
+    hung_mounts: &mut HashMap<String, HungMount>, timeout: Duration,
+    #[cfg(target_os = "linux")] proc_root: &std::path::Path,
 ) -> crate::utils::error::Result<Option<Vec<DiskHarvest>>> {
     if !actually_get {
         return Ok(None);
     }
 
-    use futures::StreamExt;
-
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
-    let partitions_stream = heim::disk::partitions_physical().await?;
-    futures::pin_mut!(partitions_stream);
-
-    while let Some(part) = partitions_stream.next().await {
-        if let Ok(partition) = part {
-            let name = get_device_name(&partition);
-
-            let mount_point = (partition
-                .mount_point()
-                .to_str()
-                .unwrap_or("Name Unavailable"))
-            .to_string();
-
-            // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
-            //
-            // For implementation, we do this as follows:
-            // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
-            // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
-            // 3. Anything else is allowed.
-
-            let filter_check_map = [(disk_filter, &name), (mount_filter, &mount_point)];
-
-            // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
-            // immediately allow it!
-            let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
+    let mut to_query = Vec::new();
+
+    #[cfg(target_os = "linux")]
+    let partitions = get_partitions(proc_root)?;
+
+    #[cfg(not(target_os = "linux"))]
+    let partitions = {
+        use futures::StreamExt;
+
+        heim::disk::partitions_physical()
+            .await?
+            .filter_map(|part| async move { part.ok() })
+            .collect::<Vec<_>>()
+            .await
+    };
+
+    for partition in partitions {
+        let name = get_device_name(&partition);
+
+        let mount_point = (partition
+            .mount_point()
+            .to_str()
+            .unwrap_or("Name Unavailable"))
+        .to_string();
+
+        // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
+        //
+        // For implementation, we do this as follows:
+        // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
+        // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
+        // 3. Anything else is allowed.
+
+        let filter_check_map = [(disk_filter, &name), (mount_filter, &mount_point)];
+
+        // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
+        // immediately allow it!
+        let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
+            if let Some(filter) = filter {
+                if !filter.is_list_ignored {
+                    for r in &filter.list {
+                        if r.is_match(text) {
+                            return true;
+                        }
+                    }
+                }
+            }
+            false
+        });
+
+        let to_keep = if matches_allow_list {
+            true
+        } else {
+            // If it doesn't match an allow list, then check if it is denied.
+            // That is, if it matches in a reject filter, then reject.  Otherwise, we always keep it.
+            !filter_check_map.iter().any(|(filter, text)| {
                 if let Some(filter) = filter {
-                    if !filter.is_list_ignored {
+                    if filter.is_list_ignored {
                         for r in &filter.list {
                             if r.is_match(text) {
                                 return true;
@@ -102,53 +241,126 @@ pub async fn get_disk_usage(
                     }
                 }
                 false
-            });
+            })
+        };
+
+        if to_keep {
+            let is_still_hung = hung_mounts
+                .get(&mount_point)
+                .map(|hung_mount| !hung_mount.is_retry_due())
+                .unwrap_or(false);
 
-            let to_keep = if matches_allow_list {
-                true
+            if is_still_hung {
+                vec_disks.push(DiskHarvest {
+                    is_hung: true,
+                    mount_point,
+                    name,
+                    ..DiskHarvest::default()
+                });
             } else {
-                // If it doesn't match an allow list, then check if it is denied.
-                // That is, if it matches in a reject filter, then reject.  Otherwise, we always keep it.
-                !filter_check_map.iter().any(|(filter, text)| {
-                    if let Some(filter) = filter {
-                        if filter.is_list_ignored {
-                            for r in &filter.list {
-                                if r.is_match(text) {
-                                    return true;
-                                }
-                            }
-                        }
-                    }
-                    false
-                })
-            };
+                #[cfg(target_os = "linux")]
+                let usage_path = get_usage_path(partition.mount_point(), proc_root);
+                #[cfg(not(target_os = "linux"))]
+                let usage_path = partition.mount_point().to_path_buf();
 
-            if to_keep {
+                to_query.push((name, mount_point, usage_path));
+            }
+        }
+    }
+
+    // heim calls `statvfs` directly, so each one is run on its own blocking thread.
+    let is_querying_flags: Vec<Arc<AtomicBool>> = to_query
+        .iter()
+        .map(|_| Arc::new(AtomicBool::new(true)))
+        .collect();
+    let usages = futures::future::join_all(to_query.iter().zip(&is_querying_flags).map(
+        |((_, _, usage_path), is_querying)| {
+            let usage_path = usage_path.clone();
+            let is_querying = is_querying.clone();
+            unblock_with_timeout(
+                move || {
+                    let usage = futures::executor::block_on(heim::disk::usage(usage_path));
+                    is_querying.store(false, Ordering::Release);
+                    usage
+                },
+                timeout,
+            )
+        },
+    ))
+    .await;
+
+    for (((name, mount_point, _), usage), is_querying) in
+        to_query.into_iter().zip(usages).zip(is_querying_flags)
+    {
+        match usage {
+            Some(Ok(usage)) => {
+                hung_mounts.remove(&mount_point);
+                vec_disks.push(DiskHarvest {
+                    free_space: Some(usage.free().get::<heim::units::information::byte>()),
+                    used_space: Some(usage.used().get::<heim::units::information::byte>()),
+                    total_space: Some(usage.total().get::<heim::units::information::byte>()),
+                    is_hung: false,
+                    mount_point,
+                    name,
+                });
+            }
+            Some(Err(_)) => {
                 // The usage line can fail in some cases (for example, if you use Void Linux + LUKS,
                 // see https://github.com/ClementTsang/bottom/issues/419 for details).  As such, check
                 // it like this instead.
-                if let Ok(usage) = heim::disk::usage(partition.mount_point()).await {
-                    vec_disks.push(DiskHarvest {
-                        free_space: Some(usage.free().get::<heim::units::information::byte>()),
-                        used_space: Some(usage.used().get::<heim::units::information::byte>()),
-                        total_space: Some(usage.total().get::<heim::units::information::byte>()),
-                        mount_point,
-                        name,
-                    });
-                } else {
-                    vec_disks.push(DiskHarvest {
-                        free_space: None,
-                        used_space: None,
-                        total_space: None,
-                        mount_point,
-                        name,
-                    });
-                }
+                hung_mounts.remove(&mount_point);
+                vec_disks.push(DiskHarvest {
+                    mount_point,
+                    name,
+                    ..DiskHarvest::default()
+                });
+            }
+            None => {
+                hung_mounts.insert(
+                    mount_point.clone(),
+                    HungMount {
+                        hung_since: Instant::now(),
+                        is_querying,
+                    },
+                );
+                vec_disks.push(DiskHarvest {
+                    is_hung: true,
+                    mount_point,
+                    name,
+                    ..DiskHarvest::default()
+                });
             }
         }
     }
 
+    // Forget about hung mounts that have since been unmounted.
+    hung_mounts.retain(|mount_point, _| {
+        vec_disks
+            .iter()
+            .any(|disk| &disk.mount_point == mount_point)
+    });
+
     vec_disks.sort_by(|a, b| a.name.cmp(&b.name));
 
     Ok(Some(vec_disks))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hung_mount_retry() {
+        let retry_time = Duration::from_millis(HUNG_MOUNT_RETRY_IN_MILLISECONDS);
+        let hung_mount = |hung_for: Duration, is_querying: bool| HungMount {
+            hung_since: Instant::now().checked_sub(hung_for).unwrap(),
+            is_querying: Arc::new(AtomicBool::new(is_querying)),
+        };
+
+        assert!(!hung_mount(Duration::from_secs(1), false).is_retry_due());
+        assert!(hung_mount(retry_time, false).is_retry_due());
+
+        // A query that is still stuck isn't retried, however long it has been.
+        assert!(!hung_mount(retry_time * 2, true).is_retry_due());
+    }
+}
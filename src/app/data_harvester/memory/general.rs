@@ -1,5 +1,8 @@
 //! Data collection for memory via heim.
 
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
 #[derive(Debug, Clone, Default)]
 pub struct MemHarvest {
     pub mem_total_in_kib: u64,
@@ -8,7 +11,7 @@ pub struct MemHarvest {
 }
 
 pub async fn get_mem_data(
-    actually_get: bool,
+    actually_get: bool, #[cfg(target_os = "linux")] proc_root: &Path,
 ) -> (
     crate::utils::error::Result<Option<MemHarvest>>,
     crate::utils::error::Result<Option<MemHarvest>>,
@@ -18,16 +21,25 @@ pub async fn get_mem_data(
     if !actually_get {
         (Ok(None), Ok(None))
     } else {
-        join!(get_ram_data(), get_swap_data())
+        #[cfg(target_os = "linux")]
+        {
+            join!(get_ram_data(proc_root), get_swap_data())
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            join!(get_ram_data(), get_swap_data())
+        }
     }
 }
 
-pub async fn get_ram_data() -> crate::utils::error::Result<Option<MemHarvest>> {
+pub async fn get_ram_data(
+    #[cfg(target_os = "linux")] proc_root: &Path,
+) -> crate::utils::error::Result<Option<MemHarvest>> {
     let (mem_total_in_kib, mem_used_in_kib) = {
         #[cfg(target_os = "linux")]
         {
             use smol::fs::read_to_string;
-            let meminfo = read_to_string("/proc/meminfo").await?;
+            let meminfo = read_to_string(proc_root.join("meminfo")).await?;
 
             // All values are in KiB by default.
             let mut mem_total = 0;
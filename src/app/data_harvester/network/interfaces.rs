@@ -0,0 +1,260 @@
+//! Data collection for per-interface network details.
+//!
+//! For Linux, counters are read from `/proc/net/dev`, link details from `/sys/class/net`, and
+//! addresses via `getifaddrs`.  Other platforms are unsupported.
+
+use std::net::IpAddr;
+
+#[cfg(target_os = "linux")]
+use std::{collections::HashMap, path::Path};
+
+#[cfg(target_os = "linux")]
+use crate::app::Filter;
+
+/// The counters kept for an interface in `/proc/net/dev`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InterfaceCounters {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct InterfaceHarvest {
+    pub name: String,
+    pub addresses: Vec<IpAddr>,
+    pub mtu: Option<u32>,
+    /// The operational state as reported by the kernel, like `up`, `down`, or `dormant`.
+    pub operstate: String,
+    /// The link speed in Mb/s, if the interface reports one.
+    pub speed_mbps: Option<u64>,
+    pub counters: InterfaceCounters,
+}
+
+/// Parses the contents of `/proc/net/dev` into each interface's name and counters.
+#[cfg(target_os = "linux")]
+fn parse_net_dev(contents: &str) -> Vec<(String, InterfaceCounters)> {
+    contents
+        .lines()
+        .skip(2) // The two header lines.
+        .filter_map(|line| {
+            let (name, counters) = line.split_once(':')?;
+            let fields = counters
+                .split_whitespace()
+                .map(|field| field.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            if fields.len() < 12 {
+                return None;
+            }
+
+            Some((
+                name.trim().to_string(),
+                InterfaceCounters {
+                    rx_bytes: fields[0],
+                    rx_packets: fields[1],
+                    rx_errors: fields[2],
+                    rx_drops: fields[3],
+                    tx_bytes: fields[8],
+                    tx_packets: fields[9],
+                    tx_errors: fields[10],
+                    tx_drops: fields[11],
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Returns the IPv4 and IPv6 addresses assigned to each interface, keyed by interface name.
+#[cfg(target_os = "linux")]
+fn get_interface_addresses() -> HashMap<String, Vec<IpAddr>> {
+    use std::{
+        ffi::CStr,
+        net::{Ipv4Addr, Ipv6Addr},
+    };
+
+    let mut addresses: HashMap<String, Vec<IpAddr>> = HashMap::new();
+    let mut ifaddrs: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut ifaddrs) } != 0 {
+        return addresses;
+    }
+
+    let mut current = ifaddrs;
+    while let Some(ifaddr) = unsafe { current.as_ref() } {
+        if !ifaddr.ifa_addr.is_null() {
+            let address = match i32::from(unsafe { (*ifaddr.ifa_addr).sa_family }) {
+                libc::AF_INET => {
+                    let sockaddr = unsafe { &*(ifaddr.ifa_addr as *const libc::sockaddr_in) };
+                    Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                        sockaddr.sin_addr.s_addr,
+                    ))))
+                }
+                libc::AF_INET6 => {
+                    let sockaddr = unsafe { &*(ifaddr.ifa_addr as *const libc::sockaddr_in6) };
+                    Some(IpAddr::V6(Ipv6Addr::from(sockaddr.sin6_addr.s6_addr)))
+                }
+                _ => None,
+            };
+
+            if let Some(address) = address {
+                let name = unsafe { CStr::from_ptr(ifaddr.ifa_name) }
+                    .to_string_lossy()
+                    .to_string();
+                addresses.entry(name).or_default().push(address);
+            }
+        }
+        current = ifaddr.ifa_next;
+    }
+    unsafe { libc::freeifaddrs(ifaddrs) };
+
+    addresses
+}
+
+/// Reads details for every interface in `<proc_root>/net/dev` that passes the network filter.
+#[cfg(target_os = "linux")]
+pub fn get_interface_data(
+    proc_root: &Path, sys_root: &Path, filter: &Option<Filter>,
+) -> std::io::Result<Vec<InterfaceHarvest>> {
+    let contents = std::fs::read_to_string(proc_root.join("net/dev"))?;
+    let mut addresses = get_interface_addresses();
+
+    let mut interfaces = parse_net_dev(&contents)
+        .into_iter()
+        .filter(|(name, _)| {
+            if let Some(filter) = filter {
+                let mut ret = filter.is_list_ignored;
+                for r in &filter.list {
+                    if r.is_match(name) {
+                        ret = !filter.is_list_ignored;
+                        break;
+                    }
+                }
+                ret
+            } else {
+                true
+            }
+        })
+        .map(|(name, counters)| {
+            let interface_dir = sys_root.join("class/net").join(&name);
+            let read_attribute = |attribute: &str| {
+                std::fs::read_to_string(interface_dir.join(attribute))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+            };
+
+            InterfaceHarvest {
+                addresses: addresses.remove(&name).unwrap_or_default(),
+                mtu: read_attribute("mtu").and_then(|mtu| mtu.parse().ok()),
+                operstate: read_attribute("operstate").unwrap_or_else(|| "unknown".to_string()),
+                // Links that are down or virtual report -1 or fail to read.
+                speed_mbps: read_attribute("speed")
+                    .and_then(|speed| speed.parse::<i64>().ok())
+                    .filter(|speed| *speed > 0)
+                    .map(|speed| speed as u64),
+                name,
+                counters,
+            }
+        })
+        .collect::<Vec<_>>();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(interfaces)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::TempDir;
+
+    const NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:  104857     1024    0    0    0     0          0         0   104857     1024    0    0    0     0       0          0
+  eth0: 9876543   54321    2   17    0     0          0        12  1234567   43210    1    3    0     0       0          0
+";
+
+    #[test]
+    fn test_net_dev_parse() {
+        let interfaces = parse_net_dev(NET_DEV);
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces[0].0, "lo");
+        assert_eq!(
+            interfaces[1],
+            (
+                "eth0".to_string(),
+                InterfaceCounters {
+                    rx_bytes: 9876543,
+                    rx_packets: 54321,
+                    rx_errors: 2,
+                    rx_drops: 17,
+                    tx_bytes: 1234567,
+                    tx_packets: 43210,
+                    tx_errors: 1,
+                    tx_drops: 3,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_interface_fixture() {
+        let root = TempDir::new("net");
+        let proc_root = root.path().join("proc");
+        let sys_root = root.path().join("sys");
+        let interface_dir = sys_root.join("class/net/eth0");
+        std::fs::create_dir_all(proc_root.join("net")).unwrap();
+        std::fs::create_dir_all(&interface_dir).unwrap();
+        std::fs::write(proc_root.join("net/dev"), NET_DEV).unwrap();
+        for (file, contents) in [
+            ("mtu", "9000\n"),
+            ("operstate", "up\n"),
+            ("speed", "1000\n"),
+        ] {
+            std::fs::write(interface_dir.join(file), contents).unwrap();
+        }
+
+        let interfaces = get_interface_data(&proc_root, &sys_root, &None).unwrap();
+
+        assert_eq!(interfaces.len(), 2);
+        let eth0 = &interfaces[0];
+        assert_eq!(eth0.name, "eth0");
+        assert_eq!(eth0.mtu, Some(9000));
+        assert_eq!(eth0.operstate, "up");
+        assert_eq!(eth0.speed_mbps, Some(1000));
+        assert_eq!(eth0.counters.rx_drops, 17);
+
+        let lo = &interfaces[1];
+        assert_eq!(lo.mtu, None);
+        assert_eq!(lo.operstate, "unknown");
+        assert_eq!(lo.speed_mbps, None);
+    }
+
+    #[test]
+    fn test_interface_filter() {
+        let root = TempDir::new("net_filter");
+        let root = root.path();
+        std::fs::create_dir_all(root.join("net")).unwrap();
+        std::fs::write(root.join("net/dev"), NET_DEV).unwrap();
+
+        let get_names = |is_list_ignored: bool| {
+            let filter = Some(Filter {
+                is_list_ignored,
+                list: vec![regex::Regex::new("^eth").unwrap()],
+            });
+            get_interface_data(root, root, &filter)
+                .unwrap()
+                .into_iter()
+                .map(|interface| interface.name)
+                .collect::<Vec<_>>()
+        };
+        let allowed = get_names(false);
+        let ignored = get_names(true);
+
+        assert_eq!(allowed, vec!["eth0".to_string()]);
+        assert_eq!(ignored, vec!["lo".to_string()]);
+    }
+}
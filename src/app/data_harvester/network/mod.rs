@@ -13,6 +13,9 @@ cfg_if::cfg_if! {
     }
 }
 
+pub mod interfaces;
+pub mod sockets;
+
 #[derive(Default, Clone, Debug)]
 /// All units in bits.
 pub struct NetworkHarvest {
@@ -0,0 +1,421 @@
+//! Data collection for TCP and UDP sockets.
+//!
+//! For Linux, sockets are read from `net/{tcp,tcp6,udp,udp6}` in procfs, and are matched to their
+//! owning processes via the socket inodes in `<PID>/fd`.  Other platforms are unsupported.
+
+use std::net::SocketAddr;
+
+use crate::Pid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SocketProtocol {
+    Tcp,
+    Tcp6,
+    Udp,
+    Udp6,
+}
+
+impl std::fmt::Display for SocketProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SocketProtocol::Tcp => "TCP",
+                SocketProtocol::Tcp6 => "TCP6",
+                SocketProtocol::Udp => "UDP",
+                SocketProtocol::Udp6 => "UDP6",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SocketHarvest {
+    pub protocol: SocketProtocol,
+    pub local_address: SocketAddr,
+    pub remote_address: SocketAddr,
+    /// The socket state, named like the kernel does (e.g. `ESTABLISHED`, `LISTEN`, `TIME_WAIT`).
+    /// Unconnected UDP sockets are `CLOSE`.
+    pub state: &'static str,
+    pub tx_queue: u32,
+    pub rx_queue: u32,
+    pub inode: u64,
+    /// The process that owns this socket, if known.
+    pub pid: Option<Pid>,
+    /// The name of the owning process, if known.
+    pub process_name: Option<String>,
+}
+
+/// The connections of the processes shown in the connections dialog.
+#[derive(Debug, Clone)]
+pub struct ConnectionsHarvest {
+    /// The processes these are the connections of.
+    pub pids: Vec<Pid>,
+    /// The connections, or why they could not be read.
+    pub connections: Result<Vec<SocketHarvest>, String>,
+}
+
+#[cfg(target_os = "linux")]
+fn get_tcp_state_name(state: &procfs::net::TcpState) -> &'static str {
+    use procfs::net::TcpState;
+
+    match state {
+        TcpState::Established => "ESTABLISHED",
+        TcpState::SynSent => "SYN_SENT",
+        TcpState::SynRecv => "SYN_RECV",
+        TcpState::FinWait1 => "FIN_WAIT1",
+        TcpState::FinWait2 => "FIN_WAIT2",
+        TcpState::TimeWait => "TIME_WAIT",
+        TcpState::Close => "CLOSE",
+        TcpState::CloseWait => "CLOSE_WAIT",
+        TcpState::LastAck => "LAST_ACK",
+        TcpState::Listen => "LISTEN",
+        TcpState::Closing => "CLOSING",
+        TcpState::NewSynRecv => "NEW_SYN_RECV",
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_udp_state_name(state: &procfs::net::UdpState) -> &'static str {
+    use procfs::net::UdpState;
+
+    match state {
+        UdpState::Established => "ESTABLISHED",
+        UdpState::Close => "CLOSE",
+    }
+}
+
+/// Reads all TCP and UDP sockets.  Socket owners are not resolved.  Tables that can't be read
+/// (e.g. `tcp6` if IPv6 is disabled) are skipped.
+#[cfg(target_os = "linux")]
+pub fn get_socket_table(proc_root: &std::path::Path) -> Vec<SocketHarvest> {
+    use std::{fs::File, io::BufReader};
+
+    let open_table = |table: &str| {
+        File::open(proc_root.join("net").join(table))
+            .map(BufReader::new)
+            .map_err(procfs::ProcError::from)
+    };
+    let mut sockets = Vec::new();
+
+    for (protocol, table) in [(SocketProtocol::Tcp, "tcp"), (SocketProtocol::Tcp6, "tcp6")] {
+        if let Ok(table) = open_table(table).and_then(procfs::net::read_tcp_table) {
+            sockets.extend(table.into_iter().map(|entry| SocketHarvest {
+                protocol,
+                local_address: entry.local_address,
+                remote_address: entry.remote_address,
+                state: get_tcp_state_name(&entry.state),
+                tx_queue: entry.tx_queue,
+                rx_queue: entry.rx_queue,
+                inode: entry.inode,
+                pid: None,
+                process_name: None,
+            }));
+        }
+    }
+
+    for (protocol, table) in [(SocketProtocol::Udp, "udp"), (SocketProtocol::Udp6, "udp6")] {
+        if let Ok(table) = open_table(table).and_then(procfs::net::read_udp_table) {
+            sockets.extend(table.into_iter().map(|entry| SocketHarvest {
+                protocol,
+                local_address: entry.local_address,
+                remote_address: entry.remote_address,
+                state: get_udp_state_name(&entry.state),
+                tx_queue: entry.tx_queue,
+                rx_queue: entry.rx_queue,
+                inode: entry.inode,
+                pid: None,
+                process_name: None,
+            }));
+        }
+    }
+
+    sockets
+}
+
+/// Returns the inodes of all sockets a process has open.  This requires permission to read the
+/// process' `<PID>/fd` directory.
+#[cfg(target_os = "linux")]
+pub fn get_socket_inodes(
+    proc_root: &std::path::Path, pid: Pid,
+) -> crate::utils::error::Result<Vec<u64>> {
+    Ok(read_socket_inodes(
+        &procfs::process::Process::new_with_root(proc_root.join(pid.to_string()))?,
+    )?)
+}
+
+#[cfg(target_os = "linux")]
+fn read_socket_inodes(process: &procfs::process::Process) -> procfs::ProcResult<Vec<u64>> {
+    use procfs::process::FDTarget;
+
+    Ok(process
+        .fd()?
+        .into_iter()
+        .filter_map(|fd_info| match fd_info.target {
+            FDTarget::Socket(inode) => Some(inode),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Gets the TCP and UDP sockets owned by the given processes.  Processes whose sockets can't be
+/// read are skipped, and this only fails if none of them could be read.
+#[cfg(target_os = "linux")]
+pub fn get_process_sockets(
+    pids: &[Pid], proc_root: &std::path::Path,
+) -> crate::utils::error::Result<Vec<SocketHarvest>> {
+    let mut inode_owners = fxhash::FxHashMap::default();
+    let mut last_error = None;
+    let mut has_read_any = false;
+    for pid in pids {
+        // Some processes in a group may have just exited or belong to another user, so these
+        // are skipped unless nothing could be read at all.
+        match get_socket_inodes(proc_root, *pid) {
+            Ok(inodes) => {
+                has_read_any = true;
+                for inode in inodes {
+                    inode_owners.insert(inode, *pid);
+                }
+            }
+            Err(err) => last_error = Some(err),
+        }
+    }
+
+    if !has_read_any {
+        if let Some(err) = last_error {
+            return Err(err);
+        }
+    }
+
+    Ok(
+        match_socket_owners(get_socket_table(proc_root), &inode_owners)
+            .into_iter()
+            .filter(|socket| socket.pid.is_some())
+            .collect(),
+    )
+}
+
+/// The owners of sockets found so far.  Finding them means reading every process'
+/// `<PID>/fd` directory, so that is only done when sockets with unknown owners show up, and at
+/// most every `SOCKET_OWNER_REFRESH_IN_MILLISECONDS`.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Default)]
+pub struct SocketOwnerCache {
+    inode_owners: fxhash::FxHashMap<u64, Pid>,
+    process_names: fxhash::FxHashMap<Pid, String>,
+    /// Sockets whose owner couldn't be found last time, so they aren't looked for again and again.
+    unowned_inodes: fxhash::FxHashSet<u64>,
+    /// Whether some processes' file descriptors couldn't be read last time for lack of
+    /// permission, so their sockets' owners are unknown.
+    is_denied: bool,
+    last_refresh: Option<std::time::Instant>,
+}
+
+#[cfg(target_os = "linux")]
+impl SocketOwnerCache {
+    pub fn is_denied(&self) -> bool {
+        self.is_denied
+    }
+
+    fn refresh(&mut self, proc_root: &std::path::Path, sockets: &[SocketHarvest]) {
+        self.inode_owners.clear();
+        self.process_names.clear();
+        self.is_denied = false;
+
+        if let Ok(processes) = procfs::process::all_processes_with_root(proc_root) {
+            for process in processes {
+                match read_socket_inodes(&process) {
+                    Ok(inodes) => {
+                        if !inodes.is_empty() {
+                            self.process_names
+                                .insert(process.pid, process.stat.comm.clone());
+                        }
+                        for inode in inodes {
+                            self.inode_owners.insert(inode, process.pid);
+                        }
+                    }
+                    Err(procfs::ProcError::PermissionDenied(_)) => self.is_denied = true,
+                    // The process most likely just exited.
+                    Err(_) => {}
+                }
+            }
+        }
+
+        self.unowned_inodes = sockets
+            .iter()
+            .map(|socket| socket.inode)
+            .filter(|inode| !self.inode_owners.contains_key(inode))
+            .collect();
+    }
+}
+
+/// Gets all TCP and UDP sockets on the system, along with their owners where they can be
+/// determined.  Sockets owned by processes whose file descriptors can't be read (e.g. those of
+/// other users when not running as root) are still returned, just without an owner.
+#[cfg(target_os = "linux")]
+pub fn get_all_sockets(
+    proc_root: &std::path::Path, owner_cache: &mut SocketOwnerCache,
+) -> Vec<SocketHarvest> {
+    use crate::constants::SOCKET_OWNER_REFRESH_IN_MILLISECONDS;
+
+    let sockets = get_socket_table(proc_root);
+
+    let has_new_sockets = sockets.iter().any(|socket| {
+        !owner_cache.inode_owners.contains_key(&socket.inode)
+            && !owner_cache.unowned_inodes.contains(&socket.inode)
+    });
+    let is_refresh_due = match owner_cache.last_refresh {
+        Some(last_refresh) => {
+            last_refresh.elapsed().as_millis() >= SOCKET_OWNER_REFRESH_IN_MILLISECONDS.into()
+        }
+        None => true,
+    };
+    if has_new_sockets && is_refresh_due {
+        owner_cache.refresh(proc_root, &sockets);
+        owner_cache.last_refresh = Some(std::time::Instant::now());
+    }
+
+    let mut sockets = match_socket_owners(sockets, &owner_cache.inode_owners);
+    for socket in &mut sockets {
+        if let Some(pid) = socket.pid {
+            socket.process_name = owner_cache.process_names.get(&pid).cloned();
+        }
+    }
+
+    sockets
+}
+
+/// Sets the owner of each socket from a mapping of socket inodes to PIDs.
+#[cfg(target_os = "linux")]
+pub fn match_socket_owners(
+    mut sockets: Vec<SocketHarvest>, inode_owners: &fxhash::FxHashMap<u64, Pid>,
+) -> Vec<SocketHarvest> {
+    for socket in &mut sockets {
+        socket.pid = inode_owners.get(&socket.inode).copied();
+    }
+
+    sockets
+}
+
+#[cfg(test)]
+#[cfg(target_os = "linux")]
+mod test {
+    use super::*;
+    use crate::utils::test_util::TempDir;
+
+    const TCP: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000:0050 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 662 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:BC20 0100007F:1F90 01 00000010:00000020 02:00001501 00000000     0        0 107372 2 0000000000000000 20 4 0 26 -1
+";
+    const UDP: &str = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  100: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 5001 2 0000000000000000 0
+";
+
+    /// Sets up a procfs with the above socket tables, and one process owning the TCP listener
+    /// and the UDP socket.
+    fn create_fixture(name: &str) -> TempDir {
+        let temp_dir = TempDir::new(&format!("sockets_{}", name));
+        let root = temp_dir.path();
+        let fd_dir = root.join("100/fd");
+        std::fs::create_dir_all(root.join("net")).unwrap();
+        std::fs::create_dir_all(&fd_dir).unwrap();
+        std::fs::write(root.join("net/tcp"), TCP).unwrap();
+        std::fs::write(root.join("net/udp"), UDP).unwrap();
+        std::fs::write(
+            root.join("100/stat"),
+            "100 (nginx) S 1 100 100 0 -1 4194304 82 0 0 0 0 0 0 0 20 0 1 0 1020914 2703360 314 \
+             18446744073709551615 1 1 1 0 0 0 0 0 0 0 0 0 17 0 0 0 0 0 0 1 1 1 1 1 1 1 0\n",
+        )
+        .unwrap();
+        std::os::unix::fs::symlink("socket:[662]", fd_dir.join("3")).unwrap();
+        std::os::unix::fs::symlink("socket:[5001]", fd_dir.join("4")).unwrap();
+        std::os::unix::fs::symlink("/dev/null", fd_dir.join("5")).unwrap();
+
+        temp_dir
+    }
+
+    #[test]
+    fn test_socket_table() {
+        let root = create_fixture("table");
+        let sockets = get_socket_table(root.path());
+
+        // The missing IPv6 tables are skipped.
+        assert_eq!(sockets.len(), 3);
+
+        let listener = &sockets[0];
+        assert_eq!(listener.protocol, SocketProtocol::Tcp);
+        assert_eq!(listener.local_address, "0.0.0.0:80".parse().unwrap());
+        assert_eq!(listener.state, "LISTEN");
+        assert_eq!(listener.inode, 662);
+        assert_eq!(listener.pid, None);
+
+        let connection = &sockets[1];
+        assert_eq!(connection.local_address, "127.0.0.1:48160".parse().unwrap());
+        assert_eq!(connection.remote_address, "127.0.0.1:8080".parse().unwrap());
+        assert_eq!(connection.state, "ESTABLISHED");
+        assert_eq!((connection.tx_queue, connection.rx_queue), (16, 32));
+
+        let dns = &sockets[2];
+        assert_eq!(dns.protocol, SocketProtocol::Udp);
+        assert_eq!(dns.local_address, "127.0.0.53:53".parse().unwrap());
+        assert_eq!(dns.state, "CLOSE");
+    }
+
+    #[test]
+    fn test_match_socket_owners() {
+        let root = create_fixture("owners");
+        let sockets = get_socket_table(root.path());
+
+        let inode_owners = vec![(662, 100), (5001, 200), (9999, 300)]
+            .into_iter()
+            .collect();
+        let owners: Vec<Option<Pid>> = match_socket_owners(sockets, &inode_owners)
+            .iter()
+            .map(|socket| socket.pid)
+            .collect();
+        assert_eq!(owners, vec![Some(100), None, Some(200)]);
+    }
+
+    #[test]
+    fn test_process_sockets() {
+        let root = create_fixture("process");
+
+        // PID 200 doesn't exist, which shouldn't stop PID 100's sockets from being found.
+        let sockets = get_process_sockets(&[100, 200], root.path());
+        let missing = get_process_sockets(&[200], root.path());
+
+        let inodes: Vec<u64> = sockets.unwrap().iter().map(|socket| socket.inode).collect();
+        assert_eq!(inodes, vec![662, 5001]);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_socket_owner_cache() {
+        let root = create_fixture("cache");
+        let mut owner_cache = SocketOwnerCache::default();
+        let sockets = get_all_sockets(root.path(), &mut owner_cache);
+
+        // Known sockets are resolved from the cache without reading the fds again.
+        std::fs::remove_dir_all(root.path().join("100/fd")).unwrap();
+        let cached_sockets = get_all_sockets(root.path(), &mut owner_cache);
+
+        for sockets in [sockets, cached_sockets] {
+            let owners: Vec<(Option<Pid>, Option<String>)> = sockets
+                .into_iter()
+                .map(|socket| (socket.pid, socket.process_name))
+                .collect();
+            assert_eq!(
+                owners,
+                vec![
+                    (Some(100), Some("nginx".to_string())),
+                    (None, None),
+                    (Some(100), Some("nginx".to_string())),
+                ]
+            );
+        }
+        assert!(owner_cache.unowned_inodes.contains(&107372));
+    }
+}
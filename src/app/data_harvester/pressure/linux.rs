@@ -0,0 +1,108 @@
+//! Linux-specific functions regarding pressure stall information.
+
+use std::path::Path;
+
+use super::{PressureHarvest, PressureRecord, ResourcePressure};
+
+/// Parses one line of a pressure file, like `some avg10=0.00 avg60=0.00 avg300=0.00 total=0`.
+/// Returns the line's kind (`some` or `full`) alongside the record.
+fn parse_pressure_line(line: &str) -> Option<(&str, PressureRecord)> {
+    let mut fields = line.split_whitespace();
+    let kind = fields.next()?;
+    let mut record = PressureRecord::default();
+
+    for field in fields {
+        let (key, value) = {
+            let mut split = field.splitn(2, '=');
+            (split.next()?, split.next()?)
+        };
+        match key {
+            "avg10" => record.avg10 = value.parse().ok()?,
+            "avg60" => record.avg60 = value.parse().ok()?,
+            "avg300" => record.avg300 = value.parse().ok()?,
+            "total" => record.total = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    Some((kind, record))
+}
+
+/// Parses the contents of a pressure file.  Returns [`None`] if there is no `some` line.
+fn parse_pressure(contents: &str) -> Option<ResourcePressure> {
+    let mut some = None;
+    let mut full = None;
+
+    for (kind, record) in contents.lines().filter_map(parse_pressure_line) {
+        match kind {
+            "some" => some = Some(record),
+            "full" => full = Some(record),
+            _ => {}
+        }
+    }
+
+    Some(ResourcePressure { some: some?, full })
+}
+
+/// Reads a pressure file.  Returns [`None`] if PSI is unavailable, which is either because the
+/// file doesn't exist, or because reading it isn't supported when PSI is disabled at boot.
+fn read_pressure(proc_root: &Path, resource: &str) -> std::io::Result<Option<ResourcePressure>> {
+    match std::fs::read_to_string(proc_root.join("pressure").join(resource)) {
+        Ok(contents) => Ok(parse_pressure(&contents)),
+        Err(err)
+            if err.kind() == std::io::ErrorKind::NotFound
+                || err.raw_os_error() == Some(libc::EOPNOTSUPP) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Reads the CPU, memory, and I/O pressure.  Returns [`None`] if PSI is unavailable.
+pub fn get_pressure_data(proc_root: &Path) -> std::io::Result<Option<PressureHarvest>> {
+    let harvest = PressureHarvest {
+        cpu: read_pressure(proc_root, "cpu")?,
+        memory: read_pressure(proc_root, "memory")?,
+        io: read_pressure(proc_root, "io")?,
+    };
+
+    if harvest.cpu.is_none() && harvest.memory.is_none() && harvest.io.is_none() {
+        Ok(None)
+    } else {
+        Ok(Some(harvest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressure_parse() {
+        let io = parse_pressure(
+            "some avg10=0.27 avg60=0.50 avg300=0.33 total=10031710\n\
+             full avg10=0.11 avg60=0.28 avg300=0.19 total=7606229\n",
+        )
+        .unwrap();
+        assert_eq!(
+            io.some,
+            PressureRecord {
+                avg10: 0.27,
+                avg60: 0.50,
+                avg300: 0.33,
+                total: 10031710,
+            }
+        );
+        assert_eq!(io.full.map(|full| full.total), Some(7606229));
+
+        // Kernels before 5.13 don't report a full line for CPU.
+        let cpu =
+            parse_pressure("some avg10=4.27 avg60=3.61 avg300=2.84 total=81927821\n").unwrap();
+        assert_eq!(cpu.some.avg10, 4.27);
+        assert!(cpu.full.is_none());
+
+        assert!(parse_pressure("").is_none());
+        assert!(parse_pressure("some avg10=abc avg60=0.00 avg300=0.00 total=0").is_none());
+    }
+}
@@ -0,0 +1,40 @@
+//! Data collection for pressure stall information (PSI).
+//!
+//! For Linux, this is read from `/proc/pressure/{cpu,memory,io}`, which requires a kernel with
+//! PSI support (4.20 or later, built with `CONFIG_PSI`).  Other platforms are unsupported.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        pub mod linux;
+        pub use self::linux::*;
+    }
+}
+
+/// A single line of a pressure file.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PressureRecord {
+    /// The percentage of time stalled over the last 10 seconds.
+    pub avg10: f64,
+    /// The percentage of time stalled over the last 60 seconds.
+    pub avg60: f64,
+    /// The percentage of time stalled over the last 300 seconds.
+    pub avg300: f64,
+    /// The total time stalled, in microseconds.
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourcePressure {
+    /// Time in which at least some tasks were stalled on the resource.
+    pub some: PressureRecord,
+    /// Time in which all non-idle tasks were stalled on the resource at once.  Older kernels do
+    /// not report this for CPU.
+    pub full: Option<PressureRecord>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PressureHarvest {
+    pub cpu: Option<ResourcePressure>,
+    pub memory: Option<ResourcePressure>,
+    pub io: Option<ResourcePressure>,
+}
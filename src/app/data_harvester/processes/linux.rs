@@ -1,15 +1,19 @@
 //! Process data collection for Linux.
 
 use std::collections::hash_map::Entry;
+use std::path::Path;
 
 use crate::utils::error::{self, BottomError};
 use crate::Pid;
 
-use super::ProcessHarvest;
+use super::{
+    get_rate_per_sec, FdBreakdown, KernelStatHarvest, MemBreakdown, ProcessDetailsToCollect,
+    ProcessHarvest,
+};
 
 use sysinfo::ProcessStatus;
 
-use procfs::process::{Process, Stat};
+use procfs::process::{FDTarget, LimitValue, Process, Stat};
 
 use fxhash::{FxHashMap, FxHashSet};
 
@@ -26,12 +30,12 @@ pub struct PrevProcDetails {
 }
 
 impl PrevProcDetails {
-    fn new(pid: Pid) -> error::Result<Self> {
+    fn new(proc_root: &Path, pid: Pid) -> error::Result<Self> {
         Ok(Self {
             total_read_bytes: 0,
             total_write_bytes: 0,
             cpu_time: 0,
-            process: Process::new(pid)?,
+            process: Process::new_with_root(proc_root.join(pid.to_string()))?,
         })
     }
 }
@@ -62,13 +66,13 @@ fn calculate_idle_values(line: String) -> (f64, f64) {
 }
 
 fn cpu_usage_calculation(
-    prev_idle: &mut f64, prev_non_idle: &mut f64,
+    prev_idle: &mut f64, prev_non_idle: &mut f64, proc_root: &Path,
 ) -> error::Result<(f64, f64)> {
     use std::io::prelude::*;
     use std::io::BufReader;
 
     // From SO answer: https://stackoverflow.com/a/23376195
-    let mut reader = BufReader::new(std::fs::File::open("/proc/stat")?);
+    let mut reader = BufReader::new(std::fs::File::open(proc_root.join("stat"))?);
     let mut first_line = String::new();
     reader.read_line(&mut first_line)?;
 
@@ -98,6 +102,34 @@ fn cpu_usage_calculation(
     Ok((result, cpu_percentage))
 }
 
+/// Parses the kernel activity lines of `/proc/stat`.  Only the first field of `intr`, its total, is
+/// used.
+fn parse_kernel_stats(contents: &str) -> KernelStatHarvest {
+    let mut kernel_stats = KernelStatHarvest::default();
+
+    for line in contents.lines() {
+        let mut split = line.split_whitespace();
+        if let (Some(key), Some(value)) = (split.next(), split.next()) {
+            let value = value.parse::<u64>().unwrap_or(0);
+            match key {
+                "ctxt" => kernel_stats.context_switches = value,
+                "intr" => kernel_stats.interrupts = value,
+                "processes" => kernel_stats.forks = value,
+                "procs_running" => kernel_stats.procs_running = value,
+                "procs_blocked" => kernel_stats.procs_blocked = value,
+                _ => {}
+            }
+        }
+    }
+
+    kernel_stats
+}
+
+/// Reads kernel activity counters from `stat` in procfs.
+pub fn get_kernel_stats(proc_root: &Path) -> std::io::Result<KernelStatHarvest> {
+    std::fs::read_to_string(proc_root.join("stat")).map(|contents| parse_kernel_stats(&contents))
+}
+
 /// Returns the usage and a new set of process times. Note: cpu_fraction should be represented WITHOUT the x100 factor!
 fn get_linux_cpu_usage(
     stat: &Stat, cpu_usage: f64, cpu_fraction: f64, prev_proc_times: u64,
@@ -116,10 +148,88 @@ fn get_linux_cpu_usage(
     }
 }
 
+/// Parses the contents of a `/proc/<PID>/smaps_rollup` file.
+fn parse_smaps_rollup(contents: &str) -> MemBreakdown {
+    let mut mem_breakdown = MemBreakdown::default();
+
+    for line in contents.lines() {
+        let mut split = line.split_whitespace();
+        if let (Some(key), Some(value)) = (split.next(), split.next()) {
+            // All values in this file are in kB.
+            let bytes = value.parse::<u64>().unwrap_or(0) * 1024;
+            match key {
+                "Pss:" => mem_breakdown.pss_bytes = bytes,
+                "Private_Clean:" | "Private_Dirty:" => mem_breakdown.uss_bytes += bytes,
+                "Shared_Clean:" | "Shared_Dirty:" => mem_breakdown.shared_bytes += bytes,
+                "Swap:" => mem_breakdown.swap_bytes = bytes,
+                _ => {}
+            }
+        }
+    }
+
+    mem_breakdown
+}
+
+/// Reads a process' memory breakdown.  This will fail if we don't have permission to read it
+/// (e.g. another user's process), or on kernels older than 4.14.
+fn read_mem_breakdown(proc_root: &Path, pid: Pid) -> Option<MemBreakdown> {
+    std::fs::read_to_string(proc_root.join(pid.to_string()).join("smaps_rollup"))
+        .ok()
+        .map(|contents| parse_smaps_rollup(&contents))
+}
+
+/// Tallies up a process' file descriptors by type.
+fn get_fd_breakdown<'a>(
+    fd_targets: impl Iterator<Item = &'a FDTarget>, soft_limit: Option<u64>,
+) -> FdBreakdown {
+    let mut fd_breakdown = FdBreakdown {
+        soft_limit,
+        ..FdBreakdown::default()
+    };
+
+    for target in fd_targets {
+        fd_breakdown.total += 1;
+        match target {
+            FDTarget::Socket(_) | FDTarget::Net(_) => fd_breakdown.sockets += 1,
+            FDTarget::Pipe(_) => fd_breakdown.pipes += 1,
+            FDTarget::Path(_) => fd_breakdown.files += 1,
+            _ => {}
+        }
+    }
+
+    fd_breakdown.limit_percent = match soft_limit {
+        Some(soft_limit) if soft_limit > 0 => {
+            Some(fd_breakdown.total as f64 / soft_limit as f64 * 100.0)
+        }
+        _ => None,
+    };
+
+    fd_breakdown
+}
+
+/// Reads a process' open file descriptors and its `RLIMIT_NOFILE` soft limit.  Like the memory
+/// breakdown, this requires permission to read the process' `/proc/<PID>/fd` directory.
+fn read_fd_breakdown(process: &Process) -> Option<FdBreakdown> {
+    let fds = process.fd().ok()?;
+    let soft_limit = match process.limits() {
+        Ok(limits) => match limits.max_open_files.soft_limit {
+            LimitValue::Value(value) => Some(value),
+            LimitValue::Unlimited => None,
+        },
+        Err(_) => None,
+    };
+
+    Some(get_fd_breakdown(
+        fds.iter().map(|fd_info| &fd_info.target),
+        soft_limit,
+    ))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn read_proc(
     prev_proc: &PrevProcDetails, stat: &Stat, cpu_usage: f64, cpu_fraction: f64,
-    use_current_cpu_total: bool, time_difference_in_secs: u64, mem_total_kb: u64,
+    use_current_cpu_total: bool, time_difference_in_secs: f64, mem_total_kb: u64,
+    details_to_collect: ProcessDetailsToCollect, proc_root: &Path,
 ) -> error::Result<(ProcessHarvest, u64)> {
     use std::convert::TryFrom;
 
@@ -170,24 +280,22 @@ fn read_proc(
     let mem_usage_percent = mem_usage_kb as f64 / mem_total_kb as f64 * 100.0;
 
     // This can fail if permission is denied!
+    let io = process.io();
+    let io_denied = matches!(io, Err(procfs::ProcError::PermissionDenied(_)));
 
     let (total_read_bytes, total_write_bytes, read_bytes_per_sec, write_bytes_per_sec) =
-        if let Ok(io) = process.io() {
+        if let Ok(io) = io {
             let total_read_bytes = io.read_bytes;
             let total_write_bytes = io.write_bytes;
 
-            let read_bytes_per_sec = if time_difference_in_secs == 0 {
-                0
-            } else {
-                total_read_bytes.saturating_sub(prev_proc.total_read_bytes)
-                    / time_difference_in_secs
-            };
-            let write_bytes_per_sec = if time_difference_in_secs == 0 {
-                0
-            } else {
-                total_write_bytes.saturating_sub(prev_proc.total_write_bytes)
-                    / time_difference_in_secs
-            };
+            let read_bytes_per_sec = get_rate_per_sec(
+                total_read_bytes.saturating_sub(prev_proc.total_read_bytes),
+                time_difference_in_secs,
+            );
+            let write_bytes_per_sec = get_rate_per_sec(
+                total_write_bytes.saturating_sub(prev_proc.total_write_bytes),
+                time_difference_in_secs,
+            );
 
             (
                 total_read_bytes,
@@ -199,6 +307,40 @@ fn read_proc(
             (0, 0, 0, 0)
         };
 
+    let exe_path = if details_to_collect.exe_path {
+        process
+            .exe()
+            .ok()
+            .map(|path| path.to_string_lossy().to_string())
+    } else {
+        None
+    };
+
+    // Prefer the unified (v2) hierarchy if it exists, otherwise just take the first one.
+    let cgroup = if details_to_collect.cgroup {
+        process.cgroups().ok().and_then(|cgroups| {
+            cgroups
+                .iter()
+                .find(|cgroup| cgroup.hierarchy == 0)
+                .or_else(|| cgroups.first())
+                .map(|cgroup| cgroup.pathname.clone())
+        })
+    } else {
+        None
+    };
+
+    let mem_breakdown = if details_to_collect.mem_breakdown {
+        read_mem_breakdown(proc_root, process.pid)
+    } else {
+        None
+    };
+
+    let fds = if details_to_collect.fds {
+        read_fd_breakdown(process)
+    } else {
+        None
+    };
+
     let uid = Some(process.owner);
 
     Ok((
@@ -216,29 +358,38 @@ fn read_proc(
             total_write_bytes,
             process_state,
             process_state_char,
+            exe_path,
+            cgroup,
+            mem_breakdown,
+            fds,
+            io_denied,
             uid,
         },
         new_process_times,
     ))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn get_process_data(
     prev_idle: &mut f64, prev_non_idle: &mut f64,
     pid_mapping: &mut FxHashMap<Pid, PrevProcDetails>, use_current_cpu_total: bool,
-    time_difference_in_secs: u64, mem_total_kb: u64,
+    time_difference_in_secs: f64, mem_total_kb: u64, details_to_collect: ProcessDetailsToCollect,
+    proc_root: &Path,
 ) -> crate::utils::error::Result<Vec<ProcessHarvest>> {
     // TODO: [PROC THREADS] Add threads
 
-    if let Ok((cpu_usage, cpu_fraction)) = cpu_usage_calculation(prev_idle, prev_non_idle) {
+    if let Ok((cpu_usage, cpu_fraction)) =
+        cpu_usage_calculation(prev_idle, prev_non_idle, proc_root)
+    {
         let mut pids_to_clear: FxHashSet<Pid> = pid_mapping.keys().cloned().collect();
 
-        let process_vector: Vec<ProcessHarvest> = std::fs::read_dir("/proc")?
+        let process_vector: Vec<ProcessHarvest> = std::fs::read_dir(proc_root)?
             .filter_map(|dir| {
                 if let Ok(dir) = dir {
                     if let Ok(pid) = dir.file_name().to_string_lossy().trim().parse::<Pid>() {
                         let mut fresh = false;
                         if let Entry::Vacant(entry) = pid_mapping.entry(pid) {
-                            if let Ok(ppd) = PrevProcDetails::new(pid) {
+                            if let Ok(ppd) = PrevProcDetails::new(proc_root, pid) {
                                 entry.insert(ppd);
                                 fresh = true;
                             } else {
@@ -268,6 +419,8 @@ pub fn get_process_data(
                                 use_current_cpu_total,
                                 time_difference_in_secs,
                                 mem_total_kb,
+                                details_to_collect,
+                                proc_root,
                             ) {
                                 prev_proc_details.cpu_time = new_process_times;
                                 prev_proc_details.total_read_bytes =
@@ -340,4 +493,90 @@ mod tests {
             "Failed to properly calculate idle/non-idle for /proc/stat CPU with 10 values"
         );
     }
+
+    #[test]
+    fn test_smaps_rollup_parse() {
+        let contents = "\
+55e6c1a4e000-7ffd5b9f3000 ---p 00000000 00:00 0                          [rollup]
+Rss:                4096 kB
+Pss:                2048 kB
+Pss_Anon:           1024 kB
+Shared_Clean:       1536 kB
+Shared_Dirty:        512 kB
+Private_Clean:       256 kB
+Private_Dirty:      1792 kB
+Referenced:         4096 kB
+Anonymous:          1024 kB
+Swap:                128 kB
+SwapPss:              64 kB
+Locked:                0 kB
+";
+        let mem_breakdown = parse_smaps_rollup(contents);
+        assert_eq!(mem_breakdown.pss_bytes, 2048 * 1024);
+        assert_eq!(mem_breakdown.uss_bytes, 2048 * 1024);
+        assert_eq!(mem_breakdown.shared_bytes, 2048 * 1024);
+        assert_eq!(mem_breakdown.swap_bytes, 128 * 1024);
+    }
+
+    #[test]
+    fn test_kernel_stats_parse() {
+        let contents = "\
+cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0
+intr 1462898 44 9 0 0 0 0 0 0 1 0
+ctxt 2250343
+btime 1062191376
+processes 26442
+procs_running 3
+procs_blocked 1
+softirq 12121993 0 4120403 13 1044829 0 0 0 0 0 6956748
+";
+        let kernel_stats = parse_kernel_stats(contents);
+        assert_eq!(
+            kernel_stats,
+            KernelStatHarvest {
+                context_switches: 2_250_343,
+                interrupts: 1_462_898,
+                forks: 26_442,
+                procs_running: 3,
+                procs_blocked: 1,
+            }
+        );
+
+        let later = KernelStatHarvest {
+            context_switches: 2_260_343,
+            interrupts: 1_463_898,
+            forks: 26_452,
+            procs_running: 5,
+            procs_blocked: 0,
+        };
+        let rates = later.rates_since(&kernel_stats, 2.0);
+        assert_eq!(rates.context_switches, 5000.0);
+        assert_eq!(rates.interrupts, 500.0);
+        assert_eq!(rates.forks, 5.0);
+        assert_eq!(rates.procs_running, 5);
+        assert_eq!(rates.procs_blocked, 0);
+    }
+
+    #[test]
+    fn test_fd_breakdown() {
+        let targets = [
+            FDTarget::Path("/dev/null".into()),
+            FDTarget::Path("/home/user/file.txt".into()),
+            FDTarget::Socket(1234),
+            FDTarget::Pipe(5678),
+            FDTarget::AnonInode("[eventpoll]".to_string()),
+        ];
+
+        let fd_breakdown = get_fd_breakdown(targets.iter(), Some(10));
+        assert_eq!(fd_breakdown.total, 5);
+        assert_eq!(fd_breakdown.sockets, 1);
+        assert_eq!(fd_breakdown.pipes, 1);
+        assert_eq!(fd_breakdown.files, 2);
+        assert_eq!(fd_breakdown.soft_limit, Some(10));
+        assert_eq!(fd_breakdown.limit_percent, Some(50.0));
+
+        let fd_breakdown = get_fd_breakdown(targets.iter(), None);
+        assert_eq!(fd_breakdown.limit_percent, None);
+    }
 }
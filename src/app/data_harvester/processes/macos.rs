@@ -1,6 +1,6 @@
 //! Process data collection for macOS.  Uses sysinfo.
 
-use super::ProcessHarvest;
+use super::{get_rate_per_sec, ProcessHarvest};
 use sysinfo::{PidExt, ProcessExt, ProcessStatus, ProcessorExt, System, SystemExt};
 
 fn get_macos_process_cpu_usage(
@@ -35,7 +35,7 @@ fn get_macos_process_cpu_usage(
 }
 
 pub fn get_process_data(
-    sys: &System, use_current_cpu_total: bool, mem_total_kb: u64,
+    sys: &System, use_current_cpu_total: bool, time_difference_in_secs: f64, mem_total_kb: u64,
 ) -> crate::utils::error::Result<Vec<ProcessHarvest>> {
     let mut process_vector: Vec<ProcessHarvest> = Vec::new();
     let process_hashmap = sys.processes();
@@ -98,12 +98,27 @@ pub fn get_process_data(
             },
             mem_usage_bytes: process_val.memory() * 1024,
             cpu_usage_percent: process_cpu_usage,
-            read_bytes_per_sec: disk_usage.read_bytes,
-            write_bytes_per_sec: disk_usage.written_bytes,
+            read_bytes_per_sec: get_rate_per_sec(disk_usage.read_bytes, time_difference_in_secs),
+            write_bytes_per_sec: get_rate_per_sec(
+                disk_usage.written_bytes,
+                time_difference_in_secs,
+            ),
             total_read_bytes: disk_usage.total_read_bytes,
             total_write_bytes: disk_usage.total_written_bytes,
             process_state: process_val.status().to_string(),
             process_state_char: convert_process_status_to_char(process_val.status()),
+            exe_path: {
+                let exe = process_val.exe();
+                if exe.as_os_str().is_empty() {
+                    None
+                } else {
+                    Some(exe.to_string_lossy().to_string())
+                }
+            },
+            cgroup: None,
+            mem_breakdown: None,
+            fds: None,
+            io_denied: false,
             uid: Some(process_val.uid),
         });
     }
@@ -23,7 +23,7 @@ cfg_if::cfg_if! {
     }
 }
 
-use crate::Pid;
+use crate::{utils::error::BottomError, Pid};
 
 // TODO: Add value so we know if it's sorted ascending or descending by default?
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -41,6 +41,15 @@ pub enum ProcessSorting {
     State,
     User,
     Count,
+    Pss,
+    Uss,
+    SharedMem,
+    Swap,
+    Fds,
+    FdPercent,
+    SocketFds,
+    PipeFds,
+    FileFds,
 }
 
 impl std::fmt::Display for ProcessSorting {
@@ -62,17 +71,191 @@ impl std::fmt::Display for ProcessSorting {
                 ProcessSorting::Pid => "PID",
                 ProcessSorting::Count => "Count",
                 ProcessSorting::User => "User",
+                ProcessSorting::Pss => "PSS",
+                ProcessSorting::Uss => "USS",
+                ProcessSorting::SharedMem => "Shared",
+                ProcessSorting::Swap => "Swap",
+                ProcessSorting::Fds => "FDs",
+                ProcessSorting::FdPercent => "FD%",
+                ProcessSorting::SocketFds => "Sockets",
+                ProcessSorting::PipeFds => "Pipes",
+                ProcessSorting::FileFds => "Files",
             }
         )
     }
 }
 
+/// Parses one of the optional process columns, as named in the config file.
+pub fn parse_extra_column(s: &str) -> Result<ProcessSorting, BottomError> {
+    match s.to_lowercase().as_str() {
+        "pss" => Ok(ProcessSorting::Pss),
+        "uss" => Ok(ProcessSorting::Uss),
+        "shared" => Ok(ProcessSorting::SharedMem),
+        "swap" => Ok(ProcessSorting::Swap),
+        "fds" => Ok(ProcessSorting::Fds),
+        "fd%" => Ok(ProcessSorting::FdPercent),
+        "sockets" => Ok(ProcessSorting::SocketFds),
+        "pipes" => Ok(ProcessSorting::PipeFds),
+        "files" => Ok(ProcessSorting::FileFds),
+        _ => Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid extra process column, use \"<pss|uss|shared|swap|fds|fd%|sockets|pipes|files>\".",
+            s
+        ))),
+    }
+}
+
 impl Default for ProcessSorting {
     fn default() -> Self {
         ProcessSorting::CpuPercent
     }
 }
 
+/// Optional process details which are more expensive to collect, and so are only collected if
+/// something actually uses them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessDetailsToCollect {
+    pub mem_breakdown: bool,
+    pub fds: bool,
+    /// Only needed to group by executable path.
+    pub exe_path: bool,
+    /// Only needed to group by cgroup.
+    pub cgroup: bool,
+}
+
+impl ProcessDetailsToCollect {
+    /// Determines what needs to be collected from the enabled extra process columns.
+    pub fn from_columns(columns: &[ProcessSorting]) -> Self {
+        ProcessDetailsToCollect {
+            mem_breakdown: columns.iter().any(|column| {
+                matches!(
+                    column,
+                    ProcessSorting::Pss
+                        | ProcessSorting::Uss
+                        | ProcessSorting::SharedMem
+                        | ProcessSorting::Swap
+                )
+            }),
+            fds: columns.iter().any(|column| {
+                matches!(
+                    column,
+                    ProcessSorting::Fds
+                        | ProcessSorting::FdPercent
+                        | ProcessSorting::SocketFds
+                        | ProcessSorting::PipeFds
+                        | ProcessSorting::FileFds
+                )
+            }),
+            exe_path: false,
+            cgroup: false,
+        }
+    }
+}
+
+/// A breakdown of a process' memory usage, from `/proc/<PID>/smaps_rollup`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemBreakdown {
+    /// Proportional set size, where shared pages are split evenly between the processes sharing them.
+    pub pss_bytes: u64,
+    /// Unique set size, or memory that is private to the process.
+    pub uss_bytes: u64,
+    pub shared_bytes: u64,
+    pub swap_bytes: u64,
+}
+
+impl std::ops::AddAssign for MemBreakdown {
+    fn add_assign(&mut self, other: Self) {
+        self.pss_bytes += other.pss_bytes;
+        self.uss_bytes += other.uss_bytes;
+        self.shared_bytes += other.shared_bytes;
+        self.swap_bytes += other.swap_bytes;
+    }
+}
+
+/// A breakdown of a process' open file descriptors, from `/proc/<PID>/fd` and
+/// `/proc/<PID>/limits`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FdBreakdown {
+    pub total: u64,
+    pub sockets: u64,
+    pub pipes: u64,
+    /// File descriptors pointing to a path, such as regular files and devices.
+    pub files: u64,
+    /// The soft `RLIMIT_NOFILE` limit.  This is `None` if unlimited, or if this is the sum of
+    /// multiple processes.
+    pub soft_limit: Option<u64>,
+    /// The percentage of the soft limit in use.  For the sum of multiple processes, this is the
+    /// highest percentage of any of them.
+    pub limit_percent: Option<f64>,
+}
+
+impl std::ops::AddAssign for FdBreakdown {
+    fn add_assign(&mut self, other: Self) {
+        self.total += other.total;
+        self.sockets += other.sockets;
+        self.pipes += other.pipes;
+        self.files += other.files;
+        self.soft_limit = None;
+        self.limit_percent = match (self.limit_percent, other.limit_percent) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+/// System-wide kernel activity, from `/proc/stat`.  All but the last two are counters since boot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KernelStatHarvest {
+    pub context_switches: u64,
+    pub interrupts: u64,
+    /// The number of processes and threads created.
+    pub forks: u64,
+    /// The number of runnable processes right now.
+    pub procs_running: u64,
+    /// The number of processes blocked on I/O right now.
+    pub procs_blocked: u64,
+}
+
+/// Per-second rates of [`KernelStatHarvest`]'s counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct KernelStatRates {
+    pub context_switches: f64,
+    pub interrupts: f64,
+    pub forks: f64,
+    pub procs_running: u64,
+    pub procs_blocked: u64,
+}
+
+impl KernelStatHarvest {
+    /// Computes rates over the `elapsed_secs` seconds since `prev` was read.
+    pub fn rates_since(&self, prev: &KernelStatHarvest, elapsed_secs: f64) -> KernelStatRates {
+        let rate = |current: u64, prev: u64| {
+            if elapsed_secs > 0.0 {
+                current.saturating_sub(prev) as f64 / elapsed_secs
+            } else {
+                0.0
+            }
+        };
+
+        KernelStatRates {
+            context_switches: rate(self.context_switches, prev.context_switches),
+            interrupts: rate(self.interrupts, prev.interrupts),
+            forks: rate(self.forks, prev.forks),
+            procs_running: self.procs_running,
+            procs_blocked: self.procs_blocked,
+        }
+    }
+}
+
+/// Returns the per-second rate of a counter that rose by `delta` over `elapsed_secs` seconds.
+/// The elapsed time is fractional, as collection can happen more than once a second.
+pub fn get_rate_per_sec(delta: u64, elapsed_secs: f64) -> u64 {
+    if elapsed_secs > 0.0 {
+        (delta as f64 / elapsed_secs).round() as u64
+    } else {
+        0
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ProcessHarvest {
     pub pid: Pid,
@@ -91,7 +274,36 @@ pub struct ProcessHarvest {
     pub process_state: String,
     pub process_state_char: char,
 
+    /// The path to the process' executable, if it could be read.
+    pub exe_path: Option<String>,
+
+    /// The process' control group, if supported.
+    pub cgroup: Option<String>,
+
+    /// The process' memory breakdown, if collected and permitted.
+    pub mem_breakdown: Option<MemBreakdown>,
+
+    /// The process' file descriptor breakdown, if collected and permitted.
+    pub fds: Option<FdBreakdown>,
+
+    /// Whether reading the process' I/O counters was denied, like for another user's process
+    /// when not running as root.
+    pub io_denied: bool,
+
     /// This is the *effective* user ID.
     #[cfg(target_family = "unix")]
     pub uid: Option<libc::uid_t>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_second_rates() {
+        // 256 KiB over a quarter of a second.
+        assert_eq!(get_rate_per_sec(262144, 0.25), 1048576);
+        assert_eq!(get_rate_per_sec(1000, 1.5), 667);
+        assert_eq!(get_rate_per_sec(1000, 0.0), 0);
+    }
+}
@@ -0,0 +1,211 @@
+//! Linux-specific functions regarding hwmon sensors.
+
+use std::path::Path;
+
+use super::{SensorHarvest, SensorKind};
+
+/// Splits an attribute file name like `fan2_input` into the sensor kind, the sensor's prefix
+/// (`fan2`), and the attribute (`input`).
+fn parse_attribute_name(file_name: &str) -> Option<(SensorKind, &str, &str)> {
+    let underscore = file_name.find('_')?;
+    let (prefix, attribute) = (&file_name[..underscore], &file_name[underscore + 1..]);
+    let kind_name = prefix.trim_end_matches(|c: char| c.is_ascii_digit());
+    if kind_name.len() == prefix.len() {
+        return None;
+    }
+
+    let kind = match kind_name {
+        "temp" => SensorKind::Temperature,
+        "fan" => SensorKind::Fan,
+        "in" => SensorKind::Voltage,
+        "power" => SensorKind::Power,
+        _ => return None,
+    };
+
+    Some((kind, prefix, attribute))
+}
+
+/// The amount hwmon values need to be divided by to get the units used by [`SensorHarvest`].
+/// See <https://www.kernel.org/doc/html/latest/hwmon/sysfs-interface.html>.
+fn get_divisor(kind: SensorKind) -> f64 {
+    match kind {
+        SensorKind::Temperature => 1000.0, // Millidegrees Celsius
+        SensorKind::Fan => 1.0,            // RPM
+        SensorKind::Voltage => 1000.0,     // Millivolts
+        SensorKind::Power => 1_000_000.0,  // Microwatts
+    }
+}
+
+fn read_value(path: &Path) -> Option<f64> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Reads all sensors of a single hwmon device directory.
+fn get_chip_sensors(chip_dir: &Path) -> Vec<SensorHarvest> {
+    let chip = std::fs::read_to_string(chip_dir.join("name"))
+        .map(|name| name.trim().to_string())
+        .unwrap_or_else(|_| {
+            chip_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+
+    let mut prefixes = match std::fs::read_dir(chip_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|file_name| {
+                let (kind, prefix, attribute) = parse_attribute_name(&file_name)?;
+                // Some power sensors only provide an average.
+                if attribute == "input" || (kind == SensorKind::Power && attribute == "average") {
+                    Some((kind, prefix.to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => return vec![],
+    };
+    prefixes.sort_by(|(a_kind, a_prefix), (b_kind, b_prefix)| {
+        a_kind
+            .cmp(b_kind)
+            .then_with(|| a_prefix.len().cmp(&b_prefix.len()))
+            .then_with(|| a_prefix.cmp(b_prefix))
+    });
+    prefixes.dedup();
+
+    prefixes
+        .into_iter()
+        .filter_map(|(kind, prefix)| {
+            let read_attribute =
+                |attribute: &str| read_value(&chip_dir.join(format!("{}_{}", prefix, attribute)));
+            let divisor = get_divisor(kind);
+
+            let value = read_attribute("input").or_else(|| read_attribute("average"))?;
+            let label = std::fs::read_to_string(chip_dir.join(format!("{}_label", prefix)))
+                .map(|label| label.trim().to_string())
+                .unwrap_or_else(|_| prefix.clone());
+
+            Some(SensorHarvest {
+                chip: chip.clone(),
+                label,
+                kind,
+                value: value / divisor,
+                max: read_attribute("max").map(|max| max / divisor),
+                crit: read_attribute("crit").map(|crit| crit / divisor),
+            })
+        })
+        .collect()
+}
+
+/// Reads all hwmon sensors under `<sys_root>/class/hwmon`.  A system without hwmon just has no
+/// sensors.
+pub fn get_sensor_data(sys_root: &Path) -> std::io::Result<Vec<SensorHarvest>> {
+    let mut chip_dirs = match std::fs::read_dir(sys_root.join("class/hwmon")) {
+        Ok(entries) => entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .collect::<Vec<_>>(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+    chip_dirs.sort();
+
+    Ok(chip_dirs
+        .iter()
+        .flat_map(|chip_dir| get_chip_sensors(chip_dir))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_util::TempDir;
+
+    #[test]
+    fn test_hwmon_attribute_parse() {
+        assert_eq!(
+            parse_attribute_name("fan12_input"),
+            Some((SensorKind::Fan, "fan12", "input"))
+        );
+        assert_eq!(
+            parse_attribute_name("temp1_crit_alarm"),
+            Some((SensorKind::Temperature, "temp1", "crit_alarm"))
+        );
+        assert_eq!(parse_attribute_name("name"), None);
+        assert_eq!(parse_attribute_name("temp_input"), None);
+        assert_eq!(parse_attribute_name("pwm1_enable"), None);
+    }
+
+    #[test]
+    fn test_hwmon_fixture() {
+        let sys_root = TempDir::new("hwmon");
+        let chip_dir = sys_root.path().join("class/hwmon/hwmon0");
+        std::fs::create_dir_all(&chip_dir).unwrap();
+        for (file, contents) in [
+            ("name", "nct6775\n"),
+            ("temp1_input", "45500\n"),
+            ("temp1_max", "80000\n"),
+            ("temp1_crit", "100000\n"),
+            ("temp1_label", "SYSTIN\n"),
+            ("fan2_input", "1200\n"),
+            ("fan10_input", "900\n"),
+            ("in0_input", "1032\n"),
+            ("power1_average", "12500000\n"),
+            ("pwm1", "128\n"),
+        ] {
+            std::fs::write(chip_dir.join(file), contents).unwrap();
+        }
+
+        let sensors = get_sensor_data(sys_root.path()).unwrap();
+
+        assert_eq!(
+            sensors,
+            vec![
+                SensorHarvest {
+                    chip: "nct6775".to_string(),
+                    label: "SYSTIN".to_string(),
+                    kind: SensorKind::Temperature,
+                    value: 45.5,
+                    max: Some(80.0),
+                    crit: Some(100.0),
+                },
+                SensorHarvest {
+                    chip: "nct6775".to_string(),
+                    label: "fan2".to_string(),
+                    kind: SensorKind::Fan,
+                    value: 1200.0,
+                    max: None,
+                    crit: None,
+                },
+                SensorHarvest {
+                    chip: "nct6775".to_string(),
+                    label: "fan10".to_string(),
+                    kind: SensorKind::Fan,
+                    value: 900.0,
+                    max: None,
+                    crit: None,
+                },
+                SensorHarvest {
+                    chip: "nct6775".to_string(),
+                    label: "in0".to_string(),
+                    kind: SensorKind::Voltage,
+                    value: 1.032,
+                    max: None,
+                    crit: None,
+                },
+                SensorHarvest {
+                    chip: "nct6775".to_string(),
+                    label: "power1".to_string(),
+                    kind: SensorKind::Power,
+                    value: 12.5,
+                    max: None,
+                    crit: None,
+                },
+            ]
+        );
+
+        assert!(get_sensor_data(Path::new("/nonexistent"))
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+//! Data collection for hardware monitoring sensors, like fans, voltages, power, and temperatures.
+//!
+//! For Linux, this is read from hwmon in sysfs.  Other platforms are unsupported.
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        pub mod linux;
+        pub use self::linux::*;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensorKind {
+    Temperature,
+    Fan,
+    Voltage,
+    Power,
+}
+
+impl SensorKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "Temp",
+            SensorKind::Fan => "Fan",
+            SensorKind::Voltage => "Voltage",
+            SensorKind::Power => "Power",
+        }
+    }
+}
+
+/// A single sensor reading.  Values are in degrees Celsius, RPM, volts, or watts, depending
+/// on the kind of sensor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorHarvest {
+    /// The name of the chip the sensor belongs to, like `k10temp` or `nct6775`.
+    pub chip: String,
+    /// The sensor's label if the driver provides one, otherwise its attribute name (like `fan1`).
+    pub label: String,
+    pub kind: SensorKind,
+    pub value: f64,
+    pub max: Option<f64>,
+    pub crit: Option<f64>,
+}
@@ -3,8 +3,11 @@
 use super::{is_temp_filtered, temp_vec_sort, TempHarvest, TemperatureType};
 use crate::app::Filter;
 
+/// Gets the temperature of every sensor that isn't filtered out, counting those that are in
+/// `filtered_out`.
 pub async fn get_temperature_data(
     temp_type: &TemperatureType, actually_get: bool, filter: &Option<Filter>,
+    filtered_out: &mut usize,
 ) -> crate::utils::error::Result<Option<Vec<TempHarvest>>> {
     use futures::StreamExt;
     use heim::units::thermodynamic_temperature;
@@ -29,29 +32,33 @@ pub async fn get_temperature_data(
             };
 
             if is_temp_filtered(filter, &name) {
+                let convert = |temperature: heim::units::ThermodynamicTemperature| match temp_type {
+                    TemperatureType::Celsius => {
+                        temperature.get::<thermodynamic_temperature::degree_celsius>()
+                    }
+                    TemperatureType::Kelvin => {
+                        temperature.get::<thermodynamic_temperature::kelvin>()
+                    }
+                    TemperatureType::Fahrenheit => {
+                        temperature.get::<thermodynamic_temperature::degree_fahrenheit>()
+                    }
+                };
+
                 temperature_vec.push(TempHarvest {
                     name,
-                    temperature: match temp_type {
-                        TemperatureType::Celsius => sensor
-                            .current()
-                            .get::<thermodynamic_temperature::degree_celsius>(
-                        ),
-                        TemperatureType::Kelvin => {
-                            sensor.current().get::<thermodynamic_temperature::kelvin>()
-                        }
-                        TemperatureType::Fahrenheit => sensor
-                            .current()
-                            .get::<thermodynamic_temperature::degree_fahrenheit>(
-                        ),
-                    },
+                    temperature: convert(sensor.current()),
+                    max: sensor.high().map(convert),
+                    crit: sensor.critical().map(convert),
                 });
+            } else {
+                *filtered_out += 1;
             }
         }
     }
 
     #[cfg(feature = "nvidia")]
     {
-        super::nvidia::add_nvidia_data(&mut temperature_vec, temp_type, filter)?;
+        super::nvidia::add_nvidia_data(&mut temperature_vec, temp_type, filter, filtered_out)?;
     }
 
     temp_vec_sort(&mut temperature_vec);
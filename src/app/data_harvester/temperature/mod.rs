@@ -24,6 +24,10 @@ use crate::app::Filter;
 pub struct TempHarvest {
     pub name: String,
     pub temperature: f32,
+    /// The temperature the sensor's driver considers high, if it reports one.
+    pub max: Option<f32>,
+    /// The temperature the sensor's driver considers critical, if it reports one.
+    pub crit: Option<f32>,
 }
 
 #[derive(Clone, Debug)]
@@ -39,18 +43,54 @@ impl Default for TemperatureType {
     }
 }
 
-cfg_if::cfg_if! {
-    if #[cfg(any(feature = "nvidia", target_os = "macos", target_os = "windows"))] {
-        fn convert_celsius_to_kelvin(celsius: f32) -> f32 {
-            celsius + 273.15
+impl TemperatureType {
+    /// Converts a temperature in degrees Celsius into this unit.
+    pub fn convert_from_celsius(&self, celsius: f64) -> f64 {
+        match self {
+            TemperatureType::Celsius => celsius,
+            TemperatureType::Kelvin => f64::from(convert_celsius_to_kelvin(celsius as f32)),
+            TemperatureType::Fahrenheit => f64::from(convert_celsius_to_fahrenheit(celsius as f32)),
         }
+    }
+}
 
-        fn convert_celsius_to_fahrenheit(celsius: f32) -> f32 {
-            (celsius * (9.0 / 5.0)) + 32.0
-        }
+/// How close a temperature is to its sensor's thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatLevel {
+    Normal,
+    /// At or above the sensor's max, or close to its crit if it has no max.
+    Warm,
+    /// At or above the sensor's crit.
+    Critical,
+}
+
+/// Returns how hot `temperature` is relative to `max` and `crit`.  All three must be in the
+/// same unit, given by `temp_type`.
+pub fn get_heat_level(
+    temperature: f64, max: Option<f64>, crit: Option<f64>, temp_type: &TemperatureType,
+) -> HeatLevel {
+    // How far below crit a sensor without a max is considered warm; 10 degrees Celsius.
+    let warm_margin = match temp_type {
+        TemperatureType::Celsius | TemperatureType::Kelvin => 10.0,
+        TemperatureType::Fahrenheit => 18.0,
+    };
+
+    match (max, crit) {
+        (_, Some(crit)) if temperature >= crit => HeatLevel::Critical,
+        (Some(max), _) if temperature >= max => HeatLevel::Warm,
+        (None, Some(crit)) if temperature >= crit - warm_margin => HeatLevel::Warm,
+        _ => HeatLevel::Normal,
     }
 }
 
+fn convert_celsius_to_kelvin(celsius: f32) -> f32 {
+    celsius + 273.15
+}
+
+fn convert_celsius_to_fahrenheit(celsius: f32) -> f32 {
+    (celsius * (9.0 / 5.0)) + 32.0
+}
+
 fn is_temp_filtered(filter: &Option<Filter>, text: &str) -> bool {
     if let Some(filter) = filter {
         if filter.is_list_ignored {
@@ -86,3 +126,51 @@ fn temp_vec_sort(temperature_vec: &mut [TempHarvest]) {
 
     temperature_vec.sort_by(|a, b| a.name.partial_cmp(&b.name).unwrap_or(Ordering::Equal));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_level() {
+        let celsius = TemperatureType::Celsius;
+        let fahrenheit = TemperatureType::Fahrenheit;
+
+        assert_eq!(
+            get_heat_level(50.0, None, None, &celsius),
+            HeatLevel::Normal
+        );
+        assert_eq!(
+            get_heat_level(50.0, Some(80.0), Some(100.0), &celsius),
+            HeatLevel::Normal
+        );
+        assert_eq!(
+            get_heat_level(95.0, Some(80.0), Some(100.0), &celsius),
+            HeatLevel::Warm
+        );
+        assert_eq!(
+            get_heat_level(100.0, Some(80.0), Some(100.0), &celsius),
+            HeatLevel::Critical
+        );
+        assert_eq!(
+            get_heat_level(85.0, None, Some(100.0), &celsius),
+            HeatLevel::Normal
+        );
+        assert_eq!(
+            get_heat_level(92.0, None, Some(100.0), &celsius),
+            HeatLevel::Warm
+        );
+        assert_eq!(
+            get_heat_level(195.0, None, Some(212.0), &fahrenheit),
+            HeatLevel::Warm
+        );
+        assert_eq!(
+            get_heat_level(190.0, None, Some(212.0), &fahrenheit),
+            HeatLevel::Normal
+        );
+        assert_eq!(
+            get_heat_level(85.0, Some(80.0), None, &celsius),
+            HeatLevel::Warm
+        );
+    }
+}
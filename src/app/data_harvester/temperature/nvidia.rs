@@ -9,6 +9,7 @@ use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, NVML};
 
 pub fn add_nvidia_data(
     temperature_vec: &mut Vec<TempHarvest>, temp_type: &TemperatureType, filter: &Option<Filter>,
+    filtered_out: &mut usize,
 ) -> crate::utils::error::Result<()> {
     if let Ok(nvml) = NVML::init() {
         if let Ok(ngpu) = nvml.device_count() {
@@ -27,7 +28,14 @@ pub fn add_nvidia_data(
                                 }
                             };
 
-                            temperature_vec.push(TempHarvest { name, temperature });
+                            temperature_vec.push(TempHarvest {
+                                name,
+                                temperature,
+                                max: None,
+                                crit: None,
+                            });
+                        } else {
+                            *filtered_out += 1;
                         }
                     }
                 }
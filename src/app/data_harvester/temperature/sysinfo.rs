@@ -6,8 +6,11 @@ use super::{
 };
 use crate::app::Filter;
 
+/// Gets the temperature of every sensor that isn't filtered out, counting those that are in
+/// `filtered_out`.
 pub async fn get_temperature_data(
-    sys: &sysinfo::System, temp_type: &TemperatureType, actually_get: bool, filter: &Option<Filter>,
+    sys: &sysinfo::System, temp_type: &TemperatureType, actually_get: bool,
+    filter: &Option<Filter>, filtered_out: &mut usize,
 ) -> crate::utils::error::Result<Option<Vec<TempHarvest>>> {
     use sysinfo::{ComponentExt, SystemExt};
 
@@ -22,22 +25,27 @@ pub async fn get_temperature_data(
         let name = component.label().to_string();
 
         if is_temp_filtered(filter, &name) {
+            let convert = |temperature: f32| match temp_type {
+                TemperatureType::Celsius => temperature,
+                TemperatureType::Kelvin => convert_celsius_to_kelvin(temperature),
+                TemperatureType::Fahrenheit => convert_celsius_to_fahrenheit(temperature),
+            };
+
             temperature_vec.push(TempHarvest {
                 name,
-                temperature: match temp_type {
-                    TemperatureType::Celsius => component.temperature(),
-                    TemperatureType::Kelvin => convert_celsius_to_kelvin(component.temperature()),
-                    TemperatureType::Fahrenheit => {
-                        convert_celsius_to_fahrenheit(component.temperature())
-                    }
-                },
+                temperature: convert(component.temperature()),
+                // sysinfo's max is the highest temperature seen, not a threshold.
+                max: None,
+                crit: component.critical().map(convert),
             });
+        } else {
+            *filtered_out += 1;
         }
     }
 
     #[cfg(feature = "nvidia")]
     {
-        super::nvidia::add_nvidia_data(&mut temperature_vec, temp_type, filter)?;
+        super::nvidia::add_nvidia_data(&mut temperature_vec, temp_type, filter, filtered_out)?;
     }
 
     temp_vec_sort(&mut temperature_vec);
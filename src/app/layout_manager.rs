@@ -898,17 +898,32 @@ pub enum BottomWidgetType {
     BasicNet,
     BasicTables,
     Battery,
+    Socket,
+    Pressure,
+    DiskUtil,
+    DiskIo,
+    TempGraph,
+    Sensor,
+    NetInterface,
+    CpuFreq,
+    LoadAvg,
 }
 
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | Proc | ProcSort | Temp | CpuLegend | Socket | Sensor | NetInterface
+        )
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(
+            self,
+            Cpu | Net | Mem | Pressure | DiskUtil | DiskIo | TempGraph | CpuFreq | LoadAvg
+        )
     }
 
     pub fn get_pretty_name(&self) -> &str {
@@ -921,6 +936,15 @@ impl BottomWidgetType {
             Temp => "Temperature",
             Disk => "Disks",
             Battery => "Battery",
+            Socket => "Sockets",
+            Pressure => "Pressure",
+            LoadAvg => "Load Average",
+            CpuFreq => "CPU Frequency",
+            TempGraph => "Temperature Graph",
+            Sensor => "Sensors",
+            NetInterface => "Interfaces",
+            DiskIo => "Disk I/O",
+            DiskUtil => "Disk Utilization",
             _ => "",
         }
     }
@@ -944,6 +968,15 @@ impl std::str::FromStr for BottomWidgetType {
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
             "disk" => Ok(BottomWidgetType::Disk),
+            "socket" | "sockets" | "connections" => Ok(BottomWidgetType::Socket),
+            "pressure" | "psi" => Ok(BottomWidgetType::Pressure),
+            "load_avg" | "loadavg" | "load" => Ok(BottomWidgetType::LoadAvg),
+            "cpu_freq" | "cpufreq" => Ok(BottomWidgetType::CpuFreq),
+            "temp_graph" | "tempgraph" => Ok(BottomWidgetType::TempGraph),
+            "sensor" | "sensors" | "hwmon" => Ok(BottomWidgetType::Sensor),
+            "net_interface" | "interfaces" => Ok(BottomWidgetType::NetInterface),
+            "disk_io" => Ok(BottomWidgetType::DiskIo),
+            "disk_util" | "diskutil" => Ok(BottomWidgetType::DiskUtil),
             "empty" => Ok(BottomWidgetType::Empty),
             "battery" | "batt" if cfg!(feature = "battery") => Ok(BottomWidgetType::Battery),
             _ => {
@@ -965,6 +998,24 @@ Supported widget names:
 +--------------------------+
 |           disk           |
 +--------------------------+
+|   socket, connections    |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|   disk_util, diskutil    |
++--------------------------+
+|          disk_io         |
++--------------------------+
+|  temp_graph, tempgraph   |
++--------------------------+
+|  sensor, sensors, hwmon  |
++--------------------------+
+|net_interface, interfaces |
++--------------------------+
+|    cpu_freq, cpufreq     |
++--------------------------+
+| load_avg, loadavg, load  |
++--------------------------+
 |       batt, battery      |
 +--------------------------+
                 ",
@@ -987,6 +1038,24 @@ Supported widget names:
 |     temp, temperature    |
 +--------------------------+
 |           disk           |
++--------------------------+
+|   socket, connections    |
++--------------------------+
+|       pressure, psi      |
++--------------------------+
+|   disk_util, diskutil    |
++--------------------------+
+|          disk_io         |
++--------------------------+
+|  temp_graph, tempgraph   |
++--------------------------+
+|  sensor, sensors, hwmon  |
++--------------------------+
+|net_interface, interfaces |
++--------------------------+
+|    cpu_freq, cpufreq     |
++--------------------------+
+| load_avg, loadavg, load  |
 +--------------------------+
                 ",
                         s
@@ -1006,4 +1075,10 @@ pub struct UsedWidgets {
     pub use_disk: bool,
     pub use_temp: bool,
     pub use_battery: bool,
+    pub use_socket: bool,
+    pub use_pressure: bool,
+    pub use_sensor: bool,
+    pub use_net_interface: bool,
+    pub use_cpu_freq: bool,
+    pub use_load_avg: bool,
 }
@@ -1,10 +1,10 @@
-use super::ProcWidgetState;
+use super::{
+    data_harvester::{network::sockets::SocketHarvest, processes::ProcessSorting},
+    ProcWidgetState,
+};
 use crate::{
     data_conversion::ConvertedProcessData,
-    utils::error::{
-        BottomError::{self, QueryError},
-        Result,
-    },
+    utils::error::{BottomError::QueryError, Result},
 };
 use std::fmt::Debug;
 use std::{borrow::Cow, collections::VecDeque};
@@ -26,6 +26,10 @@ pub trait ProcessQuery {
     /// - PIDs: Use prefix `pid`, can use regex or match word (case is irrelevant).
     /// - CPU: Use prefix `cpu`, cannot use r/m/c (regex, match word, case).  Can compare.
     /// - MEM: Use prefix `mem`, cannot use r/m/c.  Can compare.
+    /// - PSS, USS, shared, and swap memory: Use prefixes `pss`, `uss`, `shared`, and `swap`.
+    ///   Can compare, and only match processes with a memory breakdown (Linux).
+    /// - FDs: Use prefix `fds` for the count, or `fd%` for the percentage of the soft limit.
+    ///   Can compare, and only match processes whose file descriptors could be read (Linux).
     /// - STATE: Use prefix `state`, can use regex, match word, or case.
     /// - USER: Use prefix `user`, can use regex, match word, or case.
     /// - Read/s: Use prefix `r`.  Can compare.
@@ -41,26 +45,51 @@ pub trait ProcessQuery {
 
 impl ProcessQuery for ProcWidgetState {
     fn parse_query(&self) -> Result<Query> {
-        fn process_string_to_filter(query: &mut VecDeque<String>) -> Result<Query> {
-            let lhs = process_or(query)?;
+        QueryKind::Process.parse_query(
+            self.get_current_search_query(),
+            self.process_search_state.is_searching_whole_word,
+            self.process_search_state.is_ignoring_case,
+            self.process_search_state.is_searching_with_regex,
+        )
+    }
+}
+
+/// What a query is filtering, which determines what prefixes are supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryKind {
+    Process,
+    Socket,
+}
+
+impl QueryKind {
+    /// Parses a query for the given kind of data.  See [`ProcessQuery::parse_query`] for the
+    /// language used.
+    pub fn parse_query(
+        self, search_query: &str, is_searching_whole_word: bool, is_ignoring_case: bool,
+        is_searching_with_regex: bool,
+    ) -> Result<Query> {
+        fn process_string_to_filter(
+            query: &mut VecDeque<String>, query_kind: QueryKind,
+        ) -> Result<Query> {
+            let lhs = process_or(query, query_kind)?;
             let mut list_of_ors = vec![lhs];
 
             while query.front().is_some() {
-                list_of_ors.push(process_or(query)?);
+                list_of_ors.push(process_or(query, query_kind)?);
             }
 
             Ok(Query { query: list_of_ors })
         }
 
-        fn process_or(query: &mut VecDeque<String>) -> Result<Or> {
-            let mut lhs = process_and(query)?;
+        fn process_or(query: &mut VecDeque<String>, query_kind: QueryKind) -> Result<Or> {
+            let mut lhs = process_and(query, query_kind)?;
             let mut rhs: Option<Box<And>> = None;
 
             while let Some(queue_top) = query.front() {
                 // debug!("OR QT: {:?}", queue_top);
                 if OR_LIST.contains(&queue_top.to_lowercase().as_str()) {
                     query.pop_front();
-                    rhs = Some(Box::new(process_and(query)?));
+                    rhs = Some(Box::new(process_and(query, query_kind)?));
 
                     if let Some(queue_next) = query.front() {
                         if OR_LIST.contains(&queue_next.to_lowercase().as_str()) {
@@ -88,8 +117,8 @@ impl ProcessQuery for ProcWidgetState {
             Ok(Or { lhs, rhs })
         }
 
-        fn process_and(query: &mut VecDeque<String>) -> Result<And> {
-            let mut lhs = process_prefix(query, false)?;
+        fn process_and(query: &mut VecDeque<String>, query_kind: QueryKind) -> Result<And> {
+            let mut lhs = process_prefix(query, false, query_kind)?;
             let mut rhs: Option<Box<Prefix>> = None;
 
             while let Some(queue_top) = query.front() {
@@ -97,7 +126,7 @@ impl ProcessQuery for ProcWidgetState {
                 if AND_LIST.contains(&queue_top.to_lowercase().as_str()) {
                     query.pop_front();
 
-                    rhs = Some(Box::new(process_prefix(query, false)?));
+                    rhs = Some(Box::new(process_prefix(query, false, query_kind)?));
 
                     if let Some(next_queue_top) = query.front() {
                         if AND_LIST.contains(&next_queue_top.to_lowercase().as_str()) {
@@ -127,7 +156,9 @@ impl ProcessQuery for ProcWidgetState {
             Ok(And { lhs, rhs })
         }
 
-        fn process_prefix(query: &mut VecDeque<String>, inside_quotation: bool) -> Result<Prefix> {
+        fn process_prefix(
+            query: &mut VecDeque<String>, inside_quotation: bool, query_kind: QueryKind,
+        ) -> Result<Prefix> {
             if let Some(queue_top) = query.pop_front() {
                 if inside_quotation {
                     if queue_top == "\"" {
@@ -171,7 +202,7 @@ impl ProcessQuery for ProcWidgetState {
 
                     while let Some(in_paren_query_top) = query.front() {
                         if in_paren_query_top != ")" {
-                            list_of_ors.push_back(process_or(query)?);
+                            list_of_ors.push_back(process_or(query, query_kind)?);
                         } else {
                             break;
                         }
@@ -229,7 +260,7 @@ impl ProcessQuery for ProcWidgetState {
                     // Similar to parentheses, trap and check for missing closing quotes.  Note, however, that we
                     // will DIRECTLY call another process_prefix call...
 
-                    let prefix = process_prefix(query, true)?;
+                    let prefix = process_prefix(query, true, query_kind)?;
                     if let Some(close_paren) = query.pop_front() {
                         if close_paren == "\"" {
                             return Ok(prefix);
@@ -241,7 +272,7 @@ impl ProcessQuery for ProcWidgetState {
                     }
                 } else {
                     //  Get prefix type...
-                    let prefix_type = queue_top.parse::<PrefixType>()?;
+                    let prefix_type = PrefixType::from_query_str(&queue_top, query_kind);
                     let content = if let PrefixType::Name = prefix_type {
                         Some(queue_top)
                     } else {
@@ -257,7 +288,12 @@ impl ProcessQuery for ProcWidgetState {
                                     compare_prefix: None,
                                 })
                             }
-                            PrefixType::Pid | PrefixType::State | PrefixType::User => {
+                            PrefixType::Pid
+                            | PrefixType::State
+                            | PrefixType::User
+                            | PrefixType::Proto
+                            | PrefixType::Local
+                            | PrefixType::Remote => {
                                 // We have to check if someone put an "="...
                                 if content == "=" {
                                     // Check next string if possible
@@ -343,6 +379,10 @@ impl ProcessQuery for ProcWidgetState {
 
                                         match prefix_type {
                                             PrefixType::MemBytes
+                                            | PrefixType::Pss
+                                            | PrefixType::Uss
+                                            | PrefixType::Shared
+                                            | PrefixType::Swap
                                             | PrefixType::Rps
                                             | PrefixType::Wps
                                             | PrefixType::TRead
@@ -418,34 +458,44 @@ impl ProcessQuery for ProcWidgetState {
 
         let mut split_query = VecDeque::new();
 
-        self.get_current_search_query()
-            .split_whitespace()
-            .for_each(|s| {
-                // From https://stackoverflow.com/a/56923739 in order to get a split but include the parentheses
-                let mut last = 0;
-                for (index, matched) in s.match_indices(|x| DELIMITER_LIST.contains(&x)) {
-                    if last != index {
-                        split_query.push_back(s[last..index].to_owned());
-                    }
-                    split_query.push_back(matched.to_owned());
-                    last = index + matched.len();
+        search_query.split_whitespace().for_each(|s| {
+            // From https://stackoverflow.com/a/56923739 in order to get a split but include the parentheses
+            let mut last = 0;
+            for (index, matched) in s.match_indices(|x| DELIMITER_LIST.contains(&x)) {
+                if last != index {
+                    split_query.push_back(s[last..index].to_owned());
                 }
-                if last < s.len() {
-                    split_query.push_back(s[last..].to_owned());
-                }
-            });
+                split_query.push_back(matched.to_owned());
+                last = index + matched.len();
+            }
+            if last < s.len() {
+                split_query.push_back(s[last..].to_owned());
+            }
+        });
 
-        let mut process_filter = process_string_to_filter(&mut split_query)?;
+        let mut process_filter = process_string_to_filter(&mut split_query, self)?;
         process_filter.process_regexes(
-            self.process_search_state.is_searching_whole_word,
-            self.process_search_state.is_ignoring_case,
-            self.process_search_state.is_searching_with_regex,
+            is_searching_whole_word,
+            is_ignoring_case,
+            is_searching_with_regex,
         )?;
 
         Ok(process_filter)
     }
 }
 
+/// Something that a parsed [`Query`] can be checked against.
+pub trait QueryTarget {
+    /// Whether a string-based prefix (e.g. a name or state) matches the given regex.
+    fn matches_string(
+        &self, prefix_type: &PrefixType, regex: &regex::Regex, is_using_command: bool,
+    ) -> bool;
+
+    /// Whether a numerical prefix matches; `matches` compares the prefix's value against the
+    /// query's.
+    fn matches_value(&self, prefix_type: &PrefixType, matches: impl Fn(f64) -> bool) -> bool;
+}
+
 pub struct Query {
     /// Remember, AND > OR, but AND must come after OR when we parse.
     pub query: Vec<Or>,
@@ -467,10 +517,15 @@ impl Query {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check<T: QueryTarget>(&self, target: &T, is_using_command: bool) -> bool {
         self.query
             .iter()
-            .all(|ok| ok.check(process, is_using_command))
+            .all(|ok| ok.check(target, is_using_command))
+    }
+
+    /// Whether any part of the query uses a prefix that `is_prefix` accepts.
+    pub fn uses_prefix(&self, is_prefix: &impl Fn(&PrefixType) -> bool) -> bool {
+        self.query.iter().any(|or| or.uses_prefix(is_prefix))
     }
 }
 
@@ -507,13 +562,18 @@ impl Or {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check<T: QueryTarget>(&self, target: &T, is_using_command: bool) -> bool {
         if let Some(rhs) = &self.rhs {
-            self.lhs.check(process, is_using_command) || rhs.check(process, is_using_command)
+            self.lhs.check(target, is_using_command) || rhs.check(target, is_using_command)
         } else {
-            self.lhs.check(process, is_using_command)
+            self.lhs.check(target, is_using_command)
         }
     }
+
+    pub fn uses_prefix(&self, is_prefix: &impl Fn(&PrefixType) -> bool) -> bool {
+        self.lhs.uses_prefix(is_prefix)
+            || matches!(&self.rhs, Some(rhs) if rhs.uses_prefix(is_prefix))
+    }
 }
 
 impl Debug for Or {
@@ -552,13 +612,18 @@ impl And {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
+    pub fn check<T: QueryTarget>(&self, target: &T, is_using_command: bool) -> bool {
         if let Some(rhs) = &self.rhs {
-            self.lhs.check(process, is_using_command) && rhs.check(process, is_using_command)
+            self.lhs.check(target, is_using_command) && rhs.check(target, is_using_command)
         } else {
-            self.lhs.check(process, is_using_command)
+            self.lhs.check(target, is_using_command)
         }
     }
+
+    pub fn uses_prefix(&self, is_prefix: &impl Fn(&PrefixType) -> bool) -> bool {
+        self.lhs.uses_prefix(is_prefix)
+            || matches!(&self.rhs, Some(rhs) if rhs.uses_prefix(is_prefix))
+    }
 }
 
 impl Debug for And {
@@ -576,6 +641,12 @@ pub enum PrefixType {
     PCpu,
     MemBytes,
     PMem,
+    Pss,
+    Uss,
+    Shared,
+    Swap,
+    Fds,
+    PFds,
     Rps,
     Wps,
     TRead,
@@ -583,30 +654,56 @@ pub enum PrefixType {
     Name,
     State,
     User,
+    Proto,
+    Local,
+    Remote,
+    Port,
+    LPort,
+    RPort,
     __Nonexhaustive,
 }
 
-impl std::str::FromStr for PrefixType {
-    type Err = BottomError;
-
-    fn from_str(s: &str) -> Result<Self> {
+impl PrefixType {
+    /// Parses a prefix for the given kind of query.  Anything that isn't a prefix is treated as
+    /// a name.
+    pub fn from_query_str(s: &str, query_kind: QueryKind) -> Self {
         use PrefixType::*;
 
         let lower_case = s.to_lowercase();
+        if let QueryKind::Socket = query_kind {
+            return match lower_case.as_str() {
+                "proto" | "protocol" => Proto,
+                "local" | "laddr" => Local,
+                "remote" | "raddr" => Remote,
+                "port" => Port,
+                "lport" => LPort,
+                "rport" => RPort,
+                "state" => State,
+                "pid" => Pid,
+                _ => Name,
+            };
+        }
+
         // Didn't add mem_bytes, total_read, and total_write
         // for now as it causes help to be clogged.
         match lower_case.as_str() {
-            "cpu" | "cpu%" => Ok(PCpu),
-            "mem" | "mem%" => Ok(PMem),
-            "memb" => Ok(MemBytes),
-            "read" | "r/s" => Ok(Rps),
-            "write" | "w/s" => Ok(Wps),
-            "tread" | "t.read" => Ok(TRead),
-            "twrite" | "t.write" => Ok(TWrite),
-            "pid" => Ok(Pid),
-            "state" => Ok(State),
-            "user" => Ok(User),
-            _ => Ok(Name),
+            "cpu" | "cpu%" => PCpu,
+            "mem" | "mem%" => PMem,
+            "memb" => MemBytes,
+            "pss" => Pss,
+            "uss" => Uss,
+            "shared" => Shared,
+            "swap" => Swap,
+            "fds" => Fds,
+            "fd%" => PFds,
+            "read" | "r/s" => Rps,
+            "write" | "w/s" => Wps,
+            "tread" | "t.read" => TRead,
+            "twrite" | "t.write" => TWrite,
+            "pid" => Pid,
+            "state" => State,
+            "user" => User,
+            _ => Name,
         }
     }
 }
@@ -632,7 +729,13 @@ impl Prefix {
         } else if let Some((prefix_type, StringQuery::Value(regex_string))) = &mut self.regex_prefix
         {
             match prefix_type {
-                PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::User => {
+                PrefixType::Pid
+                | PrefixType::Name
+                | PrefixType::State
+                | PrefixType::User
+                | PrefixType::Proto
+                | PrefixType::Local
+                | PrefixType::Remote => {
                     let escaped_regex: String;
                     let final_regex_string = &format!(
                         "{}{}{}{}",
@@ -662,85 +765,38 @@ impl Prefix {
         Ok(())
     }
 
-    pub fn check(&self, process: &ConvertedProcessData, is_using_command: bool) -> bool {
-        fn matches_condition(condition: &QueryComparison, lhs: f64, rhs: f64) -> bool {
-            match condition {
-                QueryComparison::Equal => (lhs - rhs).abs() < std::f64::EPSILON,
-                QueryComparison::Less => lhs < rhs,
-                QueryComparison::Greater => lhs > rhs,
-                QueryComparison::LessOrEqual => lhs <= rhs,
-                QueryComparison::GreaterOrEqual => lhs >= rhs,
-            }
-        }
-
+    pub fn check<T: QueryTarget>(&self, target: &T, is_using_command: bool) -> bool {
         if let Some(and) = &self.or {
-            and.check(process, is_using_command)
+            and.check(target, is_using_command)
         } else if let Some((prefix_type, query_content)) = &self.regex_prefix {
             if let StringQuery::Regex(r) = query_content {
-                match prefix_type {
-                    PrefixType::Name => r.is_match(if is_using_command {
-                        process.command.as_str()
-                    } else {
-                        process.name.as_str()
-                    }),
-                    PrefixType::Pid => r.is_match(process.pid.to_string().as_str()),
-                    PrefixType::State => r.is_match(process.process_state.as_str()),
-                    PrefixType::User => {
-                        if let Some(user) = &process.user {
-                            r.is_match(user.as_str())
-                        } else {
-                            false
-                        }
-                    }
-                    _ => true,
-                }
+                target.matches_string(prefix_type, r, is_using_command)
             } else {
                 true
             }
         } else if let Some((prefix_type, numerical_query)) = &self.compare_prefix {
-            match prefix_type {
-                PrefixType::PCpu => matches_condition(
-                    &numerical_query.condition,
-                    process.cpu_percent_usage,
-                    numerical_query.value,
-                ),
-                PrefixType::PMem => matches_condition(
-                    &numerical_query.condition,
-                    process.mem_percent_usage,
-                    numerical_query.value,
-                ),
-                PrefixType::MemBytes => matches_condition(
-                    &numerical_query.condition,
-                    process.mem_usage_bytes as f64,
-                    numerical_query.value,
-                ),
-                PrefixType::Rps => matches_condition(
-                    &numerical_query.condition,
-                    process.rps_f64,
-                    numerical_query.value,
-                ),
-                PrefixType::Wps => matches_condition(
-                    &numerical_query.condition,
-                    process.wps_f64,
-                    numerical_query.value,
-                ),
-                PrefixType::TRead => matches_condition(
-                    &numerical_query.condition,
-                    process.tr_f64,
-                    numerical_query.value,
-                ),
-                PrefixType::TWrite => matches_condition(
-                    &numerical_query.condition,
-                    process.tw_f64,
-                    numerical_query.value,
-                ),
-                _ => true,
-            }
+            target.matches_value(prefix_type, |value| {
+                numerical_query
+                    .condition
+                    .matches(value, numerical_query.value)
+            })
         } else {
             // Somehow we have an empty condition... oh well.  Return true.
             true
         }
     }
+
+    pub fn uses_prefix(&self, is_prefix: &impl Fn(&PrefixType) -> bool) -> bool {
+        if let Some(or) = &self.or {
+            or.uses_prefix(is_prefix)
+        } else if let Some((prefix_type, _)) = &self.regex_prefix {
+            is_prefix(prefix_type)
+        } else if let Some((prefix_type, _)) = &self.compare_prefix {
+            is_prefix(prefix_type)
+        } else {
+            false
+        }
+    }
 }
 
 impl Debug for Prefix {
@@ -766,6 +822,19 @@ pub enum QueryComparison {
     GreaterOrEqual,
 }
 
+impl QueryComparison {
+    /// Whether `lhs` compares to `rhs` as required.
+    pub fn matches(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            QueryComparison::Equal => (lhs - rhs).abs() < std::f64::EPSILON,
+            QueryComparison::Less => lhs < rhs,
+            QueryComparison::Greater => lhs > rhs,
+            QueryComparison::LessOrEqual => lhs <= rhs,
+            QueryComparison::GreaterOrEqual => lhs >= rhs,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum StringQuery {
     Value(String),
@@ -777,3 +846,138 @@ pub struct NumericalQuery {
     pub condition: QueryComparison,
     pub value: f64,
 }
+
+impl QueryTarget for ConvertedProcessData {
+    fn matches_string(
+        &self, prefix_type: &PrefixType, regex: &regex::Regex, is_using_command: bool,
+    ) -> bool {
+        match prefix_type {
+            PrefixType::Name => regex.is_match(if is_using_command {
+                self.command.as_str()
+            } else {
+                self.name.as_str()
+            }),
+            PrefixType::Pid => regex.is_match(self.pid.to_string().as_str()),
+            PrefixType::State => regex.is_match(self.process_state.as_str()),
+            PrefixType::User => {
+                if let Some(user) = &self.user {
+                    regex.is_match(user.as_str())
+                } else {
+                    false
+                }
+            }
+            _ => true,
+        }
+    }
+
+    fn matches_value(&self, prefix_type: &PrefixType, matches: impl Fn(f64) -> bool) -> bool {
+        match prefix_type {
+            PrefixType::PCpu => matches(self.cpu_percent_usage),
+            PrefixType::PMem => matches(self.mem_percent_usage),
+            PrefixType::MemBytes => matches(self.mem_usage_bytes as f64),
+            PrefixType::Pss | PrefixType::Uss | PrefixType::Shared | PrefixType::Swap => {
+                let column = match prefix_type {
+                    PrefixType::Pss => ProcessSorting::Pss,
+                    PrefixType::Uss => ProcessSorting::Uss,
+                    PrefixType::Shared => ProcessSorting::SharedMem,
+                    _ => ProcessSorting::Swap,
+                };
+
+                // Processes without a memory breakdown never match.
+                if let Some(bytes) = self.get_mem_breakdown_bytes(&column) {
+                    matches(bytes as f64)
+                } else {
+                    false
+                }
+            }
+            PrefixType::Fds => {
+                if let Some(count) = self.get_fd_count(&ProcessSorting::Fds) {
+                    matches(count as f64)
+                } else {
+                    false
+                }
+            }
+            PrefixType::PFds => {
+                if let Some(limit_percent) = self.get_fd_limit_percent() {
+                    matches(limit_percent)
+                } else {
+                    false
+                }
+            }
+            PrefixType::Rps => matches(self.rps_f64),
+            PrefixType::Wps => matches(self.wps_f64),
+            PrefixType::TRead => matches(self.tr_f64),
+            PrefixType::TWrite => matches(self.tw_f64),
+            _ => true,
+        }
+    }
+}
+
+impl QueryTarget for SocketHarvest {
+    fn matches_string(
+        &self, prefix_type: &PrefixType, regex: &regex::Regex, _is_using_command: bool,
+    ) -> bool {
+        match prefix_type {
+            // Unprefixed searches match the owning process or either address.
+            PrefixType::Name => {
+                self.process_name
+                    .as_ref()
+                    .map(|name| regex.is_match(name.as_str()))
+                    .unwrap_or(false)
+                    || regex.is_match(self.local_address.to_string().as_str())
+                    || regex.is_match(self.remote_address.to_string().as_str())
+            }
+            PrefixType::Proto => regex.is_match(self.protocol.to_string().as_str()),
+            PrefixType::Local => regex.is_match(self.local_address.ip().to_string().as_str()),
+            PrefixType::Remote => regex.is_match(self.remote_address.ip().to_string().as_str()),
+            PrefixType::State => regex.is_match(self.state),
+            PrefixType::Pid => {
+                if let Some(pid) = self.pid {
+                    regex.is_match(pid.to_string().as_str())
+                } else {
+                    false
+                }
+            }
+            _ => true,
+        }
+    }
+
+    fn matches_value(&self, prefix_type: &PrefixType, matches: impl Fn(f64) -> bool) -> bool {
+        match prefix_type {
+            PrefixType::Port => {
+                matches(f64::from(self.local_address.port()))
+                    || matches(f64::from(self.remote_address.port()))
+            }
+            PrefixType::LPort => matches(f64::from(self.local_address.port())),
+            PrefixType::RPort => matches(f64::from(self.remote_address.port())),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uses_prefix() {
+        let is_mem_breakdown = |prefix_type: &PrefixType| {
+            matches!(
+                prefix_type,
+                PrefixType::Pss | PrefixType::Uss | PrefixType::Shared | PrefixType::Swap
+            )
+        };
+
+        for (query, uses_prefix) in [
+            ("btm", false),
+            ("cpu > 10 and mem > 5", false),
+            ("pss > 100 mib", true),
+            ("btm or (cpu > 10 and swap > 0)", true),
+        ] {
+            let query = QueryKind::Process
+                .parse_query(query, false, true, false)
+                .unwrap();
+            assert_eq!(query.uses_prefix(&is_mem_breakdown), uses_prefix);
+        }
+    }
+}
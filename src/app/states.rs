@@ -7,7 +7,11 @@ use tui::widgets::TableState;
 use crate::{
     app::{layout_manager::BottomWidgetType, query::*},
     constants,
-    data_harvester::processes::{self, ProcessSorting},
+    data_conversion::{get_group_key, ConvertedProcessData, ProcessGroupingType},
+    data_harvester::{
+        network::sockets::{ConnectionsHarvest, SocketHarvest},
+        processes::{self, ProcessSorting},
+    },
 };
 use ProcessSorting::*;
 
@@ -102,6 +106,82 @@ pub struct AppHelpDialogState {
     pub index_shortcuts: Vec<u16>,
 }
 
+/// State for the dialog listing recent collector errors.
+#[derive(Default)]
+pub struct AppErrorsDialogState {
+    pub is_showing_errors: bool,
+    pub scroll_state: ParagraphScrollState,
+}
+
+/// State for the dialog listing recently started and exited processes.
+#[derive(Default)]
+pub struct AppProcessEventsDialogState {
+    pub is_showing_events: bool,
+    pub scroll_state: ParagraphScrollState,
+}
+
+/// State for the dialog listing notifications from process watches.
+#[derive(Default)]
+pub struct AppWatchEventsDialogState {
+    pub is_showing_events: bool,
+    pub scroll_state: ParagraphScrollState,
+}
+
+/// State for the dialog showing the network connections of a process, or of a group of processes.
+#[derive(Default)]
+pub struct AppConnectionsDialogState {
+    pub is_showing_connections: bool,
+    /// Describes whose connections are being shown, used as the dialog title.
+    pub process_description: String,
+    pub pids: Vec<crate::Pid>,
+    pub connections: Vec<SocketHarvest>,
+    /// Whether the connections of these processes haven't been collected yet.
+    pub is_collecting: bool,
+    /// Set if the connections could not be read, such as due to permissions.
+    pub error: Option<String>,
+    pub scroll_state: ParagraphScrollState,
+}
+
+impl AppConnectionsDialogState {
+    /// Clears the connections shown, until those of the processes now being shown are collected.
+    pub fn start_collecting(&mut self) {
+        self.connections.clear();
+        if cfg!(target_os = "linux") {
+            self.is_collecting = true;
+            self.error = None;
+        } else {
+            self.is_collecting = false;
+            self.error = Some("Network connections are currently only supported on Linux.".into());
+        }
+    }
+
+    /// Shows the latest connections collected, if they are of the processes being shown.  These
+    /// are read on the collection thread, since it means reading each process' `<PID>/fd`.
+    pub fn update_connections(&mut self, harvest: &Option<ConnectionsHarvest>) {
+        if let Some(harvest) = harvest {
+            if harvest.pids == self.pids {
+                self.is_collecting = false;
+                match &harvest.connections {
+                    Ok(connections) => {
+                        self.connections = connections.clone();
+                        self.error = None;
+                    }
+                    Err(err) => {
+                        self.connections.clear();
+                        self.error = Some(err.clone());
+                    }
+                }
+            }
+        }
+
+        self.scroll_state.max_scroll_index = self.connections.len() as u16;
+        if self.scroll_state.current_scroll_index >= self.scroll_state.max_scroll_index {
+            self.scroll_state.current_scroll_index =
+                self.scroll_state.max_scroll_index.saturating_sub(1);
+        }
+    }
+}
+
 impl Default for AppHelpDialogState {
     fn default() -> Self {
         AppHelpDialogState {
@@ -232,10 +312,19 @@ impl Default for ProcColumn {
             CpuPercent,
             Mem,
             MemPercent,
+            Pss,
+            Uss,
+            SharedMem,
+            Swap,
             ReadPerSecond,
             WritePerSecond,
             TotalRead,
             TotalWrite,
+            Fds,
+            FdPercent,
+            SocketFds,
+            PipeFds,
+            FileFds,
             User,
             State,
         ];
@@ -331,6 +420,16 @@ impl Default for ProcColumn {
                         },
                     );
                 }
+                Pss | Uss | SharedMem | Swap | Fds | FdPercent | SocketFds | PipeFds | FileFds => {
+                    // Optional columns, only enabled via the config.
+                    column_mapping.insert(
+                        column,
+                        ColumnInfo {
+                            enabled: false,
+                            shortcut: None,
+                        },
+                    );
+                }
                 _ => {
                     column_mapping.insert(
                         column,
@@ -407,6 +506,15 @@ impl ProcColumn {
         }
     }
 
+    /// Returns the enabled columns, in the order they are displayed.
+    pub fn get_enabled_columns(&self) -> Vec<ProcessSorting> {
+        self.ordered_columns
+            .iter()
+            .filter(|column_type| self.is_enabled(column_type))
+            .cloned()
+            .collect()
+    }
+
     pub fn get_enabled_columns_len(&self) -> usize {
         self.ordered_columns
             .iter()
@@ -486,9 +594,38 @@ impl ProcColumn {
     }
 }
 
+/// The entry a process widget's selection is pinned to while following.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FollowedProcess {
+    /// A single process, identified by its PID.
+    Process(crate::Pid),
+    /// A grouped entry, identified by its name (or command).
+    Group(String),
+}
+
+impl FollowedProcess {
+    fn from_entry(entry: &ConvertedProcessData, is_grouped: bool) -> Self {
+        if is_grouped {
+            FollowedProcess::Group(entry.name.clone())
+        } else {
+            FollowedProcess::Process(entry.pid)
+        }
+    }
+
+    /// Whether the entry is (or, for groups, contains) the followed process.  This is
+    /// lenient so that toggling grouping keeps the selection on the same process.
+    fn is_match(&self, entry: &ConvertedProcessData) -> bool {
+        match self {
+            FollowedProcess::Process(pid) => entry.pid == *pid || entry.group_pids.contains(pid),
+            FollowedProcess::Group(name) => entry.name == *name || entry.command == *name,
+        }
+    }
+}
+
 pub struct ProcWidgetState {
     pub process_search_state: ProcessSearchState,
     pub is_grouped: bool,
+    pub grouping_type: ProcessGroupingType,
     pub scroll_state: AppScrollWidgetState,
     pub process_sorting_type: processes::ProcessSorting,
     pub is_process_sort_descending: bool,
@@ -497,15 +634,68 @@ pub struct ProcWidgetState {
     pub is_sort_open: bool,
     pub columns: ProcColumn,
     pub is_tree_mode: bool,
+    pub show_tree_totals: bool,
     pub table_width_state: CanvasTableWidthState,
     pub requires_redraw: bool,
+    pub is_following: bool,
+    pub followed_process: Option<FollowedProcess>,
+    /// The scroll position we last moved the selection to, used to tell if the user has since
+    /// moved the selection themselves.
+    pub followed_position: Option<usize>,
+    /// A message shown in the title for a while, like when the followed process exits or a watch
+    /// is toggled, and when it was set.
+    pub notice: Option<(String, Instant)>,
+}
+
+/// How a process widget starts out, from the config file and flags.
+#[derive(Debug, Clone)]
+pub struct ProcWidgetConfig {
+    pub is_case_sensitive: bool,
+    pub is_match_whole_word: bool,
+    pub is_use_regex: bool,
+    pub is_grouped: bool,
+    pub show_memory_as_values: bool,
+    pub is_tree_mode: bool,
+    pub is_using_command: bool,
+    pub is_following: bool,
+    pub grouping_type: ProcessGroupingType,
+    pub show_tree_totals: bool,
+    pub extra_columns: Vec<ProcessSorting>,
+}
+
+impl Default for ProcWidgetConfig {
+    fn default() -> Self {
+        ProcWidgetConfig {
+            is_case_sensitive: false,
+            is_match_whole_word: false,
+            is_use_regex: false,
+            is_grouped: false,
+            show_memory_as_values: false,
+            is_tree_mode: false,
+            is_using_command: false,
+            is_following: false,
+            grouping_type: ProcessGroupingType::Name,
+            show_tree_totals: false,
+            extra_columns: vec![],
+        }
+    }
 }
 
 impl ProcWidgetState {
-    pub fn init(
-        is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool, is_grouped: bool,
-        show_memory_as_values: bool, is_tree_mode: bool, is_using_command: bool,
-    ) -> Self {
+    pub fn init(config: &ProcWidgetConfig) -> Self {
+        let ProcWidgetConfig {
+            is_case_sensitive,
+            is_match_whole_word,
+            is_use_regex,
+            is_grouped,
+            show_memory_as_values,
+            is_tree_mode,
+            is_using_command,
+            is_following,
+            grouping_type,
+            show_tree_totals,
+            ref extra_columns,
+        } = *config;
         let mut process_search_state = ProcessSearchState::default();
 
         if is_case_sensitive {
@@ -542,10 +732,14 @@ impl ProcWidgetState {
             columns.toggle(&ProcessSorting::ProcessName);
             columns.toggle(&ProcessSorting::Command);
         }
+        for column in extra_columns {
+            columns.try_enable(column);
+        }
 
         ProcWidgetState {
             process_search_state,
             is_grouped,
+            grouping_type,
             scroll_state: AppScrollWidgetState::default(),
             process_sorting_type,
             is_process_sort_descending,
@@ -554,9 +748,104 @@ impl ProcWidgetState {
             is_sort_open: false,
             columns,
             is_tree_mode,
+            show_tree_totals,
             table_width_state: CanvasTableWidthState::default(),
             requires_redraw: false,
+            is_following,
+            followed_process: None,
+            followed_position: None,
+            notice: None,
+        }
+    }
+
+    pub fn toggle_following(&mut self) {
+        self.is_following = !self.is_following;
+        self.followed_process = None;
+        self.followed_position = None;
+        self.notice = None;
+    }
+
+    /// Moves the selection to wherever the followed entry ended up in the newly built table.
+    ///
+    /// If the user has moved the selection since the last update, whatever entry they moved to
+    /// (in `previous_data`, which is what they were looking at) becomes the new followed entry.
+    /// If the followed entry is gone, the selection stays where it is, follows whatever is now
+    /// there, and a notice is set for the title.
+    pub fn update_followed_position(
+        &mut self, previous_data: Option<&[ConvertedProcessData]>,
+        current_data: &[ConvertedProcessData],
+        all_processes: &HashMap<crate::Pid, ConvertedProcessData>,
+    ) {
+        let current_position = self.scroll_state.current_scroll_position;
+
+        if self.followed_process.is_none() || self.followed_position != Some(current_position) {
+            self.followed_process = previous_data
+                .and_then(|data| data.get(current_position))
+                .map(|entry| FollowedProcess::from_entry(entry, self.is_grouped));
+        }
+
+        if let Some(followed_process) = &self.followed_process {
+            if let Some(new_position) = current_data
+                .iter()
+                .position(|entry| followed_process.is_match(entry))
+            {
+                if new_position < current_position {
+                    self.scroll_state.scroll_direction = ScrollDirection::Up;
+                } else if new_position > current_position {
+                    self.scroll_state.scroll_direction = ScrollDirection::Down;
+                }
+                self.scroll_state.current_scroll_position = new_position;
+            } else {
+                let still_exists = match followed_process {
+                    FollowedProcess::Process(pid) => all_processes.contains_key(pid),
+                    FollowedProcess::Group(name) => all_processes.values().any(|process| {
+                        get_group_key(
+                            process,
+                            self.is_using_command,
+                            self.grouping_type,
+                            all_processes,
+                        )
+                        .unwrap_or_else(|| "N/A".to_string())
+                            == *name
+                    }),
+                };
+                let description = match followed_process {
+                    FollowedProcess::Process(pid) => format!("PID {}", pid),
+                    FollowedProcess::Group(name) => format!("\"{}\"", name),
+                };
+                let notice = if still_exists {
+                    format!("{} is no longer shown", description)
+                } else {
+                    format!("{} exited", description)
+                };
+                self.notice = Some((notice, Instant::now()));
+
+                if current_position >= current_data.len() {
+                    self.scroll_state.current_scroll_position =
+                        current_data.len().saturating_sub(1);
+                    self.scroll_state.scroll_bar = 0;
+                    self.scroll_state.scroll_direction = ScrollDirection::Down;
+                }
+            }
         }
+
+        self.followed_process = current_data
+            .get(self.scroll_state.current_scroll_position)
+            .map(|entry| FollowedProcess::from_entry(entry, self.is_grouped));
+        self.followed_position = Some(self.scroll_state.current_scroll_position);
+    }
+
+    /// Returns the notice if it was set recently enough to still be shown.
+    pub fn get_notice(&self) -> Option<&str> {
+        self.notice.as_ref().and_then(|(notice, time)| {
+            if Instant::now().duration_since(*time).as_millis()
+                < constants::NOTICE_TIMEOUT_MILLISECONDS.into()
+            {
+                Some(notice.as_str())
+            } else {
+                None
+            }
+        })
     }
 
     /// Updates sorting when using the column list.
@@ -663,8 +952,11 @@ impl ProcWidgetState {
                 self.process_search_state.search_state.error_message = Some(err.to_string());
             }
         }
-        self.scroll_state.scroll_bar = 0;
-        self.scroll_state.current_scroll_position = 0;
+        // Don't jump to the top if following, the followed entry will be found again instead.
+        if !self.is_following {
+            self.scroll_state.scroll_bar = 0;
+            self.scroll_state.current_scroll_position = 0;
+        }
     }
 
     pub fn clear_search(&mut self) {
@@ -881,6 +1173,70 @@ impl TempState {
     }
 }
 
+pub struct SensorWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+}
+
+impl SensorWidgetState {
+    pub fn init() -> Self {
+        SensorWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+        }
+    }
+}
+
+pub struct SensorState {
+    pub widget_states: HashMap<u64, SensorWidgetState>,
+}
+
+impl SensorState {
+    pub fn init(widget_states: HashMap<u64, SensorWidgetState>) -> Self {
+        SensorState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut SensorWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&SensorWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct NetInterfaceWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+}
+
+impl NetInterfaceWidgetState {
+    pub fn init() -> Self {
+        NetInterfaceWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+        }
+    }
+}
+
+pub struct NetInterfaceState {
+    pub widget_states: HashMap<u64, NetInterfaceWidgetState>,
+}
+
+impl NetInterfaceState {
+    pub fn init(widget_states: HashMap<u64, NetInterfaceWidgetState>) -> Self {
+        NetInterfaceState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut NetInterfaceWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&NetInterfaceWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
 pub struct DiskWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub table_width_state: CanvasTableWidthState,
@@ -912,6 +1268,211 @@ impl DiskState {
         self.widget_states.get(&widget_id)
     }
 }
+
+/// The columns a socket widget can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketSorting {
+    Protocol,
+    LocalAddress,
+    LocalPort,
+    RemoteAddress,
+    RemotePort,
+    State,
+    RecvQueue,
+    SendQueue,
+    Pid,
+    ProcessName,
+}
+
+impl SocketSorting {
+    /// All columns, in the order they are displayed.
+    pub const COLUMNS: [SocketSorting; 10] = [
+        SocketSorting::Protocol,
+        SocketSorting::LocalAddress,
+        SocketSorting::LocalPort,
+        SocketSorting::RemoteAddress,
+        SocketSorting::RemotePort,
+        SocketSorting::State,
+        SocketSorting::RecvQueue,
+        SocketSorting::SendQueue,
+        SocketSorting::Pid,
+        SocketSorting::ProcessName,
+    ];
+
+    /// Returns the next column to sort by, wrapping around.
+    pub fn next(&self) -> Self {
+        let index = Self::COLUMNS
+            .iter()
+            .position(|column| column == self)
+            .unwrap_or_default();
+        Self::COLUMNS[(index + 1) % Self::COLUMNS.len()]
+    }
+
+    pub fn get_header(&self) -> &'static str {
+        match self {
+            SocketSorting::Protocol => "Proto",
+            SocketSorting::LocalAddress => "Local Address",
+            SocketSorting::LocalPort => "L.Port",
+            SocketSorting::RemoteAddress => "Remote Address",
+            SocketSorting::RemotePort => "R.Port",
+            SocketSorting::State => "State",
+            SocketSorting::RecvQueue => "Recv-Q",
+            SocketSorting::SendQueue => "Send-Q",
+            SocketSorting::Pid => "PID",
+            SocketSorting::ProcessName => "Process",
+        }
+    }
+}
+
+pub struct SocketWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+    pub sorting_type: SocketSorting,
+    pub is_sort_descending: bool,
+    /// The filter query.  While `is_enabled` is set, typed characters are added to the query.
+    pub search_state: AppSearchState,
+    pub is_ignoring_case: bool,
+    pub is_searching_whole_word: bool,
+    pub is_searching_with_regex: bool,
+}
+
+impl SocketWidgetState {
+    pub fn init(is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool) -> Self {
+        SocketWidgetState {
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+            sorting_type: SocketSorting::State,
+            is_sort_descending: false,
+            search_state: AppSearchState::default(),
+            is_ignoring_case: !is_case_sensitive,
+            is_searching_whole_word: is_match_whole_word,
+            is_searching_with_regex: is_use_regex,
+        }
+    }
+
+    /// Re-parses the filter query after it has been edited.
+    pub fn update_query(&mut self) {
+        if self.search_state.current_search_query.is_empty() {
+            self.search_state.is_blank_search = true;
+            self.search_state.is_invalid_search = false;
+            self.search_state.error_message = None;
+        } else {
+            match QueryKind::Socket.parse_query(
+                &self.search_state.current_search_query,
+                self.is_searching_whole_word,
+                self.is_ignoring_case,
+                self.is_searching_with_regex,
+            ) {
+                Ok(parsed_query) => {
+                    self.search_state.query = Some(parsed_query);
+                    self.search_state.is_blank_search = false;
+                    self.search_state.is_invalid_search = false;
+                    self.search_state.error_message = None;
+                }
+                Err(err) => {
+                    self.search_state.is_blank_search = false;
+                    self.search_state.is_invalid_search = true;
+                    self.search_state.error_message = Some(err.to_string());
+                }
+            }
+        }
+        self.scroll_state.scroll_bar = 0;
+        self.scroll_state.current_scroll_position = 0;
+    }
+
+    /// Whether a socket passes the current filter.  Invalid or blank queries let everything
+    /// through.
+    pub fn matches_filter(&self, socket: &SocketHarvest) -> bool {
+        if self.search_state.is_invalid_or_blank_search() {
+            true
+        } else if let Some(query) = &self.search_state.query {
+            query.check(socket, false)
+        } else {
+            true
+        }
+    }
+}
+
+pub struct SocketState {
+    pub widget_states: HashMap<u64, SocketWidgetState>,
+    pub force_update: Option<u64>,
+}
+
+impl SocketState {
+    pub fn init(widget_states: HashMap<u64, SocketWidgetState>) -> Self {
+        SocketState {
+            widget_states,
+            force_update: None,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut SocketWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&SocketWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+/// The state of a graph widget that only needs its time range, like the pressure or disk I/O
+/// graphs.
+pub struct GraphWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl GraphWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        GraphWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
+
+pub struct GraphState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, GraphWidgetState>,
+}
+
+impl GraphState {
+    pub fn init(widget_states: HashMap<u64, GraphWidgetState>) -> Self {
+        GraphState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut GraphWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&GraphWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+
+    /// Sets the time range shown by a widget to `get_new_time` of the current one, kept between
+    /// [`constants::STALE_MIN_MILLISECONDS`] and [`constants::STALE_MAX_MILLISECONDS`].
+    pub fn zoom(
+        &mut self, widget_id: u64, get_new_time: impl FnOnce(u64) -> u64, autohide_time: bool,
+    ) {
+        if let Some(widget_state) = self.widget_states.get_mut(&widget_id) {
+            let new_time = get_new_time(widget_state.current_display_time).clamp(
+                constants::STALE_MIN_MILLISECONDS,
+                constants::STALE_MAX_MILLISECONDS,
+            );
+            if new_time != widget_state.current_display_time {
+                widget_state.current_display_time = new_time;
+                self.force_update = Some(widget_id);
+                if autohide_time {
+                    widget_state.autohide_timer = Some(Instant::now());
+                }
+            }
+        }
+    }
+}
+
 pub struct BasicTableWidgetState {
     // Since this is intended (currently) to only be used for ONE widget, that's
     // how it's going to be written.  If we want to allow for multiple of these,
@@ -1026,4 +1587,249 @@ mod test {
         // Update by 15 but with a larger bound. Should increment to 15.
         check_scroll_update(s, 15, 16, Some(15), 15);
     }
+
+    #[test]
+    fn test_follow_process() {
+        fn entry(pid: crate::Pid) -> ConvertedProcessData {
+            ConvertedProcessData {
+                pid,
+                name: format!("proc_{}", pid),
+                ..ConvertedProcessData::default()
+            }
+        }
+
+        let mut state = ProcWidgetState::init(&ProcWidgetConfig {
+            is_following: true,
+            ..ProcWidgetConfig::default()
+        });
+        let all: HashMap<crate::Pid, ConvertedProcessData> =
+            (1..=4).map(|pid| (pid, entry(pid))).collect();
+
+        // Select PID 2, then re-sort so it moves to the end.
+        let before = vec![entry(1), entry(2), entry(3), entry(4)];
+        state.scroll_state.current_scroll_position = 1;
+        state.update_followed_position(None, &before, &all);
+        let after = vec![entry(4), entry(3), entry(1), entry(2)];
+        state.update_followed_position(Some(&before), &after, &all);
+        assert_eq!(state.scroll_state.current_scroll_position, 3);
+        assert!(state.get_notice().is_none());
+
+        // Moving the selection follows the new entry instead.
+        state.scroll_state.current_scroll_position = 1;
+        state.update_followed_position(Some(&after), &before, &all);
+        assert_eq!(state.scroll_state.current_scroll_position, 2);
+        assert_eq!(state.followed_process, Some(FollowedProcess::Process(3)));
+
+        // PID 3 exits; the selection stays put and a notice is set.
+        let mut all = all;
+        all.remove(&3);
+        let exited = vec![entry(1), entry(2), entry(4)];
+        state.update_followed_position(Some(&before), &exited, &all);
+        assert_eq!(state.scroll_state.current_scroll_position, 2);
+        assert_eq!(state.followed_process, Some(FollowedProcess::Process(4)));
+        assert_eq!(state.get_notice(), Some("PID 3 exited"));
+    }
+
+    #[test]
+    fn test_follow_group() {
+        fn entry(pid: crate::Pid, cgroup: &str) -> ConvertedProcessData {
+            ConvertedProcessData {
+                pid,
+                name: format!("proc_{}", pid),
+                cgroup: Some(cgroup.to_string()),
+                ..ConvertedProcessData::default()
+            }
+        }
+        fn group(name: &str) -> ConvertedProcessData {
+            ConvertedProcessData {
+                name: name.to_string(),
+                ..ConvertedProcessData::default()
+            }
+        }
+
+        let mut state = ProcWidgetState::init(&ProcWidgetConfig {
+            is_grouped: true,
+            is_following: true,
+            grouping_type: ProcessGroupingType::Cgroup,
+            ..ProcWidgetConfig::default()
+        });
+        let all: HashMap<crate::Pid, ConvertedProcessData> =
+            vec![(1, entry(1, "/init.scope")), (2, entry(2, "/user.slice"))]
+                .into_iter()
+                .collect();
+
+        // Follow the "/user.slice" group, which is then filtered out while its processes remain.
+        let before = vec![group("/init.scope"), group("/user.slice")];
+        state.scroll_state.current_scroll_position = 1;
+        state.update_followed_position(None, &before, &all);
+        state.update_followed_position(Some(&before), &before[..1], &all);
+        assert_eq!(
+            state.get_notice(),
+            Some("\"/user.slice\" is no longer shown")
+        );
+
+        // Once its processes are gone, the group has exited.
+        state.scroll_state.current_scroll_position = 1;
+        state.update_followed_position(None, &before, &all);
+        let mut all = all;
+        all.remove(&2);
+        state.update_followed_position(Some(&before), &before[..1], &all);
+        assert_eq!(state.get_notice(), Some("\"/user.slice\" exited"));
+    }
+
+    #[test]
+    fn test_graph_zoom() {
+        let mut state = GraphState::init(
+            vec![(1, GraphWidgetState::init(60000, None))]
+                .into_iter()
+                .collect(),
+        );
+
+        state.zoom(1, |time| time - 15000, false);
+        assert_eq!(
+            state.get_widget_state(1).unwrap().current_display_time,
+            45000
+        );
+        assert_eq!(state.force_update, Some(1));
+
+        // Zooming is kept within the allowed range, and does nothing once at its end.
+        state.zoom(1, |time| time - 30000, false);
+        assert_eq!(
+            state.get_widget_state(1).unwrap().current_display_time,
+            constants::STALE_MIN_MILLISECONDS
+        );
+        state.force_update = None;
+        state.zoom(1, |time| time - 15000, true);
+        assert_eq!(state.force_update, None);
+        assert!(state.get_widget_state(1).unwrap().autohide_timer.is_none());
+
+        state.zoom(1, |_| u64::MAX, true);
+        assert_eq!(
+            state.get_widget_state(1).unwrap().current_display_time,
+            constants::STALE_MAX_MILLISECONDS
+        );
+        assert!(state.get_widget_state(1).unwrap().autohide_timer.is_some());
+    }
+
+    fn socket(
+        protocol: crate::data_harvester::network::sockets::SocketProtocol, local_address: &str,
+        remote_address: &str, state: &'static str, pid: Option<crate::Pid>,
+        process_name: Option<&str>,
+    ) -> SocketHarvest {
+        SocketHarvest {
+            protocol,
+            local_address: local_address.parse().unwrap(),
+            remote_address: remote_address.parse().unwrap(),
+            state,
+            tx_queue: 0,
+            rx_queue: 0,
+            inode: 0,
+            pid,
+            process_name: process_name.map(str::to_string),
+        }
+    }
+
+    fn sockets() -> Vec<SocketHarvest> {
+        use crate::data_harvester::network::sockets::SocketProtocol;
+
+        vec![
+            socket(
+                SocketProtocol::Tcp,
+                "0.0.0.0:80",
+                "0.0.0.0:0",
+                "LISTEN",
+                Some(100),
+                Some("nginx"),
+            ),
+            socket(
+                SocketProtocol::Tcp,
+                "127.0.0.1:48160",
+                "127.0.0.1:8080",
+                "ESTABLISHED",
+                Some(200),
+                Some("curl"),
+            ),
+            socket(
+                SocketProtocol::Udp,
+                "127.0.0.53:53",
+                "0.0.0.0:0",
+                "CLOSE",
+                None,
+                None,
+            ),
+            socket(
+                SocketProtocol::Tcp6,
+                "[::1]:8080",
+                "[::1]:51000",
+                "ESTABLISHED",
+                Some(300),
+                Some("server"),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_socket_filter() {
+        let mut state = SocketWidgetState::init(false, false, false);
+        let sockets = sockets();
+
+        for (query, expected_pids) in [
+            ("", vec![100, 200, 0, 300]),
+            ("nginx", vec![100]),
+            ("127.0.0", vec![200, 0]),
+            ("proto = udp", vec![0]),
+            ("state = estab", vec![200, 300]),
+            ("port = 8080", vec![200, 300]),
+            ("lport = 8080", vec![300]),
+            ("rport > 1024 and pid = 200", vec![200]),
+            ("remote = ::1", vec![300]),
+            ("local = 0.0.0.0 or state = close", vec![100, 0]),
+        ] {
+            state.search_state.current_search_query = query.to_string();
+            state.update_query();
+            assert!(!state.search_state.is_invalid_search, "{}", query);
+
+            let pids: Vec<crate::Pid> = sockets
+                .iter()
+                .filter(|socket| state.matches_filter(socket))
+                .map(|socket| socket.pid.unwrap_or_default())
+                .collect();
+            assert_eq!(pids, expected_pids, "{}", query);
+        }
+
+        // An invalid query doesn't hide anything.
+        state.search_state.current_search_query = "port >".to_string();
+        state.update_query();
+        assert!(state.search_state.is_invalid_search);
+        assert!(sockets.iter().all(|socket| state.matches_filter(socket)));
+    }
+
+    #[test]
+    fn test_socket_sorting() {
+        let mut state = SocketWidgetState::init(false, false, false);
+        let get_pids = |state: &SocketWidgetState| {
+            let mut sockets = sockets();
+            crate::sort_socket_data(&mut sockets, state);
+            sockets
+                .iter()
+                .map(|socket| socket.pid.unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+
+        // Sorting is stable, so ties keep their order.
+        assert_eq!(get_pids(&state), vec![0, 200, 300, 100]);
+
+        state.sorting_type = SocketSorting::LocalPort;
+        assert_eq!(get_pids(&state), vec![0, 100, 300, 200]);
+
+        state.sorting_type = SocketSorting::ProcessName;
+        state.is_sort_descending = true;
+        assert_eq!(get_pids(&state), vec![300, 100, 200, 0]);
+
+        state.sorting_type = SocketSorting::Protocol;
+        state.is_sort_descending = false;
+        assert_eq!(get_pids(&state), vec![100, 200, 300, 0]);
+
+        assert_eq!(SocketSorting::ProcessName.next(), SocketSorting::Protocol);
+    }
 }
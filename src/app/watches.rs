@@ -0,0 +1,296 @@
+//! Watches on process queries, which report when matching processes appear or exit, and when
+//! running processes start or stop matching, like by crossing a threshold in the query.
+
+use std::{collections::HashMap, path::PathBuf, time::Instant};
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use super::{
+    alerts::{append_to_log, run_command},
+    query::{Query, QueryKind},
+};
+use crate::{
+    data_conversion::ConvertedProcessData, options::WatchesConfig, utils::error::Result, Pid,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchEventKind {
+    /// A new process matches.
+    Appeared,
+    /// A process that was already running now matches.
+    Matched,
+    /// A process that is still running no longer matches.
+    Unmatched,
+    /// A matching process exited.
+    Exited,
+}
+
+impl std::fmt::Display for WatchEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatchEventKind::Appeared => write!(f, "Appeared"),
+            WatchEventKind::Matched => write!(f, "Matched"),
+            WatchEventKind::Unmatched => write!(f, "Unmatched"),
+            WatchEventKind::Exited => write!(f, "Exited"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WatchEvent {
+    pub kind: WatchEventKind,
+    /// The query of the watch that reported this.
+    pub query: String,
+    /// The process' data at the time, or its last known data if it exited.
+    pub process: ConvertedProcessData,
+    pub instant: Instant,
+}
+
+/// A watch on the processes matching a query.
+#[derive(Debug)]
+pub struct Watch {
+    /// The query as written.
+    pub query_text: String,
+    query: Query,
+    is_using_command: bool,
+    /// The processes matching the query as of the last check.  This is `None` until the first
+    /// check, so processes that already match when the watch is armed aren't reported.
+    matching: Option<FxHashMap<Pid, ConvertedProcessData>>,
+}
+
+impl Watch {
+    pub fn new(query_text: String, query: Query, is_using_command: bool) -> Self {
+        Watch {
+            query_text,
+            query,
+            is_using_command,
+            matching: None,
+        }
+    }
+
+    pub fn get_query(&self) -> &Query {
+        &self.query
+    }
+
+    /// Checks which processes match now, returning what changed since the last check.
+    fn update(
+        &mut self, process_data: &HashMap<Pid, ConvertedProcessData>, prev_pids: &FxHashSet<Pid>,
+        now: Instant,
+    ) -> Vec<WatchEvent> {
+        let matching: FxHashMap<Pid, ConvertedProcessData> = process_data
+            .iter()
+            .filter(|(_pid, process)| self.query.check(*process, self.is_using_command))
+            .map(|(pid, process)| (*pid, process.clone()))
+            .collect();
+
+        let mut events = vec![];
+        if let Some(prev_matching) = &self.matching {
+            let mut new_event = |kind: WatchEventKind, process: &ConvertedProcessData| {
+                events.push(WatchEvent {
+                    kind,
+                    query: self.query_text.clone(),
+                    process: process.clone(),
+                    instant: now,
+                });
+            };
+
+            for (pid, process) in &matching {
+                if !prev_matching.contains_key(pid) {
+                    if prev_pids.contains(pid) {
+                        new_event(WatchEventKind::Matched, process);
+                    } else {
+                        new_event(WatchEventKind::Appeared, process);
+                    }
+                }
+            }
+
+            for (pid, prev_process) in prev_matching {
+                if !matching.contains_key(pid) {
+                    if let Some(process) = process_data.get(pid) {
+                        new_event(WatchEventKind::Unmatched, process);
+                    } else {
+                        new_event(WatchEventKind::Exited, prev_process);
+                    }
+                }
+            }
+        }
+
+        self.matching = Some(matching);
+        events
+    }
+}
+
+/// The armed watches, and where they report to.
+#[derive(Debug, Default)]
+pub struct Watches {
+    pub watches: Vec<Watch>,
+    /// The most recent events from all watches, oldest first.
+    pub events: Vec<WatchEvent>,
+    log: Option<PathBuf>,
+    command: Option<String>,
+}
+
+impl Watches {
+    pub fn from_config(config: &Option<WatchesConfig>) -> Result<Self> {
+        let mut watches = Watches::default();
+
+        if let Some(config) = config {
+            if let Some(queries) = &config.queries {
+                for query_text in queries {
+                    let query = QueryKind::Process.parse_query(query_text, false, true, false)?;
+                    watches
+                        .watches
+                        .push(Watch::new(query_text.clone(), query, false));
+                }
+            }
+            watches.log = config.log.as_ref().map(PathBuf::from);
+            watches.command = config.command.clone();
+        }
+
+        Ok(watches)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.watches.is_empty()
+    }
+
+    /// Arms a watch, or disarms the existing watch on the same query.  Returns whether the query
+    /// is now being watched.
+    pub fn toggle(&mut self, watch: Watch) -> bool {
+        let existing_len = self.watches.len();
+        self.watches
+            .retain(|existing| existing.query_text != watch.query_text);
+
+        if self.watches.len() == existing_len {
+            self.watches.push(watch);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks every watch against the latest processes, reporting any changes.  `prev_pids` are
+    /// the processes from the previous check, to tell new processes apart from existing ones.
+    pub fn update(
+        &mut self, process_data: &HashMap<Pid, ConvertedProcessData>, prev_pids: &FxHashSet<Pid>,
+        now: Instant,
+    ) {
+        const MAX_WATCH_EVENTS: usize = 200;
+
+        let new_events: Vec<WatchEvent> = self
+            .watches
+            .iter_mut()
+            .flat_map(|watch| watch.update(process_data, prev_pids, now))
+            .collect();
+        for event in new_events {
+            self.report(&event);
+            self.events.push(event);
+        }
+
+        if self.events.len() > MAX_WATCH_EVENTS {
+            let excess = self.events.len() - MAX_WATCH_EVENTS;
+            self.events.drain(0..excess);
+        }
+    }
+
+    /// Appends an event to the log and runs the command, as configured.
+    fn report(&self, event: &WatchEvent) {
+        let (mem_value, mem_unit) = &event.process.mem_usage_str;
+        let cpu = format!("{:.1}", event.process.cpu_percent_usage);
+        let mem = format!("{:.1}{}", mem_value, mem_unit);
+
+        if let Some(log) = &self.log {
+            append_to_log(
+                log,
+                &format!(
+                    "[{}] {}: {} (PID {}, CPU {}%, memory {})",
+                    event.query, event.kind, event.process.name, event.process.pid, cpu, mem
+                ),
+            );
+        }
+
+        if let Some(command) = &self.command {
+            run_command(
+                command,
+                &[
+                    ("BTM_WATCH_QUERY", &event.query),
+                    ("BTM_WATCH_EVENT", &event.kind.to_string().to_lowercase()),
+                    ("BTM_WATCH_PID", &event.process.pid.to_string()),
+                    ("BTM_WATCH_NAME", &event.process.name),
+                    ("BTM_WATCH_CPU", &cpu),
+                    ("BTM_WATCH_MEM", &mem),
+                ],
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn process(pid: Pid, name: &str, cpu_percent_usage: f64) -> (Pid, ConvertedProcessData) {
+        (
+            pid,
+            ConvertedProcessData {
+                pid,
+                name: name.to_string(),
+                cpu_percent_usage,
+                ..ConvertedProcessData::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_watch_events() {
+        let query = QueryKind::Process
+            .parse_query("ffmpeg and cpu > 80", false, true, false)
+            .unwrap();
+        let mut watch = Watch::new("ffmpeg and cpu > 80".to_string(), query, false);
+        let now = Instant::now();
+
+        // Processes that match when armed aren't reported.
+        let process_data: HashMap<_, _> =
+            vec![process(1, "ffmpeg", 90.0), process(2, "ffmpeg", 10.0)]
+                .into_iter()
+                .collect();
+        assert!(watch
+            .update(&process_data, &FxHashSet::default(), now)
+            .is_empty());
+
+        let prev_pids = process_data.keys().copied().collect();
+        let process_data: HashMap<_, _> = vec![
+            process(2, "ffmpeg", 95.0),
+            process(3, "ffmpeg", 85.0),
+            process(4, "bash", 99.0),
+        ]
+        .into_iter()
+        .collect();
+        let mut events: Vec<(WatchEventKind, Pid)> = watch
+            .update(&process_data, &prev_pids, now)
+            .into_iter()
+            .map(|event| (event.kind, event.process.pid))
+            .collect();
+        events.sort_by_key(|(_kind, pid)| *pid);
+        assert_eq!(
+            events,
+            vec![
+                (WatchEventKind::Exited, 1),
+                (WatchEventKind::Matched, 2),
+                (WatchEventKind::Appeared, 3),
+            ]
+        );
+
+        let prev_pids = process_data.keys().copied().collect();
+        let process_data: HashMap<_, _> =
+            vec![process(2, "ffmpeg", 5.0), process(3, "ffmpeg", 85.0)]
+                .into_iter()
+                .collect();
+        let events: Vec<(WatchEventKind, Pid)> = watch
+            .update(&process_data, &prev_pids, now)
+            .into_iter()
+            .map(|event| (event.kind, event.process.pid))
+            .collect();
+        assert_eq!(events, vec![(WatchEventKind::Unmatched, 2)]);
+    }
+}
@@ -127,6 +127,10 @@ fn main() -> Result<()> {
         ist_clone.store(true, Ordering::SeqCst);
     })?;
     let mut first_run = true;
+    #[cfg(target_os = "linux")]
+    update_process_details_to_collect(&mut app, &collection_thread_ctrl_sender);
+    #[cfg(target_os = "linux")]
+    update_connection_pids(&mut app, &collection_thread_ctrl_sender);
 
     while !is_terminated.load(Ordering::SeqCst) {
         if let Ok(recv) = receiver.recv_timeout(Duration::from_millis(TICK_RATE_IN_MILLISECONDS)) {
@@ -135,14 +139,27 @@ fn main() -> Result<()> {
                     if handle_key_event_or_break(event, &mut app, &collection_thread_ctrl_sender) {
                         break;
                     }
+                    #[cfg(target_os = "linux")]
+                    update_process_details_to_collect(&mut app, &collection_thread_ctrl_sender);
+                    #[cfg(target_os = "linux")]
+                    update_connection_pids(&mut app, &collection_thread_ctrl_sender);
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::MouseInput(event) => {
                     handle_mouse_event(event, &mut app);
+                    #[cfg(target_os = "linux")]
+                    update_process_details_to_collect(&mut app, &collection_thread_ctrl_sender);
+                    #[cfg(target_os = "linux")]
+                    update_connection_pids(&mut app, &collection_thread_ctrl_sender);
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::Update(data) => {
                     app.data_collection.eat_data(data);
+                    app.alerts.update(
+                        &app.data_collection,
+                        #[cfg(target_family = "unix")]
+                        &mut app.user_table,
+                    );
 
                     // This thing is required as otherwise, some widgets can't draw correctly w/o
                     // some data (or they need to be re-drawn).
@@ -151,8 +168,20 @@ fn main() -> Result<()> {
                         app.is_force_redraw = true;
                     }
 
+                    // Connections dialog; a dialog opened while frozen still gets its connections
+                    if app.connections_dialog_state.is_showing_connections
+                        && (!app.is_frozen || app.connections_dialog_state.is_collecting)
+                    {
+                        app.connections_dialog_state
+                            .update_connections(&app.data_collection.connections_harvest);
+                    }
+
                     if !app.is_frozen {
                         // Convert all data into tui-compliant components
+                        app.canvas_data.stale_sources = app.data_collection.stale_sources.clone();
+                        app.canvas_data.failing_sources =
+                            app.data_collection.failing_sources.clone();
+                        app.canvas_data.alerting_sources = app.alerts.get_highlighted_sources();
 
                         // Network
                         if app.used_widgets.use_net {
@@ -180,11 +209,20 @@ fn main() -> Result<()> {
                         // Disk
                         if app.used_widgets.use_disk {
                             app.canvas_data.disk_data = convert_disk_row(&app.data_collection);
+                            app.canvas_data.disk_util_data =
+                                convert_disk_util_data(&app.data_collection, false);
+                            app.canvas_data.disk_io_data =
+                                convert_disk_io_data(&app.data_collection, false);
                         }
 
                         // Temperatures
                         if app.used_widgets.use_temp {
                             app.canvas_data.temp_sensor_data = convert_temp_row(&app);
+                            app.canvas_data.temp_graph_data = convert_temp_graph_data(
+                                &app.data_collection,
+                                false,
+                                &app.app_config_fields.temp_graph_filter,
+                            );
                         }
 
                         // Memory
@@ -209,6 +247,36 @@ fn main() -> Result<()> {
                                 false,
                             );
                             app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
+                            app.canvas_data.kernel_stat_rates =
+                                app.data_collection.kernel_stat_rates;
+                        }
+
+                        // Load Average
+                        if app.used_widgets.use_load_avg {
+                            app.canvas_data.load_avg_graph_data =
+                                convert_load_avg_data(&app.data_collection, false);
+                        }
+
+                        // CPU Frequency
+                        if app.used_widgets.use_cpu_freq {
+                            app.canvas_data.cpu_freq_data =
+                                convert_cpu_freq_data(&app.data_collection, false);
+                        }
+
+                        // Pressure
+                        if app.used_widgets.use_pressure {
+                            app.canvas_data.pressure_data =
+                                convert_pressure_data(&app.data_collection, false);
+                        }
+
+                        // Sensors
+                        if app.used_widgets.use_sensor {
+                            app.canvas_data.sensor_data = convert_sensor_row(&app);
+                        }
+
+                        // Network Interfaces
+                        if app.used_widgets.use_net_interface {
+                            app.canvas_data.net_interface_data = convert_net_interface_row(&app);
                         }
 
                         // Processes
@@ -216,12 +284,17 @@ fn main() -> Result<()> {
                             update_all_process_lists(&mut app);
                         }
 
+                        // Sockets
+                        if app.used_widgets.use_socket {
+                            update_all_socket_lists(&mut app);
+                        }
+
                         // Battery
                         #[cfg(feature = "battery")]
                         {
                             if app.used_widgets.use_battery {
                                 app.canvas_data.battery_data =
-                                    convert_battery_harvest(&app.data_collection);
+                                    convert_battery_harvest(&app.data_collection, false);
                             }
                         }
                     }
@@ -1,5 +1,8 @@
 use itertools::izip;
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use tui::{
     backend::Backend,
@@ -14,11 +17,19 @@ use canvas_colours::*;
 use crate::{
     app::{
         self,
+        data_harvester::{
+            network::sockets::SocketHarvest, processes::KernelStatRates, temperature::HeatLevel,
+            DataSource,
+        },
         layout_manager::{BottomColRow, BottomLayout, BottomWidgetType},
         App,
     },
     constants::*,
-    data_conversion::{ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData},
+    data_conversion::{
+        ConvertedBatteryData, ConvertedCpuData, ConvertedCpuFreqData, ConvertedDiskIoData,
+        ConvertedDiskUtilData, ConvertedLoadAvgData, ConvertedPressureData, ConvertedProcessData,
+        ConvertedTempData,
+    },
     options::Config,
     utils::error,
     utils::error::BottomError,
@@ -42,10 +53,17 @@ pub struct DisplayableData {
     pub network_data_rx: Vec<Point>,
     pub network_data_tx: Vec<Point>,
     pub disk_data: Vec<Vec<String>>,
-    pub temp_sensor_data: Vec<Vec<String>>,
+    pub disk_util_data: Vec<ConvertedDiskUtilData>,
+    pub disk_io_data: Vec<ConvertedDiskIoData>,
+    pub temp_sensor_data: Vec<(Vec<String>, HeatLevel)>, // Represents the row and how hot the sensor is
+    pub sensor_data: Vec<(Vec<String>, HeatLevel)>, // Represents the row and how hot the sensor is, if it is a temperature
+    pub net_interface_data: Vec<(Vec<String>, bool)>, // Represents the row and whether its error or drop counters just rose
+    pub temp_graph_data: Vec<ConvertedTempData>,
     pub single_process_data: HashMap<Pid, ConvertedProcessData>, // Contains single process data, key is PID
     pub finalized_process_data_map: HashMap<u64, Vec<ConvertedProcessData>>, // What's actually displayed, key is the widget ID.
     pub stringified_process_data_map: HashMap<u64, Vec<(Vec<(String, Option<String>)>, bool)>>, // Represents the row and whether it is disabled, key is the widget ID
+    pub socket_data: Vec<SocketHarvest>, // All sockets, only updated if not frozen
+    pub socket_data_map: HashMap<u64, Vec<SocketHarvest>>, // Filtered and sorted sockets, key is the widget ID
 
     pub mem_labels: Option<(String, String)>,
     pub swap_labels: Option<(String, String)>,
@@ -53,8 +71,18 @@ pub struct DisplayableData {
     pub mem_data: Vec<Point>, // TODO: Switch this and all data points over to a better data structure...
     pub swap_data: Vec<Point>,
     pub load_avg_data: [f32; 3],
+    pub kernel_stat_rates: Option<KernelStatRates>,
     pub cpu_data: Vec<ConvertedCpuData>,
+    pub pressure_data: Vec<ConvertedPressureData>,
+    pub cpu_freq_data: Vec<ConvertedCpuFreqData>,
+    pub load_avg_graph_data: ConvertedLoadAvgData,
     pub battery_data: Vec<ConvertedBatteryData>,
+    /// Sources whose last collection timed out, so their widgets can be marked as stale.
+    pub stale_sources: HashSet<DataSource>,
+    /// Sources whose last collection failed, so their widgets can be marked as failing.
+    pub failing_sources: HashSet<DataSource>,
+    /// Sources with a firing alert, so their widgets can be highlighted.
+    pub alerting_sources: HashSet<DataSource>,
 }
 
 #[derive(Debug)]
@@ -377,6 +405,47 @@ impl Painter {
                     .split(vertical_dialog_chunk[1]);
 
                 self.draw_help_dialog(f, app_state, middle_dialog_chunk[1]);
+            } else if app_state.connections_dialog_state.is_showing_connections
+                || app_state.errors_dialog_state.is_showing_errors
+                || app_state.process_events_dialog_state.is_showing_events
+                || app_state.watch_events_dialog_state.is_showing_events
+            {
+                let dialog_width = if terminal_width < 100 {
+                    terminal_width
+                } else {
+                    terminal_width * 80 / 100
+                };
+                let dialog_height = terminal_height * 80 / 100;
+
+                let vertical_bordering = terminal_height.saturating_sub(dialog_height) / 2;
+                let vertical_dialog_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(vertical_bordering),
+                        Constraint::Length(dialog_height),
+                        Constraint::Length(vertical_bordering),
+                    ])
+                    .split(terminal_size);
+
+                let horizontal_bordering = terminal_width.saturating_sub(dialog_width) / 2;
+                let middle_dialog_chunk = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Length(horizontal_bordering),
+                        Constraint::Length(dialog_width),
+                        Constraint::Length(horizontal_bordering),
+                    ])
+                    .split(vertical_dialog_chunk[1]);
+
+                if app_state.connections_dialog_state.is_showing_connections {
+                    self.draw_connections_dialog(f, app_state, middle_dialog_chunk[1]);
+                } else if app_state.errors_dialog_state.is_showing_errors {
+                    self.draw_errors_dialog(f, app_state, middle_dialog_chunk[1]);
+                } else if app_state.process_events_dialog_state.is_showing_events {
+                    self.draw_process_events_dialog(f, app_state, middle_dialog_chunk[1]);
+                } else {
+                    self.draw_watch_events_dialog(f, app_state, middle_dialog_chunk[1]);
+                }
             } else if app_state.delete_dialog_state.is_showing_dd {
                 // TODO: This needs the paragraph wrap feature from tui-rs to be pushed to complete... but for now it's pretty close!
                 // The main problem right now is that I cannot properly calculate the height offset since
@@ -491,6 +560,63 @@ impl Painter {
                         true,
                         app_state.current_widget.widget_id,
                     ),
+                    Socket => self.draw_socket_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Sensor => self.draw_sensor_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    NetInterface => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    Pressure => self.draw_pressure_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    DiskUtil => self.draw_disk_util_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    DiskIo => self.draw_disk_io_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraph => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    CpuFreq => self.draw_cpu_freq_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    LoadAvg => self.draw_load_avg_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     Net => self.draw_network_graph(
                         f,
                         app_state,
@@ -736,6 +862,45 @@ impl Painter {
                     Disk => {
                         self.draw_disk_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
                     }
+                    Socket => self.draw_socket_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    Sensor => self.draw_sensor_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    NetInterface => self.draw_net_interface_table(
+                        f,
+                        app_state,
+                        *widget_draw_loc,
+                        true,
+                        widget.widget_id,
+                    ),
+                    Pressure => {
+                        self.draw_pressure_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    DiskUtil => {
+                        self.draw_disk_util_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    DiskIo => {
+                        self.draw_disk_io_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    TempGraph => {
+                        self.draw_temp_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    CpuFreq => {
+                        self.draw_cpu_freq_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
+                    LoadAvg => {
+                        self.draw_load_avg_graph(f, app_state, *widget_draw_loc, widget.widget_id)
+                    }
                     Proc => self.draw_process_features(
                         f,
                         app_state,
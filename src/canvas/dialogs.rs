@@ -1,2 +1,6 @@
+pub mod connections_dialog;
 pub mod dd_dialog;
+pub mod errors_dialog;
 pub mod help_dialog;
+pub mod process_events_dialog;
+pub mod watch_events_dialog;
@@ -0,0 +1,117 @@
+use crate::{app::App, canvas::Painter};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Row, Table, Wrap},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const CONNECTIONS_HEADERS: [&str; 7] = [
+    "Proto",
+    "Local Address",
+    "Remote Address",
+    "State",
+    "Recv-Q",
+    "Send-Q",
+    "PID",
+];
+
+impl Painter {
+    pub fn draw_connections_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let connections_dialog_state = &app_state.connections_dialog_state;
+
+        let title_base = format!(
+            " Connections of {} ({}) ",
+            connections_dialog_state.process_description,
+            connections_dialog_state.connections.len()
+        );
+        let title = Spans::from(vec![
+            Span::styled(title_base.clone(), self.colours.widget_title_style),
+            Span::styled(
+                format!(
+                    "─{}─ Esc to close ",
+                    "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                        UnicodeSegmentation::graphemes(title_base.as_str(), true).count() + 18
+                    ))
+                ),
+                self.colours.border_style,
+            ),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style);
+
+        let message = if let Some(error) = &connections_dialog_state.error {
+            Some(format!("Could not read connections: {}", error))
+        } else if connections_dialog_state.is_collecting {
+            Some("Reading connections...".to_string())
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            f.render_widget(
+                Paragraph::new(Span::styled(message, self.colours.text_style))
+                    .block(block)
+                    .alignment(Alignment::Left)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+            return;
+        }
+
+        let current_scroll_index =
+            usize::from(connections_dialog_state.scroll_state.current_scroll_index);
+        let rows = connections_dialog_state
+            .connections
+            .iter()
+            .enumerate()
+            .skip(current_scroll_index)
+            .map(|(itx, connection)| {
+                Row::new(vec![
+                    connection.protocol.to_string(),
+                    connection.local_address.to_string(),
+                    connection.remote_address.to_string(),
+                    connection.state.to_string(),
+                    connection.rx_queue.to_string(),
+                    connection.tx_queue.to_string(),
+                    connection
+                        .pid
+                        .map(|pid| pid.to_string())
+                        .unwrap_or_default(),
+                ])
+                .style(if itx == current_scroll_index {
+                    self.colours.currently_selected_text_style
+                } else {
+                    self.colours.text_style
+                })
+            });
+
+        f.render_widget(
+            Table::new(rows)
+                .header(
+                    Row::new(CONNECTIONS_HEADERS.to_vec())
+                        .style(self.colours.table_header_style)
+                        .bottom_margin(1),
+                )
+                .block(block)
+                .style(self.colours.text_style)
+                .widths(&[
+                    Constraint::Length(6),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(30),
+                    Constraint::Length(12),
+                    Constraint::Length(7),
+                    Constraint::Length(7),
+                    Constraint::Length(8),
+                ]),
+            draw_loc,
+        );
+    }
+}
@@ -29,17 +29,24 @@ impl Painter {
             if let Some(first_pid) = to_kill_processes.1.first() {
                 return Some(Text::from(vec![
                     Spans::from(""),
-                    if app_state.is_grouped(app_state.current_widget.widget_id) {
+                    if let Some(grouping_type) = app_state
+                        .proc_state
+                        .widget_states
+                        .get(&app_state.current_widget.widget_id)
+                        .filter(|proc_widget_state| proc_widget_state.is_grouped)
+                        .map(|proc_widget_state| proc_widget_state.grouping_type.description())
+                    {
                         if to_kill_processes.1.len() != 1 {
                             Spans::from(format!(
-                                "Kill {} processes with the name \"{}\"?  Press ENTER to confirm.",
+                                "Kill {} processes with the {} \"{}\"?  Press ENTER to confirm.",
                                 to_kill_processes.1.len(),
+                                grouping_type,
                                 to_kill_processes.0
                             ))
                         } else {
                             Spans::from(format!(
-                                "Kill 1 process with the name \"{}\"?  Press ENTER to confirm.",
-                                to_kill_processes.0
+                                "Kill 1 process with the {} \"{}\"?  Press ENTER to confirm.",
+                                grouping_type, to_kill_processes.0
                             ))
                         }
                     } else {
@@ -0,0 +1,103 @@
+use crate::{
+    app::App,
+    canvas::{drawing_utils::format_elapsed, Painter},
+};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Row, Table, Wrap},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const ERRORS_HEADERS: [&str; 5] = ["Source", "Kind", "When", "Count", "Message"];
+
+impl Painter {
+    pub fn draw_errors_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let collector_errors = &app_state.data_collection.collector_errors;
+        let scroll_state = &mut app_state.errors_dialog_state.scroll_state;
+
+        // Errors keep coming in while the dialog is open.
+        scroll_state.max_scroll_index = collector_errors.len() as u16;
+        if scroll_state.current_scroll_index >= scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index = scroll_state.max_scroll_index.saturating_sub(1);
+        }
+
+        let title_base = format!(" Collector Errors ({}) ", collector_errors.len());
+        let title = Spans::from(vec![
+            Span::styled(title_base.clone(), self.colours.widget_title_style),
+            Span::styled(
+                format!(
+                    "─{}─ Esc to close ",
+                    "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                        UnicodeSegmentation::graphemes(title_base.as_str(), true).count() + 18
+                    ))
+                ),
+                self.colours.border_style,
+            ),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style);
+
+        if collector_errors.is_empty() {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    "No errors have occurred while collecting data.",
+                    self.colours.text_style,
+                ))
+                .block(block)
+                .alignment(Alignment::Left)
+                .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+            return;
+        }
+
+        let current_scroll_index = usize::from(scroll_state.current_scroll_index);
+        let rows = collector_errors
+            .iter()
+            .rev()
+            .enumerate()
+            .skip(current_scroll_index)
+            .map(|(itx, recent)| {
+                Row::new(vec![
+                    recent.error.source.to_string(),
+                    recent.error.kind.to_string(),
+                    format_elapsed(recent.error.instant.elapsed().as_secs()),
+                    recent.count.to_string(),
+                    recent.error.message.clone(),
+                ])
+                .style(if itx == current_scroll_index {
+                    self.colours.currently_selected_text_style
+                } else {
+                    self.colours.text_style
+                })
+            });
+
+        f.render_widget(
+            Table::new(rows)
+                .header(
+                    Row::new(ERRORS_HEADERS.to_vec())
+                        .style(self.colours.table_header_style)
+                        .bottom_margin(1),
+                )
+                .block(block)
+                .style(self.colours.text_style)
+                .widths(&[
+                    Constraint::Length(12),
+                    Constraint::Length(11),
+                    Constraint::Length(8),
+                    Constraint::Length(6),
+                    Constraint::Min(20),
+                ]),
+            draw_loc,
+        );
+    }
+}
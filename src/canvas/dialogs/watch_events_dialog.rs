@@ -0,0 +1,130 @@
+use crate::{
+    app::{watches::WatchEventKind, App},
+    canvas::{drawing_utils::format_elapsed, Painter},
+};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph, Row, Table, Wrap},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const WATCH_EVENTS_HEADERS: [&str; 7] = ["Event", "When", "PID", "Name", "CPU%", "Mem", "Query"];
+
+impl Painter {
+    pub fn draw_watch_events_dialog<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let watches = &app_state.alerts.watches;
+        let scroll_state = &mut app_state.watch_events_dialog_state.scroll_state;
+
+        // Events keep coming in while the dialog is open.
+        scroll_state.max_scroll_index = watches.events.len() as u16;
+        if scroll_state.current_scroll_index >= scroll_state.max_scroll_index {
+            scroll_state.current_scroll_index = scroll_state.max_scroll_index.saturating_sub(1);
+        }
+
+        let title_base = format!(
+            " Watch Notifications ({}) ─ {} watched ",
+            watches.events.len(),
+            watches.watches.len()
+        );
+        let title = Spans::from(vec![
+            Span::styled(title_base.clone(), self.colours.widget_title_style),
+            Span::styled(
+                format!(
+                    "─{}─ Esc to close ",
+                    "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                        UnicodeSegmentation::graphemes(title_base.as_str(), true).count() + 18
+                    ))
+                ),
+                self.colours.border_style,
+            ),
+        ]);
+
+        let block = Block::default()
+            .title(title)
+            .style(self.colours.border_style)
+            .borders(Borders::ALL)
+            .border_style(self.colours.border_style);
+
+        if watches.events.is_empty() {
+            let message = if watches.is_empty() {
+                "Nothing is being watched.  Search in a process widget and press w to watch the matching processes.".to_string()
+            } else {
+                format!(
+                    "Nothing has happened yet to the processes matching {}.",
+                    watches
+                        .watches
+                        .iter()
+                        .map(|watch| format!("\"{}\"", watch.query_text))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+
+            f.render_widget(
+                Paragraph::new(Span::styled(message, self.colours.text_style))
+                    .block(block)
+                    .alignment(Alignment::Left)
+                    .wrap(Wrap { trim: true }),
+                draw_loc,
+            );
+            return;
+        }
+
+        // Exited processes show their last known usage.
+        let current_scroll_index = usize::from(scroll_state.current_scroll_index);
+        let rows = watches
+            .events
+            .iter()
+            .rev()
+            .enumerate()
+            .skip(current_scroll_index)
+            .map(|(itx, event)| {
+                let (mem_value, mem_unit) = &event.process.mem_usage_str;
+                Row::new(vec![
+                    event.kind.to_string(),
+                    format_elapsed(event.instant.elapsed().as_secs()),
+                    event.process.pid.to_string(),
+                    event.process.name.clone(),
+                    format!("{:.1}%", event.process.cpu_percent_usage),
+                    format!("{:.1}{}", mem_value, mem_unit),
+                    event.query.clone(),
+                ])
+                .style(if itx == current_scroll_index {
+                    self.colours.currently_selected_text_style
+                } else if matches!(
+                    event.kind,
+                    WatchEventKind::Unmatched | WatchEventKind::Exited
+                ) {
+                    self.colours.disabled_text_style
+                } else {
+                    self.colours.text_style
+                })
+            });
+
+        f.render_widget(
+            Table::new(rows)
+                .header(
+                    Row::new(WATCH_EVENTS_HEADERS.to_vec())
+                        .style(self.colours.table_header_style)
+                        .bottom_margin(1),
+                )
+                .block(block)
+                .style(self.colours.text_style)
+                .widths(&[
+                    Constraint::Length(10),
+                    Constraint::Length(8),
+                    Constraint::Length(8),
+                    Constraint::Length(16),
+                    Constraint::Length(7),
+                    Constraint::Length(9),
+                    Constraint::Min(20),
+                ]),
+            draw_loc,
+        );
+    }
+}
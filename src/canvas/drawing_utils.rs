@@ -1,6 +1,9 @@
 use tui::layout::Rect;
 
-use crate::app;
+use crate::{
+    app::{self, data_harvester::DataSource},
+    canvas::DisplayableData,
+};
 use std::{
     cmp::{max, min},
     time::Instant,
@@ -219,6 +222,42 @@ pub fn should_hide_x_label(
     }
 }
 
+/// Formats how long ago something happened, like `5s ago`.
+pub fn format_elapsed(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s ago", secs)
+    } else if secs < 60 * 60 {
+        format!("{}m ago", secs / 60)
+    } else {
+        format!("{}h ago", secs / (60 * 60))
+    }
+}
+
+/// Returns whether `source` has a firing alert, or whether its last collection failed or timed out,
+/// if any.
+pub fn get_source_status(
+    source: DataSource, canvas_data: &DisplayableData,
+) -> Option<&'static str> {
+    if canvas_data.alerting_sources.contains(&source) {
+        Some("alert")
+    } else if canvas_data.failing_sources.contains(&source) {
+        Some("error")
+    } else if canvas_data.stale_sources.contains(&source) {
+        Some("stale")
+    } else {
+        None
+    }
+}
+
+/// Returns a widget's title, marked if its source has a firing alert, or if its last collection
+/// failed or timed out.
+pub fn get_title_name(name: &str, source: DataSource, canvas_data: &DisplayableData) -> String {
+    match get_source_status(source, canvas_data) {
+        Some(status) => format!("{} ({})", name, status),
+        None => name.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod test {
 
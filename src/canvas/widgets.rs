@@ -1,11 +1,20 @@
 pub mod basic_table_arrows;
 pub mod battery_display;
 pub mod cpu_basic;
+pub mod cpu_freq_graph;
 pub mod cpu_graph;
+pub mod disk_io_graph;
 pub mod disk_table;
+pub mod disk_util_graph;
+pub mod load_avg_graph;
 pub mod mem_basic;
 pub mod mem_graph;
+pub mod net_interface_table;
 pub mod network_basic;
 pub mod network_graph;
+pub mod pressure_graph;
 pub mod process_table;
+pub mod sensor_table;
+pub mod socket_table;
+pub mod temp_graph;
 pub mod temp_table;
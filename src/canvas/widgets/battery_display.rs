@@ -1,6 +1,12 @@
+use std::borrow::Cow;
+
 use crate::{
-    app::App,
-    canvas::{drawing_utils::calculate_basic_use_bars, Painter},
+    app::{data_harvester::DataSource, App},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{calculate_basic_use_bars, get_title_name},
+        Painter,
+    },
     constants::*,
 };
 
@@ -13,6 +19,15 @@ use tui::{
 };
 use unicode_segmentation::UnicodeSegmentation;
 
+/// The minimum height needed to draw the charge and power history below the battery details.
+const BATTERY_GRAPH_MIN_HEIGHT: u16 = 8;
+
+/// Returns the upper y-axis bound for the power graph; a little above the highest value, rounded
+/// up to a whole number of watts.
+fn get_power_y_max(max_watts: f64) -> f64 {
+    (max_watts * 1.1).ceil().max(1.0)
+}
+
 impl Painter {
     pub fn draw_battery_display<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, draw_border: bool,
@@ -34,25 +49,33 @@ impl Painter {
                 app_state.app_config_fields.table_gap
             };
 
+            let title_base = format!(
+                " {} ",
+                get_title_name("Battery", DataSource::Battery, &app_state.canvas_data)
+            );
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Battery ── Esc to go back ";
+                const ESCAPE_ENDING: &str = "── Esc to go back ";
                 Spans::from(vec![
-                    Span::styled(" Battery ".to_string(), self.colours.widget_title_style),
+                    Span::styled(title_base.clone(), self.colours.widget_title_style),
                     Span::styled(
                         format!(
                             "─{}─ Esc to go back ",
-                            "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
-                            ))
+                            "─".repeat(
+                                usize::from(draw_loc.width).saturating_sub(
+                                    UnicodeSegmentation::graphemes(
+                                        format!("{}{}", title_base, ESCAPE_ENDING).as_str(),
+                                        true
+                                    )
+                                    .count()
+                                        + 2
+                                )
+                            )
                         ),
                         border_style,
                     ),
                 ])
             } else {
-                Spans::from(Span::styled(
-                    " Battery ".to_string(),
-                    self.colours.widget_title_style,
-                ))
+                Spans::from(Span::styled(title_base, self.colours.widget_title_style))
             };
 
             let battery_block = if draw_border {
@@ -68,6 +91,29 @@ impl Painter {
                 Block::default().borders(Borders::NONE)
             };
 
+            let battery_details = app_state
+                .canvas_data
+                .battery_data
+                .get(battery_widget_state.currently_selected_battery_index);
+
+            // Top and bottom borders, the tab row and its gap, the rows themselves, and the header gap.
+            let num_rows = battery_details
+                .map(|details| if details.model.is_some() { 10 } else { 9 })
+                .unwrap_or(1);
+            let table_height = 4 + num_rows + table_gap;
+            let (table_draw_loc, graph_draw_loc) = if draw_border
+                && battery_details.is_some()
+                && draw_loc.height >= table_height + BATTERY_GRAPH_MIN_HEIGHT
+            {
+                let split = Layout::default()
+                    .constraints([Constraint::Length(table_height), Constraint::Min(0)])
+                    .direction(Direction::Vertical)
+                    .split(draw_loc);
+                (split[0], Some(split[1]))
+            } else {
+                (draw_loc, None)
+            };
+
             let battery_names = app_state
                 .canvas_data
                 .battery_data
@@ -82,7 +128,7 @@ impl Painter {
                     Constraint::Min(0),
                 ])
                 .direction(Direction::Vertical)
-                .split(draw_loc)[1];
+                .split(table_draw_loc)[1];
 
             f.render_widget(
                 Tabs::new(
@@ -103,13 +149,9 @@ impl Painter {
                 .constraints([Constraint::Percentage(100)])
                 .horizontal_margin(if is_on_widget || draw_border { 0 } else { 1 })
                 .direction(Direction::Horizontal)
-                .split(draw_loc)[0];
+                .split(table_draw_loc)[0];
 
-            if let Some(battery_details) = app_state
-                .canvas_data
-                .battery_data
-                .get(battery_widget_state.currently_selected_battery_index)
-            {
+            if let Some(battery_details) = battery_details {
                 // Assuming a 50/50 split in width
                 let bar_length =
                     usize::from((draw_loc.width.saturating_sub(2) / 2).saturating_sub(8));
@@ -122,17 +164,20 @@ impl Painter {
                     charge_percentage,
                 );
 
-                let battery_rows = vec![
+                let charge_style = if charge_percentage < 10.0 {
+                    self.colours.low_battery_colour
+                } else if charge_percentage < 50.0 {
+                    self.colours.medium_battery_colour
+                } else {
+                    self.colours.high_battery_colour
+                };
+
+                let mut battery_rows = vec![
                     Row::new(vec![
                         Cell::from("Charge %").style(self.colours.text_style),
-                        Cell::from(bars).style(if charge_percentage < 10.0 {
-                            self.colours.low_battery_colour
-                        } else if charge_percentage < 50.0 {
-                            self.colours.medium_battery_colour
-                        } else {
-                            self.colours.high_battery_colour
-                        }),
+                        Cell::from(bars).style(charge_style),
                     ]),
+                    Row::new(vec!["State", &battery_details.state]).style(self.colours.text_style),
                     Row::new(vec!["Consumption", &battery_details.watt_consumption])
                         .style(self.colours.text_style),
                     if let Some(duration_until_full) = &battery_details.duration_until_full {
@@ -147,7 +192,18 @@ impl Painter {
                     },
                     Row::new(vec!["Health %", &battery_details.health])
                         .style(self.colours.text_style),
+                    Row::new(vec!["Energy", &battery_details.energy])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Voltage", &battery_details.voltage])
+                        .style(self.colours.text_style),
+                    Row::new(vec!["Cycle count", &battery_details.cycle_count])
+                        .style(self.colours.text_style),
                 ];
+                if let Some(model) = &battery_details.model {
+                    battery_rows.push(
+                        Row::new(vec!["Model", model.as_str()]).style(self.colours.text_style),
+                    );
+                }
 
                 // Draw
                 f.render_widget(
@@ -157,11 +213,99 @@ impl Painter {
                         .widths(&[Constraint::Percentage(50), Constraint::Percentage(50)]),
                     margined_draw_loc,
                 );
+
+                if let Some(graph_draw_loc) = graph_draw_loc {
+                    let display_time = app_state.app_config_fields.default_time_value;
+                    let time_start = -(display_time as f64);
+                    let x_bounds = [0, display_time];
+                    let hide_x_labels = app_state.app_config_fields.hide_time;
+                    let graph_locs = Layout::default()
+                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .direction(Direction::Horizontal)
+                        .split(graph_draw_loc);
+
+                    let charge_y_labels = [
+                        Cow::Borrowed("0%"),
+                        Cow::Borrowed("50%"),
+                        Cow::Borrowed("100%"),
+                    ];
+                    TimeGraph {
+                        use_dot: app_state.app_config_fields.use_dot,
+                        x_bounds,
+                        hide_x_labels,
+                        y_bounds: [0.0, 100.5],
+                        y_labels: &charge_y_labels,
+                        graph_style: self.colours.graph_style,
+                        border_style,
+                        title: " Charge ".into(),
+                        is_expanded: false,
+                        title_style: self.colours.widget_title_style,
+                        legend_constraints: None,
+                    }
+                    .draw_time_graph(
+                        f,
+                        graph_locs[0],
+                        &[GraphData {
+                            points: &battery_details.charge_points,
+                            style: charge_style,
+                            name: None,
+                        }],
+                    );
+
+                    let max_watts = battery_details
+                        .power_points
+                        .iter()
+                        .filter(|(time, _)| *time >= time_start)
+                        .map(|(_, watts)| *watts)
+                        .fold(0.0, f64::max);
+                    let power_y_max = get_power_y_max(max_watts);
+                    let power_y_labels = [
+                        Cow::Borrowed("0W"),
+                        Cow::Owned(format!("{:.1}W", power_y_max / 2.0)),
+                        Cow::Owned(format!("{:.0}W", power_y_max)),
+                    ];
+                    TimeGraph {
+                        use_dot: app_state.app_config_fields.use_dot,
+                        x_bounds,
+                        hide_x_labels,
+                        y_bounds: [0.0, power_y_max],
+                        y_labels: &power_y_labels,
+                        graph_style: self.colours.graph_style,
+                        border_style,
+                        title: " Power ".into(),
+                        is_expanded: false,
+                        title_style: self.colours.widget_title_style,
+                        legend_constraints: None,
+                    }
+                    .draw_time_graph(
+                        f,
+                        graph_locs[1],
+                        &[GraphData {
+                            points: &battery_details.power_points,
+                            style: self.colours.cpu_colour_styles[0],
+                            name: None,
+                        }],
+                    );
+                }
             } else {
                 let mut contents = vec![Spans::default(); table_gap.into()];
 
                 contents.push(Spans::from(Span::styled(
-                    "No data found for this battery",
+                    if app_state
+                        .canvas_data
+                        .failing_sources
+                        .contains(&DataSource::Battery)
+                    {
+                        "Could not read batteries, press E for details"
+                    } else if app_state
+                        .canvas_data
+                        .stale_sources
+                        .contains(&DataSource::Battery)
+                    {
+                        "Reading batteries timed out, press E for details"
+                    } else {
+                        "No data found for this battery"
+                    },
                     self.colours.text_style,
                 )));
 
@@ -195,10 +339,22 @@ impl Painter {
                     widget.top_left_corner = Some((margined_draw_loc.x, margined_draw_loc.y));
                     widget.bottom_right_corner = Some((
                         margined_draw_loc.x + margined_draw_loc.width,
-                        margined_draw_loc.y + margined_draw_loc.height,
+                        draw_loc.y + draw_loc.height,
                     ));
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_y_max() {
+        assert_eq!(get_power_y_max(0.0), 1.0);
+        assert_eq!(get_power_y_max(10.0), 11.0);
+        assert_eq!(get_power_y_max(14.2), 16.0);
+    }
+}
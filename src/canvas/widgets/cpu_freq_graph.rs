@@ -0,0 +1,115 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{data_harvester::DataSource, App},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        Painter,
+    },
+    data_conversion::format_frequency,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+impl Painter {
+    pub fn draw_cpu_freq_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(cpu_freq_widget_state) =
+            app_state.cpu_freq_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(cpu_freq_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, cpu_freq_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut cpu_freq_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let cpu_freq_data = &app_state.canvas_data.cpu_freq_data;
+
+            // Scale to the fastest core's max frequency so throttling is visible at a glance,
+            // rounded up to the next 500 MHz.
+            let max_value = cpu_freq_data
+                .iter()
+                .flat_map(|cpu| {
+                    cpu.points
+                        .iter()
+                        .filter(|(time, _)| *time >= time_start)
+                        .map(|(_, value)| *value)
+                        .chain(cpu.max)
+                })
+                .fold(0.0, f64::max);
+            let y_max = if max_value > 0.0 {
+                (max_value / 500.0).ceil() * 500.0
+            } else {
+                1000.0
+            };
+            let y_bounds = [0.0, y_max];
+            let y_labels = [
+                Cow::Borrowed("0"),
+                Cow::Owned(format_frequency(y_max / 2.0)),
+                Cow::Owned(format_frequency(y_max)),
+            ];
+
+            let name_width = cpu_freq_data
+                .iter()
+                .map(|cpu| cpu.name.len())
+                .max()
+                .unwrap_or(0);
+            let points = cpu_freq_data
+                .iter()
+                .enumerate()
+                .map(|(itx, cpu)| GraphData {
+                    points: &cpu.points,
+                    style: self.colours.cpu_colour_styles
+                        [itx % self.colours.cpu_colour_styles.len()],
+                    name: Some(
+                        format!(
+                            "{:<width$} {}",
+                            cpu.name,
+                            cpu.legend_value,
+                            width = name_width
+                        )
+                        .into(),
+                    ),
+                })
+                .collect::<Vec<_>>();
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name("CPU Frequency", DataSource::Cpu, &app_state.canvas_data)
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
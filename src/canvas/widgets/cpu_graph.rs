@@ -1,14 +1,16 @@
 use std::borrow::Cow;
 
 use crate::{
-    app::{layout_manager::WidgetDirection, App},
+    app::{data_harvester::DataSource, layout_manager::WidgetDirection, App},
     canvas::{
         components::{GraphData, TimeGraph},
-        drawing_utils::{get_column_widths, get_start_position, should_hide_x_label},
+        drawing_utils::{
+            get_column_widths, get_start_position, get_title_name, should_hide_x_label,
+        },
         Painter,
     },
     constants::*,
-    data_conversion::ConvertedCpuData,
+    data_conversion::{format_kernel_stat_rates, ConvertedCpuData},
 };
 
 use concat_string::concat_string;
@@ -22,6 +24,9 @@ use tui::{
 };
 
 const CPU_LEGEND_HEADER: [&str; 2] = ["CPU", "Use%"];
+const CPU_FREQ_LEGEND_HEADER: [&str; 3] = ["CPU", "Use%", "Freq"];
+/// Wide enough for frequencies like "3.40GHz".
+const FREQ_COLUMN_WIDTH: u16 = 7;
 const AVG_POSITION: usize = 1;
 const ALL_POSITION: usize = 0;
 
@@ -179,16 +184,32 @@ impl Painter {
             };
 
             // TODO: Maybe hide load avg if too long? Or maybe the CPU part.
+            let title_name = get_title_name("CPU", DataSource::Cpu, &app_state.canvas_data);
             let title = if cfg!(target_family = "unix") {
                 let load_avg = app_state.canvas_data.load_avg_data;
                 let load_avg_str = format!(
                     "─ {:.2} {:.2} {:.2} ",
                     load_avg[0], load_avg[1], load_avg[2]
                 );
+                let title = concat_string!(" ", title_name, " ", load_avg_str);
 
-                concat_string!(" CPU ", load_avg_str).into()
+                // Kernel activity is only shown if it fits, as it's the least important part.
+                match &app_state.canvas_data.kernel_stat_rates {
+                    Some(kernel_stat_rates) => {
+                        let kernel_stat_str =
+                            format!("─ {} ", format_kernel_stat_rates(kernel_stat_rates));
+                        if title.chars().count() + kernel_stat_str.chars().count() + 2
+                            <= usize::from(draw_loc.width)
+                        {
+                            concat_string!(title, kernel_stat_str).into()
+                        } else {
+                            title.into()
+                        }
+                    }
+                    None => title.into(),
+                }
             } else {
-                " CPU ".into()
+                format!(" {} ", title_name).into()
             };
 
             TimeGraph {
@@ -247,17 +268,45 @@ impl Painter {
                 .saturating_sub(start_position);
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
 
+            // Only show frequencies if we have them and there's room for them after the other
+            // two columns, including the borders and column spacing.
+            let show_freq = cpu_data.iter().any(|cpu| cpu.freq_value.is_some())
+                && draw_loc.width >= 2 + 6 + 1 + 4 + 1 + FREQ_COLUMN_WIDTH;
+
             // Calculate widths
-            if recalculate_column_widths {
+            let num_columns = if show_freq { 3 } else { 2 };
+            if recalculate_column_widths
+                || cpu_widget_state
+                    .table_width_state
+                    .desired_column_widths
+                    .len()
+                    != num_columns
+            {
+                let (hard_widths, soft_widths_max): (&[Option<u16>], &[Option<f64>]) = if show_freq
+                {
+                    (
+                        &[None, None, Some(FREQ_COLUMN_WIDTH)],
+                        &[Some(0.5), Some(0.5), None],
+                    )
+                } else {
+                    (&[None, None], &[Some(0.5), Some(0.5)])
+                };
                 cpu_widget_state.table_width_state.desired_column_widths = vec![6, 4];
+                if show_freq {
+                    cpu_widget_state
+                        .table_width_state
+                        .desired_column_widths
+                        .push(FREQ_COLUMN_WIDTH);
+                }
                 cpu_widget_state.table_width_state.calculated_column_widths = get_column_widths(
                     draw_loc.width,
-                    &[None, None],
+                    hard_widths,
                     &(CPU_LEGEND_HEADER_LENS
                         .iter()
                         .map(|width| Some(*width as u16))
+                        .chain(hard_widths.iter().skip(2).map(|_| None))
                         .collect::<Vec<_>>()),
-                    &[Some(0.5), Some(0.5)],
+                    soft_widths_max,
                     &(cpu_widget_state
                         .table_width_state
                         .desired_column_widths
@@ -304,7 +353,11 @@ impl Painter {
                     truncated_name.patch_style(self.colours.currently_selected_text_style);
                     Row::new(vec![truncated_name, truncated_legend])
                 } else {
-                    let cpu_string_row = vec![truncated_name, truncated_legend];
+                    let mut cpu_string_row = vec![truncated_name, truncated_legend];
+                    if show_freq {
+                        cpu_string_row
+                            .push(Text::raw(cpu.freq_value.as_deref().unwrap_or_default()));
+                    }
 
                     Row::new(cpu_string_row).style(if itx == offset_scroll_index {
                         self.colours.currently_selected_text_style
@@ -342,9 +395,13 @@ impl Painter {
                             .border_style(border_and_title_style),
                     )
                     .header(
-                        Row::new(CPU_LEGEND_HEADER.to_vec())
-                            .style(self.colours.table_header_style)
-                            .bottom_margin(table_gap),
+                        Row::new(if show_freq {
+                            CPU_FREQ_LEGEND_HEADER.to_vec()
+                        } else {
+                            CPU_LEGEND_HEADER.to_vec()
+                        })
+                        .style(self.colours.table_header_style)
+                        .bottom_margin(table_gap),
                     )
                     .widths(
                         &(cpu_widget_state
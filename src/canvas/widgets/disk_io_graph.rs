@@ -0,0 +1,129 @@
+use crate::{
+    app::{data_harvester::DataSource, App, AxisScaling},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        widgets::network_graph::adjust_network_data_point,
+        Painter,
+    },
+    units::data_units::DataUnit,
+    utils::gen_util::KILO_LIMIT_F64,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+impl Painter {
+    pub fn draw_disk_io_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(disk_io_widget_state) =
+            app_state.disk_io_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(disk_io_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, disk_io_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut disk_io_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let disk_io_data = &app_state.canvas_data.disk_io_data;
+
+            // Scale to the largest rate on screen, like the network graph does.
+            let max_entry = disk_io_data
+                .iter()
+                .flat_map(|disk| disk.read_points.iter().chain(&disk.write_points))
+                .filter(|(time, _)| *time >= time_start)
+                .map(|(_, value)| *value)
+                .fold(0.0, f64::max);
+            let (max_range, labels) = adjust_network_data_point(
+                if max_entry > 0.0 {
+                    max_entry
+                } else {
+                    KILO_LIMIT_F64
+                },
+                &AxisScaling::Linear,
+                &DataUnit::Byte,
+                false,
+            );
+            let y_labels = labels.iter().map(|label| label.into()).collect::<Vec<_>>();
+            let y_bounds = [0.0, max_range];
+
+            // Each disk gets two colours, one for reads and one for writes.
+            let colours = &self.colours.cpu_colour_styles;
+            let name_width = disk_io_data
+                .iter()
+                .map(|disk| disk.name.len())
+                .max()
+                .unwrap_or(0);
+            let points = disk_io_data
+                .iter()
+                .enumerate()
+                .flat_map(|(itx, disk)| {
+                    vec![
+                        GraphData {
+                            points: &disk.read_points,
+                            style: colours[(itx * 2) % colours.len()],
+                            name: Some(
+                                format!(
+                                    "{:<width$} R: {:>8}",
+                                    disk.name,
+                                    disk.read_legend_value,
+                                    width = name_width
+                                )
+                                .into(),
+                            ),
+                        },
+                        GraphData {
+                            points: &disk.write_points,
+                            style: colours[(itx * 2 + 1) % colours.len()],
+                            name: Some(
+                                format!(
+                                    "{:<width$} W: {:>8}",
+                                    disk.name,
+                                    disk.write_legend_value,
+                                    width = name_width
+                                )
+                                .into(),
+                            ),
+                        },
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name("Disk I/O", DataSource::Disk, &app_state.canvas_data)
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
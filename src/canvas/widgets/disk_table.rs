@@ -9,16 +9,18 @@ use tui::{
 };
 
 use crate::{
-    app,
+    app::{self, data_harvester::DataSource},
     canvas::{
-        drawing_utils::{get_column_widths, get_start_position},
+        drawing_utils::{get_column_widths, get_start_position, get_title_name},
         Painter,
     },
     constants::*,
 };
 use unicode_segmentation::UnicodeSegmentation;
 
-const DISK_HEADERS: [&str; 7] = ["Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s"];
+const DISK_HEADERS: [&str; 11] = [
+    "Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s", "IOPS", "Await", "Queue", "Util",
+];
 
 static DISK_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
     DISK_HEADERS
@@ -59,7 +61,19 @@ impl Painter {
             let sliced_vec = &app_state.canvas_data.disk_data[start_position..];
 
             // Calculate widths
-            let hard_widths = [None, None, Some(4), Some(6), Some(6), Some(7), Some(7)];
+            let hard_widths = [
+                None,
+                None,
+                Some(4),
+                Some(6),
+                Some(6),
+                Some(7),
+                Some(7),
+                Some(5),
+                Some(8),
+                Some(5),
+                Some(4),
+            ];
             if recalculate_column_widths {
                 disk_widget_state.table_width_state.desired_column_widths = {
                     let mut column_widths = DISK_HEADERS_LENS.clone();
@@ -97,7 +111,19 @@ impl Painter {
                         .iter()
                         .map(|w| Some(*w))
                         .collect::<Vec<_>>()),
-                    &[Some(0.2), Some(0.2), None, None, None, None, None],
+                    &[
+                        Some(0.2),
+                        Some(0.2),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    ],
                     &(disk_widget_state
                         .table_width_state
                         .desired_column_widths
@@ -156,9 +182,11 @@ impl Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let title_name = get_title_name("Disk", DataSource::Disk, &app_state.canvas_data);
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 let title_string = format!(
-                    " Disk ({} of {}) ",
+                    " {} ({} of {}) ",
+                    title_name,
                     disk_widget_state
                         .scroll_state
                         .current_scroll_position
@@ -169,10 +197,10 @@ impl Painter {
                 if title_string.len() <= draw_loc.width.into() {
                     title_string
                 } else {
-                    " Disk ".to_string()
+                    format!(" {} ", title_name)
                 }
             } else {
-                " Disk ".to_string()
+                format!(" {} ", title_name)
             };
 
             let title = if app_state.is_expanded {
@@ -183,8 +211,8 @@ impl Painter {
 
                     if temp_title_base.len() > draw_loc.width.into() {
                         (
-                            " Disk ".to_string(),
-                            format!("{}{}", " Disk ", ESCAPE_ENDING),
+                            format!(" {} ", title_name),
+                            format!(" {} {}", title_name, ESCAPE_ENDING),
                         )
                     } else {
                         (title_base, temp_title_base)
@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{data_harvester::DataSource, App},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        Painter,
+    },
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+impl Painter {
+    pub fn draw_disk_util_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        const Y_BOUNDS: [f64; 2] = [0.0, 100.5];
+        const Y_LABELS: [Cow<'static, str>; 2] = [Cow::Borrowed("  0%"), Cow::Borrowed("100%")];
+
+        if let Some(disk_util_widget_state) =
+            app_state.disk_util_state.widget_states.get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, disk_util_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut disk_util_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let disk_util_data = &app_state.canvas_data.disk_util_data;
+            let name_width = disk_util_data
+                .iter()
+                .map(|disk| disk.name.len())
+                .max()
+                .unwrap_or(0);
+            let points = disk_util_data
+                .iter()
+                .enumerate()
+                .map(|(itx, disk)| GraphData {
+                    points: &disk.points,
+                    style: self.colours.cpu_colour_styles
+                        [itx % self.colours.cpu_colour_styles.len()],
+                    name: Some(
+                        format!(
+                            "{:<width$} {}",
+                            disk.name,
+                            disk.legend_value,
+                            width = name_width
+                        )
+                        .into(),
+                    ),
+                })
+                .collect::<Vec<_>>();
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds: Y_BOUNDS,
+                y_labels: &Y_LABELS,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name("Disk Utilization", DataSource::Disk, &app_state.canvas_data)
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{data_harvester::DataSource, App},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        Painter, Point,
+    },
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+const LOAD_AVG_NAMES: [&str; 3] = ["1m", "5m", "15m"];
+
+/// Returns the upper y-axis bound; a little above the highest value, rounded up to a whole number.
+fn get_y_max(max_value: f64) -> f64 {
+    (max_value * 1.1).ceil().max(1.0)
+}
+
+impl Painter {
+    pub fn draw_load_avg_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(load_avg_widget_state) =
+            app_state.load_avg_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(load_avg_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, load_avg_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut load_avg_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let load_avg_data = &app_state.canvas_data.load_avg_graph_data;
+            let core_count = load_avg_data.core_count.map(|core_count| core_count as f64);
+
+            // Always keep the core count line in view, as it's the reference point.
+            let max_value = load_avg_data
+                .points
+                .iter()
+                .flat_map(|points| points.iter())
+                .filter(|(time, _)| *time >= time_start)
+                .map(|(_, value)| *value)
+                .chain(core_count)
+                .fold(0.0, f64::max);
+            let y_max = get_y_max(max_value);
+            let y_bounds = [0.0, y_max];
+            let y_labels = [
+                Cow::Borrowed("0"),
+                Cow::Owned(format!("{:.1}", y_max / 2.0)),
+                Cow::Owned(format!("{:.0}", y_max)),
+            ];
+
+            let mut points = load_avg_data
+                .points
+                .iter()
+                .zip(LOAD_AVG_NAMES)
+                .enumerate()
+                .map(|(itx, (points, name))| GraphData {
+                    points,
+                    style: self.colours.cpu_colour_styles
+                        [itx % self.colours.cpu_colour_styles.len()],
+                    name: Some(
+                        format!(
+                            "{:<5} {:>6.2}",
+                            name,
+                            points.last().map(|(_, value)| *value).unwrap_or(0.0)
+                        )
+                        .into(),
+                    ),
+                })
+                .collect::<Vec<_>>();
+
+            // The core count line spans the whole x-axis.
+            let core_count_points: Option<[Point; 2]> =
+                core_count.map(|core_count| [(time_start, core_count), (0.0, core_count)]);
+            if let (Some(core_count_points), Some(core_count)) =
+                (&core_count_points, load_avg_data.core_count)
+            {
+                points.push(GraphData {
+                    points: core_count_points,
+                    style: self.colours.medium_battery_colour,
+                    name: Some(format!("{:<5} {:>6}", "Cores", core_count).into()),
+                });
+            }
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name("Load Average", DataSource::Cpu, &app_state.canvas_data)
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load_avg_y_max() {
+        assert_eq!(get_y_max(0.0), 1.0);
+        assert_eq!(get_y_max(4.0), 5.0);
+        assert_eq!(get_y_max(9.5), 11.0);
+    }
+}
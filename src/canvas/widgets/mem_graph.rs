@@ -1,10 +1,10 @@
 use std::borrow::Cow;
 
 use crate::{
-    app::App,
+    app::{data_harvester::DataSource, App},
     canvas::{
         components::{GraphData, TimeGraph},
-        drawing_utils::should_hide_x_label,
+        drawing_utils::{get_title_name, should_hide_x_label},
         Painter,
     },
 };
@@ -61,7 +61,11 @@ impl Painter {
                 y_labels: &Y_LABELS,
                 graph_style: self.colours.graph_style,
                 border_style,
-                title: " Memory ".into(),
+                title: format!(
+                    " {} ",
+                    get_title_name("Memory", DataSource::Memory, &app_state.canvas_data)
+                )
+                .into(),
                 is_expanded: app_state.is_expanded,
                 title_style: self.colours.widget_title_style,
                 legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
@@ -2,10 +2,10 @@ use once_cell::sync::Lazy;
 use std::cmp::max;
 
 use crate::{
-    app::{App, AxisScaling},
+    app::{data_harvester::DataSource, App, AxisScaling},
     canvas::{
         components::{GraphData, TimeGraph},
-        drawing_utils::{get_column_widths, should_hide_x_label},
+        drawing_utils::{get_column_widths, get_title_name, should_hide_x_label},
         Painter, Point,
     },
     constants::*,
@@ -162,7 +162,11 @@ impl Painter {
                 y_labels: &y_labels,
                 graph_style: self.colours.graph_style,
                 border_style,
-                title: " Network ".into(),
+                title: format!(
+                    " {} ",
+                    get_title_name("Network", DataSource::Network, &app_state.canvas_data)
+                )
+                .into(),
                 is_expanded: app_state.is_expanded,
                 title_style: self.colours.widget_title_style,
                 legend_constraints: Some(legend_constraints),
@@ -360,7 +364,7 @@ fn get_max_entry(
 }
 
 /// Returns the required max data point and labels.
-fn adjust_network_data_point(
+pub(super) fn adjust_network_data_point(
     max_entry: f64, network_scale_type: &AxisScaling, network_unit_type: &DataUnit,
     network_use_binary_prefix: bool,
 ) -> (f64, Vec<String>) {
@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{data_harvester::DataSource, App},
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        Painter,
+    },
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+/// The upper bounds the y-axis can snap to.  Pressure tends to sit near zero, so always scaling
+/// to 100% would flatten everything interesting.
+const Y_UPPER_BOUNDS: [f64; 4] = [10.0, 25.0, 50.0, 100.0];
+
+impl Painter {
+    pub fn draw_pressure_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(pressure_widget_state) =
+            app_state.pressure_state.widget_states.get_mut(&widget_id)
+        {
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, pressure_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut pressure_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let pressure_data = &app_state.canvas_data.pressure_data;
+            let max_value = pressure_data
+                .iter()
+                .flat_map(|resource| resource.points.iter())
+                .filter(|(time, _)| -time <= x_bounds[1] as f64)
+                .map(|(_, value)| *value)
+                .fold(0.0, f64::max);
+            let upper_bound = Y_UPPER_BOUNDS
+                .iter()
+                .copied()
+                .find(|bound| max_value <= *bound)
+                .unwrap_or(100.0);
+            let y_bounds = [0.0, upper_bound + 0.5];
+            let y_labels = [
+                Cow::Borrowed("  0%"),
+                Cow::Owned(format!("{:3.0}%", upper_bound)),
+            ];
+
+            let points = pressure_data
+                .iter()
+                .enumerate()
+                .map(|(itx, resource)| GraphData {
+                    points: &resource.points,
+                    style: self.colours.cpu_colour_styles
+                        [itx % self.colours.cpu_colour_styles.len()],
+                    name: Some(format!("{:<4}{}", resource.name, resource.legend_value).into()),
+                })
+                .collect::<Vec<_>>();
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name("Pressure", DataSource::Cpu, &app_state.canvas_data)
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
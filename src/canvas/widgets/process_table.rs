@@ -1,7 +1,12 @@
 use crate::{
-    app::App,
+    app::{
+        data_harvester::{processes::ProcessSorting, DataSource},
+        App,
+    },
     canvas::{
-        drawing_utils::{get_column_widths, get_search_start_position, get_start_position},
+        drawing_utils::{
+            get_column_widths, get_search_start_position, get_source_status, get_start_position,
+        },
         Painter,
     },
     constants::*,
@@ -18,74 +23,52 @@ use tui::{
 use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
 use unicode_width::UnicodeWidthStr;
 
-const PROCESS_HEADERS_HARD_WIDTH_NO_GROUP: &[Option<u16>] = &[
-    Some(7),
-    None,
-    Some(8),
-    Some(8),
-    Some(8),
-    Some(8),
-    Some(7),
-    Some(8),
-    #[cfg(target_family = "unix")]
-    None,
-    None,
-];
-const PROCESS_HEADERS_HARD_WIDTH_GROUPED: &[Option<u16>] = &[
-    Some(7),
-    None,
-    Some(8),
-    Some(8),
-    Some(8),
-    Some(8),
-    Some(7),
-    Some(8),
-];
-
-const PROCESS_HEADERS_SOFT_WIDTH_MAX_GROUPED_COMMAND: &[Option<f64>] =
-    &[None, Some(0.7), None, None, None, None, None, None];
-const PROCESS_HEADERS_SOFT_WIDTH_MAX_GROUPED_ELSE: &[Option<f64>] =
-    &[None, Some(0.3), None, None, None, None, None, None];
-
-const PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_COMMAND: &[Option<f64>] = &[
-    None,
-    Some(0.7),
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    #[cfg(target_family = "unix")]
-    Some(0.05),
-    Some(0.2),
-];
-const PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_TREE: &[Option<f64>] = &[
-    None,
-    Some(0.5),
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    #[cfg(target_family = "unix")]
-    Some(0.05),
-    Some(0.2),
-];
-const PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_ELSE: &[Option<f64>] = &[
-    None,
-    Some(0.3),
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    #[cfg(target_family = "unix")]
-    Some(0.05),
-    Some(0.2),
-];
+/// Returns the hard width of a process column, if it has one.
+fn get_process_column_hard_width(column: &ProcessSorting) -> Option<u16> {
+    match column {
+        ProcessSorting::Count | ProcessSorting::Pid | ProcessSorting::TotalRead => Some(7),
+        ProcessSorting::CpuPercent
+        | ProcessSorting::Mem
+        | ProcessSorting::MemPercent
+        | ProcessSorting::Pss
+        | ProcessSorting::Uss
+        | ProcessSorting::SharedMem
+        | ProcessSorting::Swap
+        | ProcessSorting::Fds
+        | ProcessSorting::FdPercent
+        | ProcessSorting::SocketFds
+        | ProcessSorting::PipeFds
+        | ProcessSorting::FileFds
+        | ProcessSorting::ReadPerSecond
+        | ProcessSorting::WritePerSecond
+        | ProcessSorting::TotalWrite => Some(8),
+        ProcessSorting::ProcessName
+        | ProcessSorting::Command
+        | ProcessSorting::User
+        | ProcessSorting::State => None,
+    }
+}
+
+/// Returns the maximum ratio of the total width a process column may take up, if it is limited.
+fn get_process_column_soft_width_max(
+    column: &ProcessSorting, is_using_command: bool, is_tree_mode: bool,
+) -> Option<f64> {
+    match column {
+        ProcessSorting::ProcessName | ProcessSorting::Command => {
+            if is_using_command {
+                Some(0.7)
+            } else if is_tree_mode {
+                // Note grouped trees are not a thing.
+                Some(0.5)
+            } else {
+                Some(0.3)
+            }
+        }
+        ProcessSorting::User => Some(0.05),
+        ProcessSorting::State => Some(0.2),
+        _ => None,
+    }
+}
 
 impl Painter {
     /// Draws and handles all process-related drawing.  Use this.
@@ -168,6 +151,35 @@ impl Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let title_name = if let Some(notice) = proc_widget_state.get_notice() {
+                format!("Processes ─ {}", notice)
+            } else {
+                let mut modes = vec![];
+                if proc_widget_state.is_grouped {
+                    modes.push(format!(
+                        "by {}",
+                        proc_widget_state.grouping_type.description()
+                    ));
+                }
+                if proc_widget_state.is_tree_mode && proc_widget_state.show_tree_totals {
+                    modes.push("subtree totals".to_string());
+                }
+                if proc_widget_state.is_following {
+                    modes.push("following".to_string());
+                }
+                if let Some(status) =
+                    get_source_status(DataSource::Processes, &app_state.canvas_data)
+                {
+                    modes.push(status.to_string());
+                }
+
+                if modes.is_empty() {
+                    "Processes".to_string()
+                } else {
+                    format!("Processes ({})", modes.join(", "))
+                }
+            };
+
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 if let Some(finalized_process_data) = app_state
                     .canvas_data
@@ -175,7 +187,8 @@ impl Painter {
                     .get(&widget_id)
                 {
                     let title = format!(
-                        " Processes ({} of {}) ",
+                        " {} ({} of {}) ",
+                        title_name,
                         proc_widget_state
                             .scroll_state
                             .current_scroll_position
@@ -192,7 +205,13 @@ impl Painter {
                     " Processes ".to_string()
                 }
             } else {
-                " Processes ".to_string()
+                let title = format!(" {} ", title_name);
+
+                if title.len() <= draw_loc.width.into() {
+                    title
+                } else {
+                    " Processes ".to_string()
+                }
             };
 
             let title = if app_state.is_expanded
@@ -307,11 +326,11 @@ impl Painter {
 
                 // Calculate widths
                 // FIXME: See if we can move this into the recalculate block?  I want to move column widths into the column widths
-                let hard_widths = if proc_widget_state.is_grouped {
-                    PROCESS_HEADERS_HARD_WIDTH_GROUPED
-                } else {
-                    PROCESS_HEADERS_HARD_WIDTH_NO_GROUP
-                };
+                let enabled_columns = proc_widget_state.columns.get_enabled_columns();
+                let hard_widths = enabled_columns
+                    .iter()
+                    .map(get_process_column_hard_width)
+                    .collect::<Vec<_>>();
 
                 if recalculate_column_widths {
                     let mut column_widths = process_headers
@@ -342,7 +361,7 @@ impl Painter {
                         .table_width_state
                         .desired_column_widths
                         .iter()
-                        .zip(hard_widths)
+                        .zip(&hard_widths)
                         .map(|(current, hard)| {
                             if let Some(hard) = hard {
                                 if *hard > *current {
@@ -356,28 +375,25 @@ impl Painter {
                         })
                         .collect::<Vec<_>>();
 
-                    let soft_widths_max = if proc_widget_state.is_grouped {
-                        // Note grouped trees are not a thing.
-
-                        if proc_widget_state.is_using_command {
-                            PROCESS_HEADERS_SOFT_WIDTH_MAX_GROUPED_COMMAND
-                        } else {
-                            PROCESS_HEADERS_SOFT_WIDTH_MAX_GROUPED_ELSE
-                        }
-                    } else if proc_widget_state.is_using_command {
-                        PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_COMMAND
-                    } else if proc_widget_state.is_tree_mode {
-                        PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_TREE
-                    } else {
-                        PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_ELSE
-                    };
+                    let is_using_command = proc_widget_state.is_using_command;
+                    let is_tree_mode = proc_widget_state.is_tree_mode;
+                    let soft_widths_max = enabled_columns
+                        .iter()
+                        .map(|column| {
+                            get_process_column_soft_width_max(
+                                column,
+                                is_using_command,
+                                is_tree_mode,
+                            )
+                        })
+                        .collect::<Vec<_>>();
 
                     proc_widget_state.table_width_state.calculated_column_widths =
                         get_column_widths(
                             draw_loc.width,
-                            hard_widths,
+                            &hard_widths,
                             &soft_widths_min,
-                            soft_widths_max,
+                            &soft_widths_max,
                             &(proc_widget_state
                                 .table_width_state
                                 .desired_column_widths
@@ -401,7 +417,7 @@ impl Painter {
                 let ccw = &proc_widget_state.table_width_state.calculated_column_widths;
 
                 let process_rows = sliced_vec.iter().map(|(data, disabled)| {
-                    let truncated_data = data.iter().zip(hard_widths).enumerate().map(
+                    let truncated_data = data.iter().zip(&hard_widths).enumerate().map(
                         |(itx, ((entry, alternative), width))| {
                             if let (Some(desired_col_width), Some(calculated_col_width)) =
                                 (dcw.get(itx), ccw.get(itx))
@@ -0,0 +1,443 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::Span,
+    text::{Spans, Text},
+    widgets::{Block, Borders, Paragraph, Row, Table},
+};
+
+use crate::{
+    app::{
+        self,
+        data_harvester::{network::sockets::SocketHarvest, DataSource},
+        SocketSorting,
+    },
+    canvas::{
+        drawing_utils::{get_column_widths, get_start_position, get_title_name},
+        Painter,
+    },
+    constants::*,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+const UP_ARROW: char = '▲';
+const DOWN_ARROW: char = '▼';
+
+/// Converts a socket into the strings shown in each column.  Ports of zero (i.e. unbound
+/// remote ends) are shown as `*`, like `ss` does.
+fn get_socket_row(socket: &SocketHarvest) -> Vec<String> {
+    fn port_to_string(port: u16) -> String {
+        if port == 0 {
+            "*".to_string()
+        } else {
+            port.to_string()
+        }
+    }
+
+    vec![
+        socket.protocol.to_string(),
+        socket.local_address.ip().to_string(),
+        port_to_string(socket.local_address.port()),
+        socket.remote_address.ip().to_string(),
+        port_to_string(socket.remote_address.port()),
+        socket.state.to_string(),
+        socket.rx_queue.to_string(),
+        socket.tx_queue.to_string(),
+        socket.pid.map(|pid| pid.to_string()).unwrap_or_default(),
+        socket.process_name.clone().unwrap_or_default(),
+    ]
+}
+
+/// Returns the number of sockets in each state, most common first.
+fn get_state_counts(sockets: &[SocketHarvest]) -> Vec<(&'static str, usize)> {
+    let mut state_counts: Vec<(&'static str, usize)> = Vec::new();
+    for socket in sockets {
+        if let Some((_, count)) = state_counts
+            .iter_mut()
+            .find(|(state, _)| *state == socket.state)
+        {
+            *count += 1;
+        } else {
+            state_counts.push((socket.state, 1));
+        }
+    }
+
+    state_counts.sort_by(|(a_state, a_count), (b_state, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_state.cmp(b_state))
+    });
+    state_counts
+}
+
+impl Painter {
+    pub fn draw_socket_table<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
+        widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(socket_widget_state) = app_state.socket_state.widget_states.get_mut(&widget_id)
+        {
+            let socket_data: &[SocketHarvest] = app_state
+                .canvas_data
+                .socket_data_map
+                .get(&widget_id)
+                .map(|socket_data| socket_data.as_slice())
+                .unwrap_or(&[]);
+            let search_state = &socket_widget_state.search_state;
+            let is_showing_search =
+                search_state.is_enabled || !search_state.current_search_query.is_empty();
+
+            let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
+                0
+            } else {
+                app_state.app_config_fields.table_gap
+            };
+            let start_position = get_start_position(
+                usize::from(
+                    (draw_loc.height + (1 - table_gap))
+                        .saturating_sub(self.table_height_offset)
+                        .saturating_sub(if is_showing_search { 1 } else { 0 }),
+                ),
+                &socket_widget_state.scroll_state.scroll_direction,
+                &mut socket_widget_state.scroll_state.scroll_bar,
+                socket_widget_state.scroll_state.current_scroll_position,
+                app_state.is_force_redraw,
+            );
+            let is_on_widget = app_state.current_widget.widget_id == widget_id;
+            socket_widget_state.scroll_state.table_state.select(Some(
+                socket_widget_state
+                    .scroll_state
+                    .current_scroll_position
+                    .saturating_sub(start_position),
+            ));
+            let sliced_vec = socket_data
+                .get(start_position..)
+                .unwrap_or(&[])
+                .iter()
+                .map(get_socket_row)
+                .collect::<Vec<_>>();
+
+            let headers = SocketSorting::COLUMNS
+                .iter()
+                .map(|column| {
+                    if *column == socket_widget_state.sorting_type {
+                        format!(
+                            "{}{}",
+                            column.get_header(),
+                            if socket_widget_state.is_sort_descending {
+                                DOWN_ARROW
+                            } else {
+                                UP_ARROW
+                            }
+                        )
+                    } else {
+                        column.get_header().to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let header_lens = headers
+                .iter()
+                .map(|header| UnicodeSegmentation::graphemes(header.as_str(), true).count() as u16)
+                .collect::<Vec<_>>();
+
+            // Calculate widths
+            let hard_widths = [
+                Some(5),
+                None,
+                Some(6),
+                None,
+                Some(6),
+                Some(10),
+                Some(6),
+                Some(6),
+                Some(7),
+                None,
+            ];
+            if recalculate_column_widths {
+                socket_widget_state.table_width_state.desired_column_widths = {
+                    let mut column_widths = header_lens.clone();
+                    for row in &sliced_vec {
+                        for (col, entry) in row.iter().enumerate() {
+                            if entry.len() as u16 > column_widths[col] {
+                                column_widths[col] = entry.len() as u16;
+                            }
+                        }
+                    }
+                    column_widths
+                        .iter()
+                        .zip(&hard_widths)
+                        .map(|(current, hard)| match hard {
+                            Some(hard) if *hard > *current => *hard,
+                            _ => *current,
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                socket_widget_state
+                    .table_width_state
+                    .calculated_column_widths = get_column_widths(
+                    draw_loc.width,
+                    &hard_widths,
+                    &(header_lens.iter().map(|w| Some(*w)).collect::<Vec<_>>()),
+                    &[
+                        None,
+                        Some(0.2),
+                        None,
+                        Some(0.2),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(0.2),
+                    ],
+                    &(socket_widget_state
+                        .table_width_state
+                        .desired_column_widths
+                        .iter()
+                        .map(|w| Some(*w))
+                        .collect::<Vec<_>>()),
+                    true,
+                );
+            }
+
+            let dcw = &socket_widget_state.table_width_state.desired_column_widths;
+            let ccw = &socket_widget_state
+                .table_width_state
+                .calculated_column_widths;
+            let socket_rows =
+                sliced_vec.iter().map(|socket_row| {
+                    let truncated_data = socket_row.iter().zip(&hard_widths).enumerate().map(
+                        |(itx, (entry, width))| {
+                            if width.is_none() {
+                                if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                    (dcw.get(itx), ccw.get(itx))
+                                {
+                                    if *desired_col_width > *calculated_col_width
+                                        && *calculated_col_width > 0
+                                    {
+                                        let calculated_col_width: usize =
+                                            (*calculated_col_width).into();
+
+                                        let graphemes =
+                                            UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                                .collect::<Vec<&str>>();
+
+                                        if graphemes.len() > calculated_col_width
+                                            && calculated_col_width > 1
+                                        {
+                                            // Truncate with ellipsis
+                                            let first_n =
+                                                graphemes[..(calculated_col_width - 1)].concat();
+                                            return Text::raw(format!("{}…", first_n));
+                                        }
+                                    }
+                                }
+                            }
+
+                            Text::raw(entry.clone())
+                        },
+                    );
+
+                    Row::new(truncated_data)
+                });
+
+            let (border_style, highlight_style) = if is_on_widget {
+                (
+                    self.colours.highlighted_border_style,
+                    self.colours.currently_selected_text_style,
+                )
+            } else {
+                (self.colours.border_style, self.colours.text_style)
+            };
+
+            let title_name = get_title_name("Sockets", DataSource::Network, &app_state.canvas_data);
+            let title_base = if app_state.app_config_fields.show_table_scroll_position {
+                format!(
+                    " {} ({} of {}) ",
+                    title_name,
+                    socket_widget_state
+                        .scroll_state
+                        .current_scroll_position
+                        .saturating_add(1),
+                    socket_data.len()
+                )
+            } else {
+                format!(" {} ({}) ", title_name, socket_data.len())
+            };
+
+            // Add as many per-state counts as will fit.
+            let escape_ending = if app_state.is_expanded {
+                "── Esc to go back "
+            } else {
+                ""
+            };
+            let max_title_width = usize::from(draw_loc.width).saturating_sub(2);
+            let mut title_with_counts = title_base;
+            for (state, count) in get_state_counts(socket_data) {
+                let state_count = format!("{} {} ", state, count);
+                if UnicodeSegmentation::graphemes(title_with_counts.as_str(), true).count()
+                    + state_count.len()
+                    + escape_ending.len()
+                    > max_title_width
+                {
+                    break;
+                }
+                title_with_counts.push_str(&state_count);
+            }
+
+            let title = if app_state.is_expanded {
+                Spans::from(vec![
+                    Span::styled(title_with_counts.clone(), self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(
+                                usize::from(draw_loc.width).saturating_sub(
+                                    UnicodeSegmentation::graphemes(
+                                        format!("{}{}", title_with_counts, escape_ending).as_str(),
+                                        true
+                                    )
+                                    .count()
+                                        + 2
+                                )
+                            )
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(Span::styled(
+                    title_with_counts,
+                    self.colours.widget_title_style,
+                ))
+            };
+
+            let socket_block = if draw_border {
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+            } else if is_on_widget {
+                Block::default()
+                    .borders(SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style)
+            } else {
+                Block::default().borders(Borders::NONE)
+            };
+
+            let margined_draw_loc = Layout::default()
+                .constraints([Constraint::Percentage(100)])
+                .horizontal_margin(if is_on_widget || draw_border { 0 } else { 1 })
+                .direction(Direction::Horizontal)
+                .split(draw_loc)[0];
+
+            let inner_draw_loc = socket_block.inner(margined_draw_loc);
+            let table_and_search_loc = Layout::default()
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(if is_showing_search { 1 } else { 0 }),
+                ])
+                .direction(Direction::Vertical)
+                .split(inner_draw_loc);
+            f.render_widget(socket_block, margined_draw_loc);
+
+            // Draw!
+            f.render_stateful_widget(
+                Table::new(socket_rows)
+                    .header(
+                        Row::new(headers)
+                            .style(self.colours.table_header_style)
+                            .bottom_margin(table_gap),
+                    )
+                    .highlight_style(highlight_style)
+                    .style(self.colours.text_style)
+                    .widths(
+                        &(socket_widget_state
+                            .table_width_state
+                            .calculated_column_widths
+                            .iter()
+                            .map(|calculated_width| Constraint::Length(*calculated_width))
+                            .collect::<Vec<_>>()),
+                    ),
+                table_and_search_loc[0],
+                &mut socket_widget_state.scroll_state.table_state,
+            );
+
+            if is_showing_search {
+                let search_state = &socket_widget_state.search_state;
+                let mut search_spans = vec![
+                    Span::styled("Filter: ", self.colours.table_header_style),
+                    Span::styled(
+                        search_state.current_search_query.clone(),
+                        self.colours.text_style,
+                    ),
+                ];
+                if search_state.is_enabled {
+                    search_spans.push(Span::styled(
+                        " ",
+                        self.colours.currently_selected_text_style,
+                    ));
+                }
+                if let Some(error_message) = &search_state.error_message {
+                    search_spans.push(Span::styled(
+                        format!("  {}", error_message),
+                        self.colours.invalid_query_style,
+                    ));
+                }
+
+                f.render_widget(
+                    Paragraph::new(Spans::from(search_spans)),
+                    table_and_search_loc[1],
+                );
+            }
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                    widget.top_left_corner = Some((margined_draw_loc.x, margined_draw_loc.y));
+                    widget.bottom_right_corner = Some((
+                        margined_draw_loc.x + margined_draw_loc.width,
+                        margined_draw_loc.y + margined_draw_loc.height,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::data_harvester::network::sockets::SocketProtocol;
+
+    #[test]
+    fn test_state_counts() {
+        let sockets: Vec<SocketHarvest> =
+            ["ESTABLISHED", "LISTEN", "ESTABLISHED", "CLOSE", "TIME_WAIT"]
+                .iter()
+                .map(|state| SocketHarvest {
+                    protocol: SocketProtocol::Tcp,
+                    local_address: "127.0.0.1:80".parse().unwrap(),
+                    remote_address: "127.0.0.1:0".parse().unwrap(),
+                    state,
+                    tx_queue: 0,
+                    rx_queue: 0,
+                    inode: 0,
+                    pid: None,
+                    process_name: None,
+                })
+                .collect();
+
+        assert_eq!(
+            get_state_counts(&sockets),
+            vec![
+                ("ESTABLISHED", 2),
+                ("CLOSE", 1),
+                ("LISTEN", 1),
+                ("TIME_WAIT", 1)
+            ]
+        );
+        assert!(get_state_counts(&[]).is_empty());
+    }
+}
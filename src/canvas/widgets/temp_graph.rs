@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{
+        data_harvester::{temperature::TemperatureType, DataSource},
+        App,
+    },
+    canvas::{
+        components::{GraphData, TimeGraph},
+        drawing_utils::{get_title_name, should_hide_x_label},
+        Painter, Point,
+    },
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    terminal::Frame,
+};
+
+/// Returns the y-axis bounds for the given range of values, padded and rounded out to the nearest
+/// multiple of 10 so the graph doesn't hug its edges.
+fn get_y_bounds(min_value: f64, max_value: f64) -> [f64; 2] {
+    let lower = ((min_value - 5.0) / 10.0).floor() * 10.0;
+    let upper = ((max_value + 5.0) / 10.0).ceil() * 10.0;
+    [lower.max(0.0), upper]
+}
+
+impl Painter {
+    pub fn draw_temp_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(temp_graph_widget_state) =
+            app_state.temp_graph_state.widget_states.get_mut(&widget_id)
+        {
+            let time_start = -(temp_graph_widget_state.current_display_time as f64);
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let x_bounds = [0, temp_graph_widget_state.current_display_time];
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut temp_graph_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let temp_graph_data = &app_state.canvas_data.temp_graph_data;
+            let thresholds = &app_state.app_config_fields.temp_graph_thresholds;
+            let unit = match app_state.app_config_fields.temperature_type {
+                TemperatureType::Celsius => "°C",
+                TemperatureType::Kelvin => "K",
+                TemperatureType::Fahrenheit => "°F",
+            };
+
+            let (min_value, max_value) = temp_graph_data
+                .iter()
+                .flat_map(|sensor| sensor.points.iter())
+                .filter(|(time, _)| *time >= time_start)
+                .map(|(_, value)| *value)
+                .chain(thresholds.iter().copied())
+                .fold((f64::MAX, f64::MIN), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                });
+            let y_bounds = if min_value <= max_value {
+                get_y_bounds(min_value, max_value)
+            } else {
+                [0.0, 100.0]
+            };
+            let y_labels = [
+                Cow::Owned(format!("{:.0}{}", y_bounds[0], unit)),
+                Cow::Owned(format!("{:.0}{}", (y_bounds[0] + y_bounds[1]) / 2.0, unit)),
+                Cow::Owned(format!("{:.0}{}", y_bounds[1], unit)),
+            ];
+
+            // Threshold lines span the whole x-axis.  The highest is drawn in the "low battery"
+            // colour and the rest in the "medium battery" one, since those are the palette's
+            // red and yellow.
+            let max_threshold = thresholds.iter().copied().fold(f64::MIN, f64::max);
+            let threshold_points = thresholds
+                .iter()
+                .map(|threshold| [(time_start, *threshold), (0.0, *threshold)])
+                .collect::<Vec<[Point; 2]>>();
+
+            let name_width = temp_graph_data
+                .iter()
+                .map(|sensor| sensor.name.len())
+                .max()
+                .unwrap_or(0);
+            let mut points = temp_graph_data
+                .iter()
+                .enumerate()
+                .map(|(itx, sensor)| GraphData {
+                    points: &sensor.points,
+                    style: self.colours.cpu_colour_styles
+                        [itx % self.colours.cpu_colour_styles.len()],
+                    name: Some(
+                        format!(
+                            "{:<width$} {:>4}{}",
+                            sensor.name,
+                            sensor.legend_value,
+                            unit,
+                            width = name_width
+                        )
+                        .into(),
+                    ),
+                })
+                .collect::<Vec<_>>();
+            points.extend(thresholds.iter().zip(&threshold_points).map(
+                |(threshold, threshold_points)| GraphData {
+                    points: threshold_points,
+                    style: if *threshold >= max_threshold {
+                        self.colours.low_battery_colour
+                    } else {
+                        self.colours.medium_battery_colour
+                    },
+                    name: None,
+                },
+            ));
+
+            TimeGraph {
+                use_dot: app_state.app_config_fields.use_dot,
+                x_bounds,
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.colours.graph_style,
+                border_style,
+                title: format!(
+                    " {} ",
+                    get_title_name(
+                        "Temperature",
+                        DataSource::Temperature,
+                        &app_state.canvas_data
+                    )
+                )
+                .into(),
+                is_expanded: app_state.is_expanded,
+                title_style: self.colours.widget_title_style,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+            }
+            .draw_time_graph(f, draw_loc, &points);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_temp_graph_y_bounds() {
+        assert_eq!(get_y_bounds(42.0, 71.0), [30.0, 80.0]);
+        assert_eq!(get_y_bounds(2.0, 95.0), [0.0, 100.0]);
+    }
+}
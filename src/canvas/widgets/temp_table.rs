@@ -2,6 +2,7 @@ use once_cell::sync::Lazy;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
     terminal::Frame,
     text::Span,
     text::{Spans, Text},
@@ -9,9 +10,12 @@ use tui::{
 };
 
 use crate::{
-    app,
+    app::{
+        self,
+        data_harvester::{temperature::HeatLevel, DataSource},
+    },
     canvas::{
-        drawing_utils::{get_column_widths, get_start_position},
+        drawing_utils::{get_column_widths, get_start_position, get_title_name},
         Painter,
     },
     constants::*,
@@ -28,6 +32,15 @@ static TEMP_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
 });
 
 impl Painter {
+    /// Returns the style to highlight a row with based on how hot its sensor is, if any.
+    pub(super) fn get_heat_level_style(&self, heat_level: HeatLevel) -> Option<Style> {
+        match heat_level {
+            HeatLevel::Normal => None,
+            HeatLevel::Warm => Some(self.colours.medium_battery_colour),
+            HeatLevel::Critical => Some(self.colours.low_battery_colour),
+        }
+    }
+
     pub fn draw_temp_table<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
         widget_id: u64,
@@ -63,7 +76,7 @@ impl Painter {
             if recalculate_column_widths {
                 temp_widget_state.table_width_state.desired_column_widths = {
                     let mut column_widths = TEMP_HEADERS_LENS.clone();
-                    for row in sliced_vec {
+                    for (row, _heat_level) in sliced_vec {
                         for (col, entry) in row.iter().enumerate() {
                             if entry.len() as u16 > column_widths[col] {
                                 column_widths[col] = entry.len() as u16;
@@ -94,7 +107,7 @@ impl Painter {
             let dcw = &temp_widget_state.table_width_state.desired_column_widths;
             let ccw = &temp_widget_state.table_width_state.calculated_column_widths;
             let temperature_rows =
-                sliced_vec.iter().map(|temp_row| {
+                sliced_vec.iter().map(|(temp_row, heat_level)| {
                     let truncated_data = temp_row.iter().zip(&hard_widths).enumerate().map(
                         |(itx, (entry, width))| {
                             if width.is_none() {
@@ -133,7 +146,11 @@ impl Painter {
                         },
                     );
 
-                    Row::new(truncated_data)
+                    let row = Row::new(truncated_data);
+                    match self.get_heat_level_style(*heat_level) {
+                        Some(style) => row.style(style),
+                        None => row,
+                    }
                 });
 
             let (border_style, highlight_style) = if is_on_widget {
@@ -145,9 +162,15 @@ impl Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let title_name = get_title_name(
+                "Temperatures",
+                DataSource::Temperature,
+                &app_state.canvas_data,
+            );
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 let title_string = format!(
-                    " Temperatures ({} of {}) ",
+                    " {} ({} of {}) ",
+                    title_name,
                     temp_widget_state
                         .scroll_state
                         .current_scroll_position
@@ -158,10 +181,10 @@ impl Painter {
                 if title_string.len() <= draw_loc.width.into() {
                     title_string
                 } else {
-                    " Temperatures ".to_string()
+                    format!(" {} ", title_name)
                 }
             } else {
-                " Temperatures ".to_string()
+                format!(" {} ", title_name)
             };
 
             let title = if app_state.is_expanded {
@@ -172,8 +195,8 @@ impl Painter {
 
                     if temp_title_base.len() > draw_loc.width.into() {
                         (
-                            " Temperatures ".to_string(),
-                            format!("{}{}", " Temperatures ", ESCAPE_ENDING),
+                            format!(" {} ", title_name),
+                            format!(" {} {}", title_name, ESCAPE_ENDING),
                         )
                     } else {
                         (title_base, temp_title_base)
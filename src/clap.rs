@@ -148,12 +148,25 @@ pub fn build_app() -> Command<'static> {
         .help("Uses a dot marker for graphs.")
         .long_help("Uses a dot marker for graphs as opposed to the default braille marker.");
 
+    let follow = Arg::new("follow")
+        .long("follow")
+        .help("Keeps the process selection on the selected process.")
+        .long_help("Defaults to keeping the selection in the process widget on the selected process (or group) as the table is re-sorted or updated, rather than on the selected row.");
+
     let group = Arg::new("group") // FIXME: Rename this to something like "group_process", would be "breaking" though.
         .short('g')
         .long("group")
         .help("Groups processes with the same name by default.")
         .long_help("Groups processes with the same name by default.");
 
+    let group_by = Arg::new("group_by")
+        .long("group_by")
+        .takes_value(true)
+        .value_name("TYPE")
+        .possible_values(["name", "user", "parent", "path", "cgroup"])
+        .help("Sets what processes are grouped by.")
+        .long_help("Sets what processes are grouped by when grouped. Defaults to \"name\". \"path\" groups by the executable path, and \"cgroup\" is only supported on Linux.");
+
     let hide_avg_cpu = Arg::new("hide_avg_cpu")
         .short('a')
         .long("hide_avg_cpu")
@@ -197,6 +210,14 @@ pub fn build_app() -> Command<'static> {
         .help("Shows the scroll position tracker in table widgets.")
         .long_help("Shows the list scroll position tracker in the widget title for table widgets.");
 
+    let show_exited = Arg::new("show_exited")
+        .long("show_exited")
+        .help("Keeps showing exited processes for a few seconds.")
+        .long_help(
+            "Keeps showing processes in the process widget, greyed out with their last known usage, for \
+            a few seconds after they exit. Useful for catching short-lived processes.",
+        );
+
     let use_old_network_legend = Arg::new("use_old_network_legend")
         .long("use_old_network_legend")
         .help("DEPRECATED - uses a separate network legend.")
@@ -309,6 +330,17 @@ use CPU (3) as the default instead.
         .help("Sets the default widget type, use --help for info.")
         .long_help(DEFAULT_WIDGET_TYPE_STR);
 
+    let proc_root = Arg::new("proc_root")
+        .long("proc_root")
+        .alias("proc-root")
+        .takes_value(true)
+        .value_name("PATH")
+        .help("Sets where procfs is mounted. Linux only.")
+        .long_help(
+            "Sets where procfs is mounted, which is /proc by default. Useful for monitoring a host from \
+            a container, with the host's /proc mounted elsewhere. Linux only.",
+        );
+
     let rate = Arg::new("rate")
         .short('r')
         .long("rate")
@@ -331,6 +363,11 @@ use CPU (3) as the default instead.
         .help("Defaults the process widget be in tree mode.")
         .long_help("Defaults to showing the process widget in tree mode.");
 
+    let tree_totals = Arg::new("tree_totals")
+        .long("tree_totals")
+        .help("Shows subtree totals in tree mode.")
+        .long_help("Makes each process in tree mode show (and sort by) its usage summed with that of all its descendants, rather than just its own usage.");
+
     let network_use_bytes = Arg::new("network_use_bytes")
         .long("network_use_bytes")
         .help("Displays the network widget using bytes.")
@@ -341,6 +378,18 @@ use CPU (3) as the default instead.
         .help("Displays the network widget with a log scale.")
         .long_help("Displays the network widget with a log scale. Defaults to a non-log scale.");
 
+    let sys_root = Arg::new("sys_root")
+        .long("sys_root")
+        .alias("sys-root")
+        .takes_value(true)
+        .value_name("PATH")
+        .help("Sets where sysfs is mounted. Linux only.")
+        .long_help(
+            "Sets where sysfs is mounted, which is /sys by default. Useful for monitoring a host from \
+            a container, with the host's /sys mounted elsewhere. Battery data is always read from /sys. \
+            Linux only.",
+        );
+
     let network_use_binary_prefix = Arg::new("network_use_binary_prefix")
         .long("network_use_binary_prefix")
         .help("Displays the network widget with binary prefixes.")
@@ -374,17 +423,23 @@ use CPU (3) as the default instead.
         .arg(default_widget_type)
         .arg(disable_click)
         .arg(dot_marker)
+        .arg(follow)
         .arg(group)
+        .arg(group_by)
         .arg(hide_avg_cpu)
         .arg(hide_table_gap)
         .arg(hide_time)
+        .arg(show_exited)
         .arg(show_table_scroll_position)
         .arg(left_legend)
         .arg(disable_advanced_kill)
+        .arg(proc_root)
         .arg(rate)
         .arg(regex)
+        .arg(sys_root)
         .arg(time_delta)
         .arg(tree)
+        .arg(tree_totals)
         .arg(network_use_bytes)
         .arg(network_use_log)
         .arg(network_use_binary_prefix)
@@ -12,11 +12,20 @@ pub const DEFAULT_TIME_MILLISECONDS: u64 = 60 * 1000; // Defaults to 1 min.
 pub const STALE_MIN_MILLISECONDS: u64 = 30 * 1000; // Lowest is 30 seconds
 pub const TIME_CHANGE_MILLISECONDS: u64 = 15 * 1000; // How much to increment each time
 pub const AUTOHIDE_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to autohide
+pub const NOTICE_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to show a notice
+pub const EXITED_PROCESS_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to show an exited process
 
 pub const TICK_RATE_IN_MILLISECONDS: u64 = 200;
 // How fast the screen refreshes
 pub const DEFAULT_REFRESH_RATE_IN_MILLISECONDS: u64 = 1000;
 pub const MAX_KEY_TIMEOUT_IN_MILLISECONDS: u64 = 1000;
+// The least time a collector is given before its source is marked as stale for that update;
+// otherwise a collector is given as long as its source's collection interval
+pub const MINIMUM_COLLECTOR_TIMEOUT_IN_MILLISECONDS: u64 = 250;
+// How long to wait before checking a hung mount again
+pub const HUNG_MOUNT_RETRY_IN_MILLISECONDS: u64 = 30 * 1000;
+// How often to look for the owners of new sockets, which means reading every process' fds.
+pub const SOCKET_OWNER_REFRESH_IN_MILLISECONDS: u64 = 5000;
 
 // Limits for when we should stop showing table gaps/labels (anything less means not shown)
 pub const TABLE_GAP_HEIGHT_LIMIT: u16 = 7;
@@ -213,7 +222,7 @@ pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigC
 });
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 8] = [
+pub const HELP_CONTENTS_TEXT: [&str; 9] = [
     "Press the corresponding numbers to jump to the section, or scroll:",
     "1 - General",
     "2 - CPU widget",
@@ -222,11 +231,12 @@ pub const HELP_CONTENTS_TEXT: [&str; 8] = [
     "5 - Process sort widget",
     "6 - Battery widget",
     "7 - Basic memory widget",
+    "8 - Socket widget",
 ];
 
 // TODO [Help]: Search in help?
 // TODO [Help]: Move to using tables for easier formatting?
-pub const GENERAL_HELP_TEXT: [&str; 31] = [
+pub const GENERAL_HELP_TEXT: [&str; 32] = [
     "1 - General",
     "q, Ctrl-c        Quit",
     "Esc              Close dialog windows, search, widgets, or exit expanded mode",
@@ -252,6 +262,7 @@ pub const GENERAL_HELP_TEXT: [&str; 31] = [
     "gg               Jump to the first entry",
     "G                Jump to the last entry",
     "e                Toggle expanding the currently selected widget",
+    "E                Show recent errors from collecting data",
     "+                Zoom in on chart (decrease time range)",
     "-                Zoom out on chart (increase time range)",
     "=                Reset zoom",
@@ -265,7 +276,7 @@ pub const CPU_HELP_TEXT: [&str; 2] = [
     "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
 ];
 
-pub const PROCESS_HELP_TEXT: [&str; 15] = [
+pub const PROCESS_HELP_TEXT: [&str; 22] = [
     "3 - Process widget",
     "dd, F9           Kill the selected process",
     "c                Sort by CPU usage, press again to reverse sorting order",
@@ -273,6 +284,7 @@ pub const PROCESS_HELP_TEXT: [&str; 15] = [
     "p                Sort by PID name, press again to reverse sorting order",
     "n                Sort by process name, press again to reverse sorting order",
     "Tab              Group/un-group processes with the same name",
+    "b                Cycle grouping by name, user, parent, executable path, or cgroup",
     "Ctrl-f, /        Open process search widget",
     "P                Toggle between showing the full command or just the process name",
     "s, F6            Open process sort widget",
@@ -280,6 +292,12 @@ pub const PROCESS_HELP_TEXT: [&str; 15] = [
     "%                Toggle between values and percentages for memory usage",
     "t, F5            Toggle tree mode",
     "+, -, click      Collapse/expand a branch while in tree mode",
+    "T                Toggle showing and sorting by subtree totals while in tree mode",
+    "F                Toggle keeping the selection on the selected process as the table changes",
+    "o                Show the network connections of the selected process (Linux only)",
+    "R                Show recently started and exited processes",
+    "w                Watch the processes matching the search, press again to stop watching",
+    "N                Show notifications from watched processes",
     "click on header  Sorts the entries by that column, click again to invert the sort",
 ];
 
@@ -354,6 +372,28 @@ pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%                Toggle between values and percentages for memory usage",
 ];
 
+pub const SOCKET_HELP_TEXT: [&str; 19] = [
+    "8 - Socket widget",
+    "s                Cycle the column to sort by",
+    "I                Invert current sort",
+    "/                Start typing a filter",
+    "Enter            Stop typing the filter (retains the filter)",
+    "Esc              Clear the filter",
+    "Backspace        Delete the last character of the filter",
+    "",
+    "Supported filter types:",
+    "<by name/addr>   ex: firefox",
+    "proto, protocol  ex: proto = tcp6",
+    "local, laddr     ex: local = 127.0.0.1",
+    "remote, raddr    ex: remote = 10.0.0.1",
+    "port             ex: port = 443",
+    "lport            ex: lport < 1024",
+    "rport            ex: rport = 22",
+    "state            ex: state = time_wait",
+    "pid              ex: pid 825",
+    "Comparisons, logical operators, and search settings are the same as process search",
+];
+
 pub const HELP_TEXT: &[&[&str]] = &[
     &HELP_CONTENTS_TEXT,
     &GENERAL_HELP_TEXT,
@@ -363,6 +403,7 @@ pub const HELP_TEXT: &[&[&str]] = &[
     &SORT_HELP_TEXT,
     &BATTERY_HELP_TEXT,
     &BASIC_MEM_HELP_TEXT,
+    &SOCKET_HELP_TEXT,
 ];
 
 // Default layouts
@@ -445,6 +486,8 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #current_usage = false
 # Whether to group processes with the same name together by default.
 #group_processes = false
+# What to group processes by when grouped, one of "name", "user", "parent", "path", or "cgroup".
+#group_by = "name"
 # Whether to make process searching case sensitive by default.
 #case_sensitive = false
 # Whether to make process searching look for matching the entire word by default.
@@ -483,8 +526,14 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #mem_as_value = false
 # Show tree mode by default in the processes widget.
 #tree = false
+# Show the summed usage of each process and its descendants in tree mode.
+#tree_totals = false
+# Keep the selection on the selected process as the processes widget is re-sorted or updated.
+#follow = false
 # Shows an indicator in table widgets tracking where in the list you are.
 #show_table_scroll_position = false
+# Keeps showing exited processes, greyed out, for a few seconds after they exit.
+#show_exited = false
 # Show processes as their commands by default in the process widget.
 #process_command = false
 # Displays the network widget with binary prefixes.
@@ -495,6 +544,9 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #network_use_log = false
 # Hides advanced options to stop a process on Unix-like systems.
 #disable_advanced_kill = false
+# Where procfs and sysfs are mounted, such as to monitor a host from a container.  Linux only.
+#proc_root = "/proc"
+#sys_root = "/sys"
 
 # These are all the components that support custom theming.  Note that colour support
 # will depend on terminal support.
@@ -594,6 +646,47 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #regex = true
 #case_sensitive = false
 #whole_word = false
+
+# Extra process columns - these are disabled by default, and are currently only available on Linux.
+# "pss", "uss", "shared", and "swap" are read from /proc/<PID>/smaps_rollup, while "fds", "fd%",
+# "sockets", "pipes", and "files" are read from /proc/<PID>/fd and /proc/<PID>/limits:
+#[processes]
+#extra_columns = ["pss", "uss", "shared", "swap", "fds", "fd%", "sockets", "pipes", "files"]
+
+# Temperature graph - which sensors to draw (using the same format as the filters above), and temperatures
+# to draw threshold lines at, in the configured temperature unit:
+#[temp_graph]
+#thresholds = [80.0, 95.0]
+#[temp_graph.sensor_filter]
+#is_list_ignored = false
+#list = ["Tctl", "Package id 0"]
+#regex = false
+#case_sensitive = false
+#whole_word = false
+
+# How often each data source is collected in milliseconds, if not at the refresh rate.  Sources are
+# "cpu", "memory", "network", "processes", "temperature", "disk", and "battery":
+#[collection_intervals]
+#cpu = 500
+#processes = 2000
+#temperature = 5000
+#disk = 10000
+
+# Alerts - rules checked after every update, which can highlight the widgets showing their data, ring
+# the terminal bell, append to a log, or run a command:
+#[[alerts]]
+#name = "CPU busy"
+#rule = "cpu.avg > 90 for 30s"
+#bell = true
+#log = "/tmp/bottom-alerts.log"
+#command = "notify-send \"$BTM_ALERT_NAME\" \"$BTM_ALERT_STATE\""
+
+# Watches - process queries to watch from startup, reporting when matching processes appear or exit,
+# or start or stop matching.  More can be added with w in a process widget:
+#[watches]
+#queries = ["ffmpeg and cpu > 80"]
+#log = "/tmp/bottom-watches.log"
+#command = "notify-send \"$BTM_WATCH_EVENT\" \"$BTM_WATCH_NAME ($BTM_WATCH_PID)\""
 "##;
 
 pub const CONFIG_TOP_HEAD: &str = r##"# This is bottom's config file.
@@ -1,11 +1,18 @@
 //! This mainly concerns converting collected data into things that the canvas
 //! can actually handle.
-use crate::{app::AxisScaling, units::data_units::DataUnit, Pid};
 use crate::{
     app::{data_farmer, data_harvester, App, ProcWidgetState},
-    utils::{self, gen_util::*},
+    utils::{self, error::BottomError, gen_util::*},
 };
-use data_harvester::processes::ProcessSorting;
+use crate::{
+    app::{AxisScaling, Filter},
+    constants::EXITED_PROCESS_TIMEOUT_MILLISECONDS,
+    units::data_units::DataUnit,
+    Pid,
+};
+use data_harvester::processes::{FdBreakdown, KernelStatRates, MemBreakdown, ProcessSorting};
+use data_harvester::sensors::SensorKind;
+use data_harvester::temperature::{get_heat_level, HeatLevel, TemperatureType};
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
 use std::collections::{HashMap, VecDeque};
@@ -21,6 +28,14 @@ pub struct ConvertedBatteryData {
     pub duration_until_full: Option<String>,
     pub duration_until_empty: Option<String>,
     pub health: String,
+    pub state: String,
+    pub voltage: String,
+    pub energy: String,
+    pub cycle_count: String,
+    /// The battery's vendor and model, if it reports either.
+    pub model: Option<String>,
+    pub charge_points: Vec<Point>,
+    pub power_points: Vec<Point>,
 }
 
 #[derive(Default, Debug)]
@@ -64,6 +79,10 @@ pub struct ConvertedProcessData {
     pub process_state: String,
     pub process_char: char,
     pub user: Option<String>,
+    pub exe_path: Option<String>,
+    pub cgroup: Option<String>,
+    pub mem_breakdown: Option<MemBreakdown>,
+    pub fds: Option<FdBreakdown>,
 
     /// Prefix printed before the process when displayed.
     pub process_description_prefix: Option<String>,
@@ -71,6 +90,37 @@ pub struct ConvertedProcessData {
     pub is_disabled_entry: bool,
     /// Whether this entry is collapsed, hiding all its children (for tree mode).
     pub is_collapsed_entry: bool,
+    /// Whether this process has exited, and is only being shown with its last known stats.
+    pub is_exited: bool,
+}
+
+impl ConvertedProcessData {
+    /// Returns the value of a memory breakdown column (PSS, USS, shared, or swap), if available.
+    pub fn get_mem_breakdown_bytes(&self, column: &ProcessSorting) -> Option<u64> {
+        self.mem_breakdown.and_then(|mem_breakdown| match column {
+            ProcessSorting::Pss => Some(mem_breakdown.pss_bytes),
+            ProcessSorting::Uss => Some(mem_breakdown.uss_bytes),
+            ProcessSorting::SharedMem => Some(mem_breakdown.shared_bytes),
+            ProcessSorting::Swap => Some(mem_breakdown.swap_bytes),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of a file descriptor count column, if available.
+    pub fn get_fd_count(&self, column: &ProcessSorting) -> Option<u64> {
+        self.fds.and_then(|fds| match column {
+            ProcessSorting::Fds => Some(fds.total),
+            ProcessSorting::SocketFds => Some(fds.sockets),
+            ProcessSorting::PipeFds => Some(fds.pipes),
+            ProcessSorting::FileFds => Some(fds.files),
+            _ => None,
+        })
+    }
+
+    /// Returns the percentage of the file descriptor soft limit in use, if available.
+    pub fn get_fd_limit_percent(&self) -> Option<f64> {
+        self.fds.and_then(|fds| fds.limit_percent)
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -81,35 +131,265 @@ pub struct ConvertedCpuData {
     pub cpu_data: Vec<Point>,
     /// Represents the value displayed on the legend.
     pub legend_value: String,
+    /// The current frequency displayed on the legend, if known.
+    pub freq_value: Option<String>,
 }
 
-pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
+/// Returns the time that graphs are drawn up to: when the data was frozen, if it is, or else
+/// the latest harvest.
+fn get_current_time(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> std::time::Instant {
+    if is_frozen {
+        current_data
+            .frozen_instant
+            .unwrap_or(current_data.current_instant)
+    } else {
+        current_data.current_instant
+    }
+}
+
+pub fn convert_temp_row(app: &App) -> Vec<(Vec<String>, HeatLevel)> {
     let current_data = &app.data_collection;
     let temp_type = &app.app_config_fields.temperature_type;
 
-    let mut sensor_vector: Vec<Vec<String>> = current_data
+    let mut sensor_vector: Vec<(Vec<String>, HeatLevel)> = current_data
         .temp_harvest
         .iter()
         .map(|temp_harvest| {
-            vec![
-                temp_harvest.name.clone(),
-                (temp_harvest.temperature.ceil() as u64).to_string()
-                    + match temp_type {
-                        data_harvester::temperature::TemperatureType::Celsius => "°C",
-                        data_harvester::temperature::TemperatureType::Kelvin => "K",
-                        data_harvester::temperature::TemperatureType::Fahrenheit => "°F",
-                    },
-            ]
+            (
+                vec![
+                    temp_harvest.name.clone(),
+                    (temp_harvest.temperature.ceil() as u64).to_string()
+                        + get_temperature_unit(temp_type),
+                ],
+                get_heat_level(
+                    temp_harvest.temperature.into(),
+                    temp_harvest.max.map(f64::from),
+                    temp_harvest.crit.map(f64::from),
+                    temp_type,
+                ),
+            )
+        })
+        .collect();
+
+    if sensor_vector.is_empty() {
+        // Say why there's nothing to show, as that could be a failure rather than no sensors.
+        let message = if current_data
+            .failing_sources
+            .contains(&data_harvester::DataSource::Temperature)
+        {
+            "Could Not Read Sensors"
+        } else if current_data.temperatures_filtered_out > 0 {
+            "All Sensors Filtered Out"
+        } else {
+            "No Sensors Found"
+        };
+
+        sensor_vector.push((vec![message.to_string(), "".to_string()], HeatLevel::Normal));
+    }
+
+    sensor_vector
+}
+
+fn get_temperature_unit(temp_type: &TemperatureType) -> &'static str {
+    match temp_type {
+        TemperatureType::Celsius => "°C",
+        TemperatureType::Kelvin => "K",
+        TemperatureType::Fahrenheit => "°F",
+    }
+}
+
+/// Converts hwmon sensors into table rows of chip, label, type, value, max, and crit.
+/// Temperatures are converted into the user's temperature unit, and rated by how hot they are.
+pub fn convert_sensor_row(app: &App) -> Vec<(Vec<String>, HeatLevel)> {
+    let temp_type = &app.app_config_fields.temperature_type;
+
+    let mut sensor_vector: Vec<(Vec<String>, HeatLevel)> = app
+        .data_collection
+        .sensor_harvest
+        .iter()
+        .map(|sensor| {
+            let (value, max, crit) = if sensor.kind == SensorKind::Temperature {
+                (
+                    temp_type.convert_from_celsius(sensor.value),
+                    sensor.max.map(|max| temp_type.convert_from_celsius(max)),
+                    sensor.crit.map(|crit| temp_type.convert_from_celsius(crit)),
+                )
+            } else {
+                (sensor.value, sensor.max, sensor.crit)
+            };
+
+            let format_value = |value: f64| match sensor.kind {
+                SensorKind::Temperature => {
+                    format!("{:.0}{}", value.ceil(), get_temperature_unit(temp_type))
+                }
+                SensorKind::Fan => format!("{:.0} RPM", value),
+                SensorKind::Voltage => format!("{:.3} V", value),
+                SensorKind::Power => format!("{:.1} W", value),
+            };
+
+            let heat_level = if sensor.kind == SensorKind::Temperature {
+                get_heat_level(value, max, crit, temp_type)
+            } else {
+                HeatLevel::Normal
+            };
+
+            (
+                vec![
+                    sensor.chip.clone(),
+                    sensor.label.clone(),
+                    sensor.kind.get_name().to_string(),
+                    format_value(value),
+                    max.map(format_value).unwrap_or_else(|| "N/A".to_string()),
+                    crit.map(format_value).unwrap_or_else(|| "N/A".to_string()),
+                ],
+                heat_level,
+            )
         })
         .collect();
 
     if sensor_vector.is_empty() {
-        sensor_vector.push(vec!["No Sensors Found".to_string(), "".to_string()]);
+        sensor_vector.push((
+            vec![
+                "No Sensors Found".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+            ],
+            HeatLevel::Normal,
+        ));
     }
 
     sensor_vector
 }
 
+/// Formats a link speed in Mb/s, switching to Gb/s at 1000 Mb/s.
+pub fn format_link_speed(mbps: u64) -> String {
+    if mbps >= 1000 {
+        let gbps = format!("{:.1}", mbps as f64 / 1000.0);
+        format!("{}Gb/s", gbps.trim_end_matches(".0"))
+    } else {
+        format!("{}Mb/s", mbps)
+    }
+}
+
+/// Converts the interface details into table rows, each paired with whether its error or drop
+/// counters rose since the previous harvest.
+pub fn convert_net_interface_row(app: &App) -> Vec<(Vec<String>, bool)> {
+    let format_count = |count: u64| {
+        let (value, prefix) = get_decimal_prefix(count, "");
+        if prefix.is_empty() {
+            format!("{:.0}", value)
+        } else {
+            format!("{:.1}{}", value, prefix)
+        }
+    };
+
+    let mut interface_vector: Vec<(Vec<String>, bool)> = app
+        .data_collection
+        .interface_harvest
+        .iter()
+        .map(|interface| {
+            let counters = &interface.counters;
+            (
+                vec![
+                    interface.name.clone(),
+                    interface.operstate.clone(),
+                    interface
+                        .addresses
+                        .iter()
+                        .map(|address| address.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    interface
+                        .mtu
+                        .map(|mtu| mtu.to_string())
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    interface
+                        .speed_mbps
+                        .map(format_link_speed)
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    format_count(counters.rx_packets),
+                    format_count(counters.tx_packets),
+                    format!("{}/{}", counters.rx_errors, counters.tx_errors),
+                    format!("{}/{}", counters.rx_drops, counters.tx_drops),
+                ],
+                app.data_collection
+                    .interfaces_with_new_drops
+                    .contains(&interface.name),
+            )
+        })
+        .collect();
+
+    if interface_vector.is_empty() {
+        let mut row = vec!["No Interfaces Found".to_string()];
+        row.resize(9, String::new());
+        interface_vector.push((row, false));
+    }
+
+    interface_vector
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedTempData {
+    pub name: String,
+    /// Tuple is time, value
+    pub points: Vec<Point>,
+    /// Represents the value displayed on the legend.
+    pub legend_value: String,
+}
+
+/// Converts the readings of each temperature sensor that passes `sensor_filter` into graph
+/// points.
+pub fn convert_temp_graph_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool, sensor_filter: &Option<Filter>,
+) -> Vec<ConvertedTempData> {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let is_sensor_shown = |name: &str| {
+        if let Some(sensor_filter) = sensor_filter {
+            sensor_filter.list.iter().any(|r| r.is_match(name)) != sensor_filter.is_list_ignored
+        } else {
+            true
+        }
+    };
+
+    let mut result: Vec<ConvertedTempData> = Vec::new();
+    for sensor in &current_data.temp_harvest {
+        if is_sensor_shown(&sensor.name)
+            && result.iter().all(|converted| converted.name != sensor.name)
+        {
+            result.push(ConvertedTempData {
+                name: sensor.name.clone(),
+                points: Vec::new(),
+                legend_value: format!("{:.0}", sensor.temperature.ceil()),
+            });
+        }
+    }
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for converted in &mut result {
+            if let Some((_, temperature)) = data
+                .temp_data
+                .iter()
+                .find(|(name, _)| *name == converted.name)
+            {
+                converted.points.push((-time_from_start, *temperature));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
 pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
     let mut disk_vector: Vec<Vec<String>> = Vec::new();
 
@@ -134,7 +414,9 @@ pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<S
                 "N/A".to_string()
             };
 
-            let usage_fmt = if let (Some(used_space), Some(total_space)) =
+            let usage_fmt = if disk.is_hung {
+                "Hung".to_string()
+            } else if let (Some(used_space), Some(total_space)) =
                 (disk.used_space, disk.total_space)
             {
                 format!("{:.0}%", used_space as f64 / total_space as f64 * 100_f64)
@@ -142,6 +424,23 @@ pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<S
                 "N/A".to_string()
             };
 
+            let (iops_fmt, await_fmt, queue_fmt, util_fmt) =
+                if let Some(stat_rates) = current_data.io_stat_rates.get(&disk.name) {
+                    (
+                        format!("{:.0}", stat_rates.iops),
+                        format!("{:.1}ms", stat_rates.await_ms),
+                        format!("{:.1}", stat_rates.queue_size),
+                        format!("{:.0}%", stat_rates.util_percent),
+                    )
+                } else {
+                    (
+                        "N/A".to_string(),
+                        "N/A".to_string(),
+                        "N/A".to_string(),
+                        "N/A".to_string(),
+                    )
+                };
+
             disk_vector.push(vec![
                 disk.name.to_string(),
                 disk.mount_point.to_string(),
@@ -150,6 +449,10 @@ pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<S
                 total_space_fmt,
                 io_read.to_string(),
                 io_write.to_string(),
+                iops_fmt,
+                await_fmt,
+                queue_fmt,
+                util_fmt,
             ]);
         });
 
@@ -160,28 +463,133 @@ pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<S
     disk_vector
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedDiskUtilData {
+    pub name: String,
+    /// Tuple is time, value
+    pub points: Vec<Point>,
+    /// Represents the value displayed on the legend.
+    pub legend_value: String,
+}
+
+/// Converts the utilization of each disk into graph points, with one entry per disk that
+/// reports utilization.
+pub fn convert_disk_util_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<ConvertedDiskUtilData> {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let mut result: Vec<ConvertedDiskUtilData> = Vec::new();
+    for disk in &current_data.disk_harvest {
+        // The same disk may be mounted in multiple places.
+        if let Some(stat_rates) = current_data.io_stat_rates.get(&disk.name) {
+            if result.iter().all(|converted| converted.name != disk.name) {
+                result.push(ConvertedDiskUtilData {
+                    name: disk.name.clone(),
+                    points: Vec::new(),
+                    legend_value: format!("{:3.0}%", stat_rates.util_percent),
+                });
+            }
+        }
+    }
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for converted in &mut result {
+            if let Some((_, util)) = data
+                .disk_util_data
+                .iter()
+                .find(|(name, _)| *name == converted.name)
+            {
+                converted.points.push((-time_from_start, *util));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedDiskIoData {
+    pub name: String,
+    /// Tuple is time, value
+    pub read_points: Vec<Point>,
+    pub write_points: Vec<Point>,
+    /// Represents the values displayed on the legend.
+    pub read_legend_value: String,
+    pub write_legend_value: String,
+}
+
+/// Converts the read and write rates of each disk into graph points, with one entry per disk
+/// that reports I/O.
+pub fn convert_disk_io_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<ConvertedDiskIoData> {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let mut result: Vec<ConvertedDiskIoData> = Vec::new();
+    for (disk, (io_read, io_write)) in current_data
+        .disk_harvest
+        .iter()
+        .zip(&current_data.io_labels)
+    {
+        // Disks without I/O are labelled as N/A, and the same disk may be mounted in multiple
+        // places.
+        if io_read != "N/A" && result.iter().all(|converted| converted.name != disk.name) {
+            result.push(ConvertedDiskIoData {
+                name: disk.name.clone(),
+                read_legend_value: io_read.clone(),
+                write_legend_value: io_write.clone(),
+                ..ConvertedDiskIoData::default()
+            });
+        }
+    }
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for converted in &mut result {
+            if let Some((_, read, write)) = data
+                .disk_io_data
+                .iter()
+                .find(|(name, _, _)| *name == converted.name)
+            {
+                converted.read_points.push((-time_from_start, *read));
+                converted.write_points.push((-time_from_start, *write));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
 pub fn convert_cpu_data_points(
     current_data: &data_farmer::DataCollection, existing_cpu_data: &mut Vec<ConvertedCpuData>,
     is_frozen: bool,
 ) {
-    let current_time = if is_frozen {
-        if let Some(frozen_instant) = current_data.frozen_instant {
-            frozen_instant
-        } else {
-            current_data.current_instant
-        }
-    } else {
-        current_data.current_instant
-    };
+    let current_time = get_current_time(current_data, is_frozen);
 
     // Initialize cpu_data_vector if the lengths don't match...
-    if let Some((_time, data)) = &current_data.timed_data_vec.last() {
+    if let Some((_time, data)) = current_data
+        .timed_data_vec
+        .iter()
+        .rev()
+        .find(|(_time, data)| !data.cpu_data.is_empty())
+    {
         if data.cpu_data.len() + 1 != existing_cpu_data.len() {
             *existing_cpu_data = vec![ConvertedCpuData {
                 cpu_name: "All".to_string(),
                 short_cpu_name: "All".to_string(),
                 cpu_data: vec![],
                 legend_value: String::new(),
+                freq_value: None,
             }];
 
             existing_cpu_data.extend(
@@ -209,6 +617,7 @@ pub fn convert_cpu_data_points(
                             String::default()
                         },
                         legend_value: format!("{:.0}%", cpu_usage.round()),
+                        freq_value: None,
                         cpu_data: vec![],
                     })
                     .collect::<Vec<ConvertedCpuData>>(),
@@ -225,6 +634,26 @@ pub fn convert_cpu_data_points(
         }
     }
 
+    // Frequencies are matched by core number; the average CPU gets the mean of all cores.
+    let freq_harvest = &current_data.cpu_freq_harvest;
+    existing_cpu_data
+        .iter_mut()
+        .skip(1)
+        .zip(&current_data.cpu_harvest)
+        .for_each(|(cpu, cpu_harvest)| {
+            cpu.freq_value = match cpu_harvest.cpu_count {
+                Some(cpu_count) => freq_harvest
+                    .iter()
+                    .find(|freq| freq.cpu == cpu_count)
+                    .map(|freq| format_frequency(freq.current)),
+                None if !freq_harvest.is_empty() => Some(format_frequency(
+                    freq_harvest.iter().map(|freq| freq.current).sum::<f64>()
+                        / freq_harvest.len() as f64,
+                )),
+                None => None,
+            };
+        });
+
     for (time, data) in &current_data.timed_data_vec {
         let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
 
@@ -240,20 +669,145 @@ pub fn convert_cpu_data_points(
     }
 }
 
-pub fn convert_mem_data_points(
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedLoadAvgData {
+    /// The 1, 5, and 15 minute load averages.  Tuple is time, value
+    pub points: [Vec<Point>; 3],
+    /// The number of cores, if known.
+    pub core_count: Option<usize>,
+}
+
+/// Converts the 1, 5, and 15 minute load averages into graph points.
+pub fn convert_load_avg_data(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
-) -> Vec<Point> {
-    let mut result: Vec<Point> = Vec::new();
-    let current_time = if is_frozen {
-        if let Some(frozen_instant) = current_data.frozen_instant {
-            frozen_instant
+) -> ConvertedLoadAvgData {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let mut result = ConvertedLoadAvgData::default();
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        if let Some(load_avg_data) = data.load_avg_data {
+            for (points, load_avg) in result.points.iter_mut().zip(load_avg_data) {
+                points.push((-time_from_start, load_avg.into()));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    let core_count = current_data
+        .cpu_harvest
+        .iter()
+        .filter(|cpu| cpu.cpu_count.is_some())
+        .count();
+    if core_count > 0 {
+        result.core_count = Some(core_count);
+    }
+
+    result
+}
+
+/// Formats kernel activity rates for the CPU widget's title, like
+/// `2.3K ctx/s 512 int/s 4 fork/s 2 run 0 blk`.
+pub fn format_kernel_stat_rates(rates: &KernelStatRates) -> String {
+    let format_rate = |rate: f64| {
+        let (value, prefix) = get_decimal_prefix(rate.round() as u64, "");
+        if prefix.is_empty() {
+            format!("{:.0}", value)
         } else {
-            current_data.current_instant
+            format!("{:.1}{}", value, prefix)
         }
-    } else {
-        current_data.current_instant
     };
 
+    format!(
+        "{} ctx/s {} int/s {} fork/s {} run {} blk",
+        format_rate(rates.context_switches),
+        format_rate(rates.interrupts),
+        format_rate(rates.forks),
+        rates.procs_running,
+        rates.procs_blocked
+    )
+}
+
+/// Formats a frequency in MHz, switching to GHz at 1000 MHz.
+pub fn format_frequency(mhz: f64) -> String {
+    if mhz.round() >= 1000.0 {
+        format!("{:.2}GHz", mhz / 1000.0)
+    } else {
+        format!("{:.0}MHz", mhz)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedCpuFreqData {
+    pub name: String,
+    /// Tuple is time, value
+    pub points: Vec<Point>,
+    /// Represents the value displayed on the legend.
+    pub legend_value: String,
+    /// The highest frequency the core can run at, if known.
+    pub max: Option<f64>,
+}
+
+/// Returns the legend for a core: its current frequency, followed by the range it can run in
+/// if known.
+fn get_cpu_freq_legend(freq: &data_harvester::cpu::CpuFreqData) -> String {
+    let current = format_frequency(freq.current);
+    match (freq.min, freq.max) {
+        (Some(min), Some(max)) => format!(
+            "{} / {}-{}",
+            current,
+            format_frequency(min),
+            format_frequency(max)
+        ),
+        (None, Some(max)) => format!("{} / {}", current, format_frequency(max)),
+        (Some(min), None) => format!("{} / {}-", current, format_frequency(min)),
+        (None, None) => current,
+    }
+}
+
+/// Converts the frequency of each core into graph points, in MHz.
+pub fn convert_cpu_freq_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<ConvertedCpuFreqData> {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let mut result: Vec<ConvertedCpuFreqData> = current_data
+        .cpu_freq_harvest
+        .iter()
+        .map(|freq| ConvertedCpuFreqData {
+            name: format!("CPU{}", freq.cpu),
+            points: Vec::new(),
+            legend_value: get_cpu_freq_legend(freq),
+            max: freq.max,
+        })
+        .collect();
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for (converted, freq) in result.iter_mut().zip(&current_data.cpu_freq_harvest) {
+            if let Some((_, current)) = data.cpu_freq_data.iter().find(|(cpu, _)| *cpu == freq.cpu)
+            {
+                converted.points.push((-time_from_start, *current));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
+pub fn convert_mem_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<Point> {
+    let mut result: Vec<Point> = Vec::new();
+    let current_time = get_current_time(current_data, is_frozen);
+
     for (time, data) in &current_data.timed_data_vec {
         if let Some(mem_data) = data.mem_data {
             let time_from_start: f64 =
@@ -272,15 +826,7 @@ pub fn convert_swap_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
 ) -> Vec<Point> {
     let mut result: Vec<Point> = Vec::new();
-    let current_time = if is_frozen {
-        if let Some(frozen_instant) = current_data.frozen_instant {
-            frozen_instant
-        } else {
-            current_data.current_instant
-        }
-    } else {
-        current_data.current_instant
-    };
+    let current_time = get_current_time(current_data, is_frozen);
 
     for (time, data) in &current_data.timed_data_vec {
         if let Some(swap_data) = data.swap_data {
@@ -366,6 +912,79 @@ pub fn convert_mem_labels(
     )
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedPressureData {
+    pub name: &'static str,
+    /// Tuple is time, value
+    pub points: Vec<Point>,
+    /// Represents the value displayed on the legend.
+    pub legend_value: String,
+}
+
+/// Formats a total stall time in microseconds, using the largest unit that keeps the value
+/// above one.
+fn format_stall_time(total_us: u64) -> String {
+    let seconds = total_us as f64 / 1_000_000.0;
+    if seconds < 60.0 {
+        format!("{:.1}s", seconds)
+    } else if seconds < 3600.0 {
+        format!("{:.1}m", seconds / 60.0)
+    } else if seconds < 86400.0 {
+        format!("{:.1}h", seconds / 3600.0)
+    } else {
+        format!("{:.1}d", seconds / 86400.0)
+    }
+}
+
+/// Converts the "some" 10 second averages of CPU, memory, and I/O pressure into graph points.
+/// Resources the kernel doesn't report pressure for are skipped.
+pub fn convert_pressure_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<ConvertedPressureData> {
+    type PressureGetter = fn(&data_farmer::TimedData) -> Option<f64>;
+
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let harvest = &current_data.pressure_harvest;
+    let resources: [(&'static str, _, PressureGetter); 3] = [
+        ("CPU", harvest.cpu, |data| data.cpu_pressure_data),
+        ("MEM", harvest.memory, |data| data.mem_pressure_data),
+        ("IO", harvest.io, |data| data.io_pressure_data),
+    ];
+
+    resources
+        .iter()
+        .filter_map(|(name, pressure, get_value)| {
+            let pressure = (*pressure)?;
+
+            let mut points: Vec<Point> = Vec::new();
+            for (time, data) in &current_data.timed_data_vec {
+                if let Some(value) = get_value(data) {
+                    let time_from_start: f64 =
+                        (current_time.duration_since(*time).as_millis() as f64).floor();
+                    points.push((-time_from_start, value));
+                    if *time == current_time {
+                        break;
+                    }
+                }
+            }
+
+            let legend_value = format!(
+                "{:5.1}%  60s:{:5.1}%  total: {}",
+                pressure.some.avg10,
+                pressure.some.avg60,
+                format_stall_time(pressure.some.total)
+            );
+
+            Some(ConvertedPressureData {
+                name,
+                points,
+                legend_value,
+            })
+        })
+        .collect()
+}
+
 pub fn get_rx_tx_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool, network_scale_type: &AxisScaling,
     network_unit_type: &DataUnit, network_use_binary_prefix: bool,
@@ -373,46 +992,39 @@ pub fn get_rx_tx_data_points(
     let mut rx: Vec<Point> = Vec::new();
     let mut tx: Vec<Point> = Vec::new();
 
-    let current_time = if is_frozen {
-        if let Some(frozen_instant) = current_data.frozen_instant {
-            frozen_instant
-        } else {
-            current_data.current_instant
-        }
-    } else {
-        current_data.current_instant
-    };
+    let current_time = get_current_time(current_data, is_frozen);
 
     for (time, data) in &current_data.timed_data_vec {
         let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
 
-        let (rx_data, tx_data) = match network_scale_type {
-            AxisScaling::Log => {
-                if network_use_binary_prefix {
-                    match network_unit_type {
-                        DataUnit::Byte => {
-                            // As dividing by 8 is equal to subtracting 4 in base 2!
-                            ((data.rx_data).log2() - 4.0, (data.tx_data).log2() - 4.0)
+        if let (Some(rx_data), Some(tx_data)) = (data.rx_data, data.tx_data) {
+            let (rx_data, tx_data) = match network_scale_type {
+                AxisScaling::Log => {
+                    if network_use_binary_prefix {
+                        match network_unit_type {
+                            DataUnit::Byte => {
+                                // As dividing by 8 is equal to subtracting 4 in base 2!
+                                (rx_data.log2() - 4.0, tx_data.log2() - 4.0)
+                            }
+                            DataUnit::Bit => (rx_data.log2(), tx_data.log2()),
                         }
-                        DataUnit::Bit => ((data.rx_data).log2(), (data.tx_data).log2()),
-                    }
-                } else {
-                    match network_unit_type {
-                        DataUnit::Byte => {
-                            ((data.rx_data / 8.0).log10(), (data.tx_data / 8.0).log10())
+                    } else {
+                        match network_unit_type {
+                            DataUnit::Byte => ((rx_data / 8.0).log10(), (tx_data / 8.0).log10()),
+                            DataUnit::Bit => (rx_data.log10(), tx_data.log10()),
                         }
-                        DataUnit::Bit => ((data.rx_data).log10(), (data.tx_data).log10()),
                     }
                 }
-            }
-            AxisScaling::Linear => match network_unit_type {
-                DataUnit::Byte => (data.rx_data / 8.0, data.tx_data / 8.0),
-                DataUnit::Bit => (data.rx_data, data.tx_data),
-            },
-        };
+                AxisScaling::Linear => match network_unit_type {
+                    DataUnit::Byte => (rx_data / 8.0, tx_data / 8.0),
+                    DataUnit::Bit => (rx_data, tx_data),
+                },
+            };
+
+            rx.push((-time_from_start, rx_data));
+            tx.push((-time_from_start, tx_data));
+        }
 
-        rx.push((-time_from_start, rx_data));
-        tx.push((-time_from_start, tx_data));
         if *time == current_time {
             break;
         }
@@ -550,9 +1162,57 @@ pub fn convert_network_data_points(
     }
 }
 
+/// What processes are grouped together by when in grouped mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProcessGroupingType {
-    Grouped,
-    Ungrouped,
+    /// Group by process name, or command if showing commands.
+    Name,
+    User,
+    Parent,
+    ExePath,
+    Cgroup,
+}
+
+impl ProcessGroupingType {
+    /// Returns the next grouping type to cycle to.
+    pub fn next(&self) -> Self {
+        match self {
+            ProcessGroupingType::Name => ProcessGroupingType::User,
+            ProcessGroupingType::User => ProcessGroupingType::Parent,
+            ProcessGroupingType::Parent => ProcessGroupingType::ExePath,
+            ProcessGroupingType::ExePath => ProcessGroupingType::Cgroup,
+            ProcessGroupingType::Cgroup => ProcessGroupingType::Name,
+        }
+    }
+
+    /// Returns a short, human-readable description of what is being grouped by.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ProcessGroupingType::Name => "name",
+            ProcessGroupingType::User => "user",
+            ProcessGroupingType::Parent => "parent",
+            ProcessGroupingType::ExePath => "executable path",
+            ProcessGroupingType::Cgroup => "cgroup",
+        }
+    }
+}
+
+impl std::str::FromStr for ProcessGroupingType {
+    type Err = BottomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "name" => Ok(ProcessGroupingType::Name),
+            "user" => Ok(ProcessGroupingType::User),
+            "parent" => Ok(ProcessGroupingType::Parent),
+            "path" | "exe" => Ok(ProcessGroupingType::ExePath),
+            "cgroup" => Ok(ProcessGroupingType::Cgroup),
+            _ => Err(BottomError::ConfigError(format!(
+                "\"{}\" is an invalid grouping type, use \"<name|user|parent|path|cgroup>\".",
+                s
+            ))),
+        }
+    }
 }
 
 pub enum ProcessNamingType {
@@ -600,11 +1260,11 @@ fn get_disk_io_strings(
     )
 }
 
-/// Because we needed to UPDATE data entries rather than REPLACING entries, we instead update
-/// the existing vector.
+/// Converts the latest process harvest.  If `show_exited` is set, processes that exited recently
+/// are kept with their last known stats.
 pub fn convert_process_data(
     current_data: &data_farmer::DataCollection,
-    existing_converted_process_data: &mut HashMap<Pid, ConvertedProcessData>,
+    existing_converted_process_data: &mut HashMap<Pid, ConvertedProcessData>, show_exited: bool,
     #[cfg(target_family = "unix")] user_table: &mut data_harvester::processes::UserTable,
 ) {
     // TODO [THREAD]: Thread highlighting and hiding support
@@ -613,7 +1273,42 @@ pub fn convert_process_data(
     let mut complete_pid_set: fxhash::FxHashSet<Pid> =
         existing_converted_process_data.keys().copied().collect();
 
-    for process in &current_data.process_harvest {
+    let exited_processes = if show_exited {
+        let running_pids: fxhash::FxHashSet<Pid> = current_data
+            .process_harvest
+            .iter()
+            .map(|process| process.pid)
+            .collect();
+
+        current_data
+            .exited_processes
+            .iter()
+            .filter(|exited| {
+                !running_pids.contains(&exited.process.pid)
+                    && current_data
+                        .current_instant
+                        .duration_since(exited.instant)
+                        .as_millis()
+                        < EXITED_PROCESS_TIMEOUT_MILLISECONDS.into()
+            })
+            .map(|exited| &exited.process)
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    let processes = current_data
+        .process_harvest
+        .iter()
+        .map(|process| (process, false))
+        .chain(exited_processes.into_iter().map(|process| (process, true)));
+
+    for (process, is_exited) in processes {
+        let process_state = if is_exited {
+            "Exited".to_string()
+        } else {
+            process.process_state.to_owned()
+        };
         let (read_per_sec, write_per_sec, total_read, total_write) = get_disk_io_strings(
             process.read_bytes_per_sec,
             process.write_bytes_per_sec,
@@ -658,11 +1353,16 @@ pub fn convert_process_data(
                 process_entry.wps_f64 = process.write_bytes_per_sec as f64;
                 process_entry.tr_f64 = process.total_read_bytes as f64;
                 process_entry.tw_f64 = process.total_write_bytes as f64;
-                process_entry.process_state = process.process_state.to_owned();
+                process_entry.process_state = process_state;
                 process_entry.process_char = process.process_state_char;
                 process_entry.process_description_prefix = None;
                 process_entry.is_disabled_entry = false;
+                process_entry.is_exited = is_exited;
                 process_entry.user = user;
+                process_entry.exe_path = process.exe_path.clone();
+                process_entry.cgroup = process.cgroup.clone();
+                process_entry.mem_breakdown = process.mem_breakdown;
+                process_entry.fds = process.fds;
             } else {
                 // ...I hate that I can't combine if let and an if statement in one line...
                 *process_entry = ConvertedProcessData {
@@ -684,12 +1384,17 @@ pub fn convert_process_data(
                     wps_f64: process.write_bytes_per_sec as f64,
                     tr_f64: process.total_read_bytes as f64,
                     tw_f64: process.total_write_bytes as f64,
-                    process_state: process.process_state.to_owned(),
+                    process_state,
                     process_char: process.process_state_char,
                     process_description_prefix: None,
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
+                    is_exited,
                     user,
+                    exe_path: process.exe_path.clone(),
+                    cgroup: process.cgroup.clone(),
+                    mem_breakdown: process.mem_breakdown,
+                    fds: process.fds,
                 };
             }
         } else {
@@ -714,12 +1419,17 @@ pub fn convert_process_data(
                     wps_f64: process.write_bytes_per_sec as f64,
                     tr_f64: process.total_read_bytes as f64,
                     tw_f64: process.total_write_bytes as f64,
-                    process_state: process.process_state.to_owned(),
+                    process_state,
                     process_char: process.process_state_char,
                     process_description_prefix: None,
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
+                    is_exited,
                     user,
+                    exe_path: process.exe_path.clone(),
+                    cgroup: process.cgroup.clone(),
+                    mem_breakdown: process.mem_breakdown,
+                    fds: process.fds,
                 },
             );
         }
@@ -736,12 +1446,14 @@ const BRANCH_VERTICAL: char = '│';
 const BRANCH_SPLIT: char = '├';
 const BRANCH_HORIZONTAL: char = '─';
 
+/// Arranges the filtered process data into a tree, ordered as it should be displayed.
+///
+/// If `show_subtree_totals` is set, each entry's usage (and thus sorting) will include the
+/// usage of all of its (visible) descendants.
 pub fn tree_process_data(
     filtered_process_data: &[ConvertedProcessData], is_using_command: bool,
-    sorting_type: &ProcessSorting, is_sort_descending: bool,
+    sorting_type: &ProcessSorting, is_sort_descending: bool, show_subtree_totals: bool,
 ) -> Vec<ConvertedProcessData> {
-    // TODO: [TREE] Option to sort usage by total branch usage or individual value usage?
-
     // Let's first build up a (really terrible) parent -> child mapping...
     // At the same time, let's make a mapping of PID -> process data!
     let mut parent_child_mapping: HashMap<Pid, IndexSet<Pid, FxBuildHasher>> = HashMap::default();
@@ -939,6 +1651,39 @@ pub fn tree_process_data(
                     });
                 }
             }
+            ProcessSorting::Pss
+            | ProcessSorting::Uss
+            | ProcessSorting::SharedMem
+            | ProcessSorting::Swap => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.get_mem_breakdown_bytes(sort_type).unwrap_or(0),
+                        b.1.get_mem_breakdown_bytes(sort_type).unwrap_or(0),
+                        is_sort_descending,
+                    )
+                });
+            }
+            ProcessSorting::Fds
+            | ProcessSorting::SocketFds
+            | ProcessSorting::PipeFds
+            | ProcessSorting::FileFds => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.get_fd_count(sort_type).unwrap_or(0),
+                        b.1.get_fd_count(sort_type).unwrap_or(0),
+                        is_sort_descending,
+                    )
+                });
+            }
+            ProcessSorting::FdPercent => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.get_fd_limit_percent().unwrap_or(0.0),
+                        b.1.get_fd_limit_percent().unwrap_or(0.0),
+                        is_sort_descending,
+                    )
+                });
+            }
             ProcessSorting::ReadPerSecond => {
                 to_sort_vec.sort_by(|a, b| {
                     utils::gen_util::get_ordering(a.1.rps_f64, b.1.rps_f64, is_sort_descending)
@@ -1038,65 +1783,90 @@ pub fn tree_process_data(
         (explored_pids, lines)
     }
 
-    /// Returns the total sum of CPU, MEM%, MEM, R/s, W/s, Total Read, and Total Write via DFS traversal.
-    fn get_usage_of_all_children(
-        parent_pid: Pid, parent_child_mapping: &HashMap<Pid, IndexSet<Pid, FxBuildHasher>>,
+    /// Sums the usage of each process with that of all its descendants via a post-order DFS
+    /// traversal, storing the results in `totals`.  An exited process keeps its own last known
+    /// usage, but it isn't counted towards its ancestors' totals.  Returns the usage of the
+    /// subtree as counted towards its ancestors.
+    fn sum_subtree_usage(
+        current_pid: Pid, parent_child_mapping: &HashMap<Pid, IndexSet<Pid, FxBuildHasher>>,
         pid_process_mapping: &HashMap<Pid, &ConvertedProcessData>,
-    ) -> (f64, f64, u64, f64, f64, f64, f64) {
-        if let Some(&converted_process_data) = pid_process_mapping.get(&parent_pid) {
-            let (
-                mut cpu,
-                mut mem_percent,
-                mut mem,
-                mut rps,
-                mut wps,
-                mut total_read,
-                mut total_write,
-            ) = (
-                (converted_process_data.cpu_percent_usage * 10.0).round() / 10.0,
-                (converted_process_data.mem_percent_usage * 10.0).round() / 10.0,
-                converted_process_data.mem_usage_bytes,
-                (converted_process_data.rps_f64 * 10.0).round() / 10.0,
-                (converted_process_data.wps_f64 * 10.0).round() / 10.0,
-                (converted_process_data.tr_f64 * 10.0).round() / 10.0,
-                (converted_process_data.tw_f64 * 10.0).round() / 10.0,
-            );
+        totals: &mut HashMap<Pid, ConvertedProcessData>,
+    ) -> SubtreeUsage {
+        if let Some(&process) = pid_process_mapping.get(&current_pid) {
+            let mut total = process.clone();
+            let mut descendants_usage = SubtreeUsage::default();
 
-            if let Some(children) = parent_child_mapping.get(&parent_pid) {
+            if let Some(children) = parent_child_mapping.get(&current_pid) {
                 for &child_pid in children {
-                    let (
-                        child_cpu,
-                        child_mem_percent,
-                        child_mem,
-                        child_rps,
-                        child_wps,
-                        child_total_read,
-                        child_total_write,
-                    ) = get_usage_of_all_children(
+                    descendants_usage += sum_subtree_usage(
                         child_pid,
                         parent_child_mapping,
                         pid_process_mapping,
+                        totals,
                     );
-
-                    cpu += child_cpu;
-                    mem_percent += child_mem_percent;
-                    mem += child_mem;
-                    rps += child_rps;
-                    wps += child_wps;
-                    total_read += child_total_read;
-                    total_write += child_total_write;
                 }
+
+                let mut usage = SubtreeUsage::from_process(process);
+                usage += descendants_usage;
+                usage.apply_to(&mut total);
+
+                let disk_io_strings = get_disk_io_strings(
+                    total.rps_f64 as u64,
+                    total.wps_f64 as u64,
+                    total.tr_f64 as u64,
+                    total.tw_f64 as u64,
+                );
+
+                total.mem_usage_str = get_binary_bytes(total.mem_usage_bytes);
+                total.read_per_sec = disk_io_strings.0;
+                total.write_per_sec = disk_io_strings.1;
+                total.total_read = disk_io_strings.2;
+                total.total_write = disk_io_strings.3;
             }
 
-            (cpu, mem_percent, mem, rps, wps, total_read, total_write)
+            totals.insert(current_pid, total);
+
+            let mut usage = SubtreeUsage::from_descendant(process);
+            usage += descendants_usage;
+            usage
         } else {
-            (0.0_f64, 0.0_f64, 0, 0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64)
+            SubtreeUsage::default()
+        }
+    }
+
+    // Prune first, as the totals should only consist of what is actually visible.
+    let root_pids: Vec<Pid> = pids_to_explore
+        .into_iter()
+        .filter(|pid| {
+            pid_process_mapping.contains_key(pid)
+                && !prune_disabled_pids(*pid, &mut parent_child_mapping, &pid_process_mapping)
+        })
+        .collect();
+
+    // Collapsed entries show the totals of what they hide even when subtree totals are off.
+    let mut subtree_totals: HashMap<Pid, ConvertedProcessData> = HashMap::default();
+    if show_subtree_totals || !collapsed_set.is_empty() {
+        for pid in &root_pids {
+            sum_subtree_usage(
+                *pid,
+                &parent_child_mapping,
+                &pid_process_mapping,
+                &mut subtree_totals,
+            );
         }
     }
+    let process_mapping: HashMap<Pid, &ConvertedProcessData> = if show_subtree_totals {
+        subtree_totals
+            .iter()
+            .map(|(pid, process)| (*pid, process))
+            .collect()
+    } else {
+        pid_process_mapping.clone()
+    };
 
     let mut to_sort_vec = Vec::new();
-    for pid in pids_to_explore {
-        if let Some(process) = pid_process_mapping.get(&pid) {
+    for pid in root_pids {
+        if let Some(process) = process_mapping.get(&pid) {
             to_sort_vec.push((pid, *process));
         }
     }
@@ -1104,21 +1874,19 @@ pub fn tree_process_data(
     pids_to_explore = to_sort_vec.iter().map(|(pid, _proc)| *pid).collect();
 
     while let Some(current_pid) = pids_to_explore.pop_front() {
-        if !prune_disabled_pids(current_pid, &mut parent_child_mapping, &pid_process_mapping) {
-            sort_remaining_pids(
-                current_pid,
-                sorting_type,
-                is_sort_descending,
-                &mut parent_child_mapping,
-                &pid_process_mapping,
-            );
+        sort_remaining_pids(
+            current_pid,
+            sorting_type,
+            is_sort_descending,
+            &mut parent_child_mapping,
+            &process_mapping,
+        );
 
-            let (pid_res, branch_res) =
-                build_explored_pids(current_pid, &parent_child_mapping, "", &collapsed_set);
-            lines.push(String::default());
-            lines.extend(branch_res);
-            explored_pids.extend(pid_res);
-        }
+        let (pid_res, branch_res) =
+            build_explored_pids(current_pid, &parent_child_mapping, "", &collapsed_set);
+        lines.push(String::default());
+        lines.extend(branch_res);
+        explored_pids.extend(pid_res);
     }
 
     // Now let's "rearrange" our current list of converted process data into the correct
@@ -1126,9 +1894,17 @@ pub fn tree_process_data(
     explored_pids
         .iter()
         .zip(lines)
-        .filter_map(|(pid, prefix)| match pid_process_mapping.get(pid) {
+        .filter_map(|(pid, prefix)| match process_mapping.get(pid) {
             Some(process) => {
-                let mut p = (*process).clone();
+                // As part of https://github.com/ClementTsang/bottom/issues/424, also append their statistics to the parent if
+                // collapsed.
+                //
+                // Note that this will technically be "missing" entries, it collapses + sums based on what is visible
+                // since this runs *after* pruning steps.
+                let mut p = match subtree_totals.get(pid) {
+                    Some(total) if process.is_collapsed_entry => total.clone(),
+                    _ => (*process).clone(),
+                };
                 p.process_description_prefix = Some(format!(
                     "{}{}{}",
                     prefix,
@@ -1140,62 +1916,6 @@ pub fn tree_process_data(
                     }
                 ));
 
-                // As part of https://github.com/ClementTsang/bottom/issues/424, also append their statistics to the parent if
-                // collapsed.
-                //
-                // Note that this will technically be "missing" entries, it collapses + sums based on what is visible
-                // since this runs *after* pruning steps.
-                if p.is_collapsed_entry {
-                    if let Some(children) = parent_child_mapping.get(&p.pid) {
-                        // Do some rounding.
-                        p.cpu_percent_usage = (p.cpu_percent_usage * 10.0).round() / 10.0;
-                        p.mem_percent_usage = (p.mem_percent_usage * 10.0).round() / 10.0;
-                        p.rps_f64 = (p.rps_f64 * 10.0).round() / 10.0;
-                        p.wps_f64 = (p.wps_f64 * 10.0).round() / 10.0;
-                        p.tr_f64 = (p.tr_f64 * 10.0).round() / 10.0;
-                        p.tw_f64 = (p.tw_f64 * 10.0).round() / 10.0;
-
-                        for &child_pid in children {
-                            // Let's just do a simple DFS traversal...
-                            let (
-                                child_cpu,
-                                child_mem_percent,
-                                child_mem,
-                                child_rps,
-                                child_wps,
-                                child_total_read,
-                                child_total_write,
-                            ) = get_usage_of_all_children(
-                                child_pid,
-                                &parent_child_mapping,
-                                &pid_process_mapping,
-                            );
-
-                            p.cpu_percent_usage += child_cpu;
-                            p.mem_percent_usage += child_mem_percent;
-                            p.mem_usage_bytes += child_mem;
-                            p.rps_f64 += child_rps;
-                            p.wps_f64 += child_wps;
-                            p.tr_f64 += child_total_read;
-                            p.tw_f64 += child_total_write;
-                        }
-
-                        let disk_io_strings = get_disk_io_strings(
-                            p.rps_f64 as u64,
-                            p.wps_f64 as u64,
-                            p.tr_f64 as u64,
-                            p.tw_f64 as u64,
-                        );
-
-                        p.mem_usage_str = get_binary_bytes(p.mem_usage_bytes);
-
-                        p.read_per_sec = disk_io_strings.0;
-                        p.write_per_sec = disk_io_strings.1;
-                        p.total_read = disk_io_strings.2;
-                        p.total_write = disk_io_strings.3;
-                    }
-                }
-
                 Some(p)
             }
             None => None,
@@ -1210,77 +1930,236 @@ pub fn stringify_process_data(
     let is_proc_widget_grouped = proc_widget_state.is_grouped;
     let is_using_command = proc_widget_state.is_using_command;
     let is_tree = proc_widget_state.is_tree_mode;
-    let mem_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::Mem);
+    let enabled_columns = proc_widget_state.columns.get_enabled_columns();
 
     finalized_process_data
         .iter()
         .map(|process| {
             (
-                vec![
-                    (
-                        if is_proc_widget_grouped {
-                            process.group_pids.len().to_string()
-                        } else {
-                            process.pid.to_string()
-                        },
-                        None,
-                    ),
-                    (
-                        if is_tree {
-                            if let Some(prefix) = &process.process_description_prefix {
-                                prefix.clone()
+                enabled_columns
+                    .iter()
+                    .map(|column| match column {
+                        ProcessSorting::Count | ProcessSorting::Pid => (
+                            if is_proc_widget_grouped {
+                                process.group_pids.len().to_string()
                             } else {
-                                String::default()
-                            }
-                        } else if is_using_command {
-                            process.command.clone()
-                        } else {
-                            process.name.clone()
-                        },
-                        None,
-                    ),
-                    (format!("{:.1}%", process.cpu_percent_usage), None),
-                    (
-                        if mem_enabled {
-                            if process.mem_usage_bytes <= GIBI_LIMIT {
-                                format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
+                                process.pid.to_string()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::ProcessName | ProcessSorting::Command => (
+                            if is_tree {
+                                if let Some(prefix) = &process.process_description_prefix {
+                                    prefix.clone()
+                                } else {
+                                    String::default()
+                                }
+                            } else if is_using_command {
+                                process.command.clone()
                             } else {
-                                format!("{:.1}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                            }
-                        } else {
-                            format!("{:.1}%", process.mem_percent_usage)
-                        },
-                        None,
-                    ),
-                    (process.read_per_sec.clone(), None),
-                    (process.write_per_sec.clone(), None),
-                    (process.total_read.clone(), None),
-                    (process.total_write.clone(), None),
-                    #[cfg(target_family = "unix")]
-                    (
-                        if let Some(user) = &process.user {
-                            user.clone()
-                        } else {
-                            "N/A".to_string()
-                        },
-                        None,
-                    ),
-                    (
-                        process.process_state.clone(),
-                        Some(process.process_char.to_string()),
-                    ),
-                ],
-                process.is_disabled_entry,
+                                process.name.clone()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::CpuPercent => {
+                            (format!("{:.1}%", process.cpu_percent_usage), None)
+                        }
+                        ProcessSorting::Mem => (
+                            format_process_mem_bytes(
+                                process.mem_usage_bytes,
+                                process.mem_usage_str.clone(),
+                            ),
+                            None,
+                        ),
+                        ProcessSorting::MemPercent => {
+                            (format!("{:.1}%", process.mem_percent_usage), None)
+                        }
+                        ProcessSorting::Pss
+                        | ProcessSorting::Uss
+                        | ProcessSorting::SharedMem
+                        | ProcessSorting::Swap => (
+                            if let Some(bytes) = process.get_mem_breakdown_bytes(column) {
+                                format_process_mem_bytes(bytes, get_binary_bytes(bytes))
+                            } else {
+                                "N/A".to_string()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::Fds => (
+                            match process.fds {
+                                Some(FdBreakdown {
+                                    total,
+                                    soft_limit: Some(soft_limit),
+                                    ..
+                                }) => format!("{}/{}", total, soft_limit),
+                                Some(FdBreakdown { total, .. }) => total.to_string(),
+                                None => "N/A".to_string(),
+                            },
+                            None,
+                        ),
+                        ProcessSorting::FdPercent => (
+                            if let Some(limit_percent) = process.get_fd_limit_percent() {
+                                format!("{:.1}%", limit_percent)
+                            } else {
+                                "N/A".to_string()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::SocketFds
+                        | ProcessSorting::PipeFds
+                        | ProcessSorting::FileFds => (
+                            if let Some(count) = process.get_fd_count(column) {
+                                count.to_string()
+                            } else {
+                                "N/A".to_string()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::ReadPerSecond => (process.read_per_sec.clone(), None),
+                        ProcessSorting::WritePerSecond => (process.write_per_sec.clone(), None),
+                        ProcessSorting::TotalRead => (process.total_read.clone(), None),
+                        ProcessSorting::TotalWrite => (process.total_write.clone(), None),
+                        ProcessSorting::User => (
+                            if let Some(user) = &process.user {
+                                user.clone()
+                            } else {
+                                "N/A".to_string()
+                            },
+                            None,
+                        ),
+                        ProcessSorting::State => (
+                            process.process_state.clone(),
+                            Some(process.process_char.to_string()),
+                        ),
+                    })
+                    .collect(),
+                process.is_disabled_entry || process.is_exited,
             )
         })
         .collect()
 }
 
+/// Formats a process' memory value, only showing a decimal place past a gibibyte.
+fn format_process_mem_bytes(bytes: u64, (value, unit): (f64, String)) -> String {
+    if bytes <= GIBI_LIMIT {
+        format!("{:.0}{}", value, unit)
+    } else {
+        format!("{:.1}{}", value, unit)
+    }
+}
+
+/// The summed usage of a process and its descendants in tree mode.
+#[derive(Clone, Copy, Debug, Default)]
+struct SubtreeUsage {
+    cpu_percent_usage: f64,
+    mem_percent_usage: f64,
+    mem_usage_bytes: u64,
+    rps_f64: f64,
+    wps_f64: f64,
+    tr_f64: f64,
+    tw_f64: f64,
+    mem_breakdown: Option<MemBreakdown>,
+    fds: Option<FdBreakdown>,
+}
+
+impl SubtreeUsage {
+    /// Takes the usage of a single process.  This isn't rounded; that's left to when it's shown.
+    fn from_process(process: &ConvertedProcessData) -> Self {
+        SubtreeUsage {
+            cpu_percent_usage: process.cpu_percent_usage,
+            mem_percent_usage: process.mem_percent_usage,
+            mem_usage_bytes: process.mem_usage_bytes,
+            rps_f64: process.rps_f64,
+            wps_f64: process.wps_f64,
+            tr_f64: process.tr_f64,
+            tw_f64: process.tw_f64,
+            mem_breakdown: process.mem_breakdown,
+            fds: process.fds,
+        }
+    }
+
+    /// Takes the usage a process adds to its ancestors' totals, which is nothing if it exited.
+    fn from_descendant(process: &ConvertedProcessData) -> Self {
+        if process.is_exited {
+            SubtreeUsage::default()
+        } else {
+            SubtreeUsage::from_process(process)
+        }
+    }
+
+    /// Replaces a process' own usage with this.
+    fn apply_to(self, process: &mut ConvertedProcessData) {
+        process.cpu_percent_usage = self.cpu_percent_usage;
+        process.mem_percent_usage = self.mem_percent_usage;
+        process.mem_usage_bytes = self.mem_usage_bytes;
+        process.rps_f64 = self.rps_f64;
+        process.wps_f64 = self.wps_f64;
+        process.tr_f64 = self.tr_f64;
+        process.tw_f64 = self.tw_f64;
+        process.mem_breakdown = self.mem_breakdown;
+        process.fds = self.fds;
+    }
+}
+
+impl std::ops::AddAssign for SubtreeUsage {
+    fn add_assign(&mut self, rhs: Self) {
+        self.cpu_percent_usage += rhs.cpu_percent_usage;
+        self.mem_percent_usage += rhs.mem_percent_usage;
+        self.mem_usage_bytes += rhs.mem_usage_bytes;
+        self.rps_f64 += rhs.rps_f64;
+        self.wps_f64 += rhs.wps_f64;
+        self.tr_f64 += rhs.tr_f64;
+        self.tw_f64 += rhs.tw_f64;
+        self.mem_breakdown = add_breakdowns(self.mem_breakdown, rhs.mem_breakdown);
+        self.fds = add_breakdowns(self.fds, rhs.fds);
+    }
+}
+
+/// Adds two optional breakdowns (memory or file descriptors) together, treating a missing
+/// breakdown as zero unless both are missing.
+fn add_breakdowns<T: std::ops::AddAssign>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            a += b;
+            Some(a)
+        }
+        (Some(a), None) => Some(a),
+        (None, b) => b,
+    }
+}
+
+/// Returns the key that a process is grouped by, or `None` if it isn't known.
+pub fn get_group_key(
+    process: &ConvertedProcessData, is_using_command: bool, grouping_type: ProcessGroupingType,
+    all_process_data: &HashMap<Pid, ConvertedProcessData>,
+) -> Option<String> {
+    match grouping_type {
+        ProcessGroupingType::Name => {
+            if is_using_command {
+                Some(process.command.to_string())
+            } else {
+                Some(process.name.to_string())
+            }
+        }
+        ProcessGroupingType::User => process.user.clone(),
+        ProcessGroupingType::Parent => process.ppid.map(|ppid| match all_process_data.get(&ppid) {
+            Some(parent) => format!("{} ({})", parent.name, ppid),
+            None => format!("PID {}", ppid),
+        }),
+        ProcessGroupingType::ExePath => process.exe_path.clone(),
+        ProcessGroupingType::Cgroup => process.cgroup.clone(),
+    }
+}
+
 /// Takes a set of converted process data and groups it together.
 ///
+/// `all_process_data` is used to look up parent names when grouping by parent, as the parent
+/// itself may have been filtered out.
+///
 /// To be honest, I really don't like how this is done, even though I've rewritten this like 3 times.
 pub fn group_process_data(
     single_process_data: &[ConvertedProcessData], is_using_command: bool,
+    grouping_type: ProcessGroupingType, all_process_data: &HashMap<Pid, ConvertedProcessData>,
 ) -> Vec<ConvertedProcessData> {
     #[derive(Clone, Default, Debug)]
     struct SingleProcessData {
@@ -1293,23 +2172,32 @@ pub fn group_process_data(
         pub write_per_sec: f64,
         pub total_read: f64,
         pub total_write: f64,
+        pub mem_breakdown: Option<MemBreakdown>,
+        pub fds: Option<FdBreakdown>,
         pub process_state: String,
+        pub user: Option<String>,
     }
 
-    let mut grouped_hashmap: HashMap<String, SingleProcessData> = std::collections::HashMap::new();
+    // Processes whose key is unknown are put together under "N/A".
+    let mut grouped_hashmap: HashMap<Option<String>, SingleProcessData> =
+        std::collections::HashMap::new();
 
     single_process_data.iter().for_each(|process| {
+        let identifier = get_group_key(process, is_using_command, grouping_type, all_process_data);
+
         let entry = grouped_hashmap
-            .entry(if is_using_command {
-                process.command.to_string()
-            } else {
-                process.name.to_string()
-            })
+            .entry(identifier)
             .or_insert(SingleProcessData {
                 pid: process.pid,
+                user: process.user.clone(),
                 ..SingleProcessData::default()
             });
 
+        // Only show a user if the whole group shares it.
+        if entry.user != process.user {
+            entry.user = None;
+        }
+
         (*entry).cpu_percent_usage += process.cpu_percent_usage;
         (*entry).mem_percent_usage += process.mem_percent_usage;
         (*entry).mem_usage_bytes += process.mem_usage_bytes;
@@ -1318,12 +2206,15 @@ pub fn group_process_data(
         (*entry).write_per_sec += process.wps_f64;
         (*entry).total_read += process.tr_f64;
         (*entry).total_write += process.tw_f64;
+        entry.mem_breakdown = add_breakdowns(entry.mem_breakdown, process.mem_breakdown);
+        entry.fds = add_breakdowns(entry.fds, process.fds);
     });
 
     grouped_hashmap
         .iter()
         .map(|(identifier, process_details)| {
             let p = process_details.clone();
+            let name = identifier.clone().unwrap_or_else(|| "N/A".to_string());
 
             let (read_per_sec, write_per_sec, total_read, total_write) = get_disk_io_strings(
                 p.read_per_sec as u64,
@@ -1336,8 +2227,8 @@ pub fn group_process_data(
                 pid: p.pid,
                 ppid: None,
                 is_thread: None,
-                name: identifier.to_string(),
-                command: identifier.to_string(),
+                name: name.clone(),
+                command: name,
                 cpu_percent_usage: p.cpu_percent_usage,
                 mem_percent_usage: p.mem_percent_usage,
                 mem_usage_bytes: p.mem_usage_bytes,
@@ -1354,9 +2245,16 @@ pub fn group_process_data(
                 process_state: p.process_state,
                 process_description_prefix: None,
                 process_char: char::default(),
-                is_disabled_entry: false,
+                // The "N/A" group tends to mix in kernel threads and processes that couldn't be
+                // read, so it's shown as disabled and can't be killed as a whole.
+                is_disabled_entry: identifier.is_none(),
                 is_collapsed_entry: false,
-                user: None,
+                is_exited: false,
+                user: p.user,
+                exe_path: None,
+                cgroup: None,
+                mem_breakdown: p.mem_breakdown,
+                fds: p.fds,
             }
         })
         .collect::<Vec<_>>()
@@ -1364,49 +2262,253 @@ pub fn group_process_data(
 
 #[cfg(feature = "battery")]
 pub fn convert_battery_harvest(
-    current_data: &data_farmer::DataCollection,
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
 ) -> Vec<ConvertedBatteryData> {
+    let current_time = get_current_time(current_data, is_frozen);
+
+    let mut battery_points: Vec<(Vec<Point>, Vec<Point>)> =
+        vec![Default::default(); current_data.battery_harvest.len()];
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for ((charge_points, power_points), (charge, power)) in
+            battery_points.iter_mut().zip(&data.battery_data)
+        {
+            charge_points.push((-time_from_start, *charge));
+            power_points.push((-time_from_start, *power));
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
     current_data
         .battery_harvest
         .iter()
+        .zip(battery_points)
         .enumerate()
-        .map(|(itx, battery_harvest)| ConvertedBatteryData {
-            battery_name: format!("Battery {}", itx),
-            charge_percentage: battery_harvest.charge_percent,
-            watt_consumption: format!("{:.2}W", battery_harvest.power_consumption_rate_watts),
-            duration_until_empty: if let Some(secs_till_empty) = battery_harvest.secs_until_empty {
-                let time = time::Duration::seconds(secs_till_empty);
-                let num_minutes = time.whole_minutes() - time.whole_hours() * 60;
-                let num_seconds = time.whole_seconds() - time.whole_minutes() * 60;
-                Some(format!(
-                    "{} hour{}, {} minute{}, {} second{}",
-                    time.whole_hours(),
-                    if time.whole_hours() == 1 { "" } else { "s" },
-                    num_minutes,
-                    if num_minutes == 1 { "" } else { "s" },
-                    num_seconds,
-                    if num_seconds == 1 { "" } else { "s" },
-                ))
-            } else {
-                None
-            },
-            duration_until_full: if let Some(secs_till_full) = battery_harvest.secs_until_full {
-                let time = time::Duration::seconds(secs_till_full);
-                let num_minutes = time.whole_minutes() - time.whole_hours() * 60;
-                let num_seconds = time.whole_seconds() - time.whole_minutes() * 60;
-                Some(format!(
-                    "{} hour{}, {} minute{}, {} second{}",
-                    time.whole_hours(),
-                    if time.whole_hours() == 1 { "" } else { "s" },
-                    num_minutes,
-                    if num_minutes == 1 { "" } else { "s" },
-                    num_seconds,
-                    if num_seconds == 1 { "" } else { "s" },
-                ))
-            } else {
-                None
+        .map(
+            |(itx, (battery_harvest, (charge_points, power_points)))| ConvertedBatteryData {
+                battery_name: format!("Battery {}", itx),
+                charge_percentage: battery_harvest.charge_percent,
+                watt_consumption: format!("{:.2}W", battery_harvest.power_consumption_rate_watts),
+                duration_until_empty: if let Some(secs_till_empty) =
+                    battery_harvest.secs_until_empty
+                {
+                    let time = time::Duration::seconds(secs_till_empty);
+                    let num_minutes = time.whole_minutes() - time.whole_hours() * 60;
+                    let num_seconds = time.whole_seconds() - time.whole_minutes() * 60;
+                    Some(format!(
+                        "{} hour{}, {} minute{}, {} second{}",
+                        time.whole_hours(),
+                        if time.whole_hours() == 1 { "" } else { "s" },
+                        num_minutes,
+                        if num_minutes == 1 { "" } else { "s" },
+                        num_seconds,
+                        if num_seconds == 1 { "" } else { "s" },
+                    ))
+                } else {
+                    None
+                },
+                duration_until_full: if let Some(secs_till_full) = battery_harvest.secs_until_full {
+                    let time = time::Duration::seconds(secs_till_full);
+                    let num_minutes = time.whole_minutes() - time.whole_hours() * 60;
+                    let num_seconds = time.whole_seconds() - time.whole_minutes() * 60;
+                    Some(format!(
+                        "{} hour{}, {} minute{}, {} second{}",
+                        time.whole_hours(),
+                        if time.whole_hours() == 1 { "" } else { "s" },
+                        num_minutes,
+                        if num_minutes == 1 { "" } else { "s" },
+                        num_seconds,
+                        if num_seconds == 1 { "" } else { "s" },
+                    ))
+                } else {
+                    None
+                },
+                health: format!("{:.2}%", battery_harvest.health_percent),
+                state: battery_harvest.state.to_string(),
+                voltage: format!("{:.2}V", battery_harvest.voltage_volts),
+                energy: format!(
+                    "{:.1}/{:.1}Wh ({:.1}Wh design)",
+                    battery_harvest.energy_wh,
+                    battery_harvest.energy_full_wh,
+                    battery_harvest.energy_full_design_wh
+                ),
+                cycle_count: battery_harvest
+                    .cycle_count
+                    .map(|cycle_count| cycle_count.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+                model: match (&battery_harvest.vendor, &battery_harvest.model) {
+                    (Some(vendor), Some(model)) => Some(format!("{} {}", vendor, model)),
+                    (Some(name), None) | (None, Some(name)) => Some(name.clone()),
+                    (None, None) => None,
+                }
+                .filter(|name| !name.is_empty()),
+                charge_points,
+                power_points,
             },
-            health: format!("{:.2}%", battery_harvest.health_percent),
-        })
+        )
         .collect()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cpu_freq_legend() {
+        use data_harvester::cpu::CpuFreqData;
+
+        let freq = |min, max| CpuFreqData {
+            cpu: 0,
+            current: 800.0,
+            min,
+            max,
+        };
+        assert_eq!(
+            get_cpu_freq_legend(&freq(Some(400.0), Some(4700.0))),
+            "800MHz / 400MHz-4.70GHz"
+        );
+        assert_eq!(
+            get_cpu_freq_legend(&freq(None, Some(3600.0))),
+            "800MHz / 3.60GHz"
+        );
+        assert_eq!(
+            get_cpu_freq_legend(&freq(Some(400.0), None)),
+            "800MHz / 400MHz-"
+        );
+        assert_eq!(get_cpu_freq_legend(&freq(None, None)), "800MHz");
+    }
+
+    fn process(pid: Pid, ppid: Option<Pid>, cpu_percent_usage: f64) -> ConvertedProcessData {
+        ConvertedProcessData {
+            pid,
+            ppid,
+            name: format!("proc_{}", pid),
+            cpu_percent_usage,
+            group_pids: vec![pid],
+            ..ConvertedProcessData::default()
+        }
+    }
+
+    #[test]
+    fn test_tree_subtree_totals() {
+        // 1 -> 2 -> 3, and 4 on its own.  The tree rooted at 1 uses less CPU by itself, but more
+        // once its descendants are included.
+        let data = vec![
+            process(1, None, 1.0),
+            process(2, Some(1), 2.0),
+            process(3, Some(2), 10.0),
+            process(4, None, 5.0),
+        ];
+
+        let tree = tree_process_data(&data, false, &ProcessSorting::CpuPercent, true, false);
+        let pids: Vec<Pid> = tree.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![4, 1, 2, 3]);
+        assert_eq!(tree[1].cpu_percent_usage, 1.0);
+
+        let tree = tree_process_data(&data, false, &ProcessSorting::CpuPercent, true, true);
+        let pids: Vec<Pid> = tree.iter().map(|p| p.pid).collect();
+        assert_eq!(pids, vec![1, 2, 3, 4]);
+        assert_eq!(tree[0].cpu_percent_usage, 13.0);
+        assert_eq!(tree[1].cpu_percent_usage, 12.0);
+        assert_eq!(tree[2].cpu_percent_usage, 10.0);
+        assert_eq!(tree[3].cpu_percent_usage, 5.0);
+    }
+
+    #[test]
+    fn test_tree_totals_skip_exited() {
+        // 1 -> 2 -> 3, where 2 has exited but is still shown with its last known usage.
+        let mut data = vec![
+            process(1, None, 1.0),
+            process(2, Some(1), 8.0),
+            process(3, Some(2), 10.0),
+        ];
+        data[1].is_exited = true;
+
+        let tree = tree_process_data(&data, false, &ProcessSorting::Pid, false, true);
+        let usages: Vec<f64> = tree.iter().map(|p| p.cpu_percent_usage).collect();
+        assert_eq!(usages, vec![11.0, 18.0, 10.0]);
+
+        data[0].is_collapsed_entry = true;
+        let tree = tree_process_data(&data, false, &ProcessSorting::Pid, false, false);
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].cpu_percent_usage, 11.0);
+    }
+
+    #[test]
+    fn test_group_unknown_keys() {
+        let user = |pid: Pid, user: Option<&str>, cgroup: Option<&str>| ConvertedProcessData {
+            user: user.map(|user| user.to_string()),
+            cgroup: cgroup.map(|cgroup| cgroup.to_string()),
+            ..process(pid, None, 1.0)
+        };
+        let data = vec![
+            user(1, Some("root"), Some("/init.scope")),
+            user(2, Some("root"), None),
+            user(3, Some("alice"), None),
+            user(4, Some("alice"), Some("/user.slice")),
+            user(5, Some("bob"), Some("/user.slice")),
+        ];
+
+        let mut groups =
+            group_process_data(&data, false, ProcessGroupingType::Cgroup, &HashMap::new());
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        let groups: Vec<(&str, usize, Option<&str>, bool)> = groups
+            .iter()
+            .map(|group| {
+                (
+                    group.name.as_str(),
+                    group.group_pids.len(),
+                    group.user.as_deref(),
+                    group.is_disabled_entry,
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("/init.scope", 1, Some("root"), false),
+                ("/user.slice", 2, None, false),
+                ("N/A", 2, None, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_stall_time() {
+        assert_eq!(format_stall_time(41_927_821), "41.9s");
+        assert_eq!(format_stall_time(90_000_000), "1.5m");
+        assert_eq!(format_stall_time(7_200_000_000), "2.0h");
+    }
+
+    #[test]
+    fn test_format_kernel_stat_rates() {
+        let rates = KernelStatRates {
+            context_switches: 2345.0,
+            interrupts: 512.4,
+            forks: 3.6,
+            procs_running: 2,
+            procs_blocked: 0,
+        };
+        assert_eq!(
+            format_kernel_stat_rates(&rates),
+            "2.3K ctx/s 512 int/s 4 fork/s 2 run 0 blk"
+        );
+    }
+
+    #[test]
+    fn test_format_frequency() {
+        assert_eq!(format_frequency(800.0), "800MHz");
+        assert_eq!(format_frequency(999.6), "1.00GHz");
+        assert_eq!(format_frequency(3400.0), "3.40GHz");
+    }
+
+    #[test]
+    fn test_format_link_speed() {
+        assert_eq!(format_link_speed(100), "100Mb/s");
+        assert_eq!(format_link_speed(1000), "1Gb/s");
+        assert_eq!(format_link_speed(2500), "2.5Gb/s");
+    }
+}
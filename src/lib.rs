@@ -41,6 +41,8 @@ pub mod utils {
     pub mod error;
     pub mod gen_util;
     pub mod logging;
+    #[cfg(test)]
+    pub mod test_util;
 }
 pub mod canvas;
 pub mod clap;
@@ -69,6 +71,10 @@ pub enum ThreadControlEvent {
     UpdateConfig(Box<app::AppConfigFields>),
     UpdateUsedWidgets(Box<UsedWidgets>),
     UpdateUpdateTime(u64),
+    #[cfg(target_os = "linux")]
+    UpdateProcessDetailsToCollect(data_harvester::processes::ProcessDetailsToCollect),
+    #[cfg(target_os = "linux")]
+    UpdateConnectionPids(Option<Vec<Pid>>),
 }
 
 pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
@@ -103,7 +109,9 @@ pub fn handle_key_event_or_break(
 
     if event.modifiers.is_empty() {
         // Required catch for searching - otherwise you couldn't search with q.
-        if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
+        if event.code == KeyCode::Char('q')
+            && !(app.is_in_search_widget() || app.is_searching_sockets())
+        {
             return true;
         }
         match event.code {
@@ -302,6 +310,42 @@ pub fn panic_hook(panic_info: &PanicInfo<'_>) {
     .unwrap();
 }
 
+/// Tells the collection thread when the optional process details that are needed change, like
+/// when cycling to grouping by cgroup.
+#[cfg(target_os = "linux")]
+pub fn update_process_details_to_collect(
+    app: &mut App, collection_thread_ctrl_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) {
+    let process_details_to_collect = app.get_process_details_to_collect();
+    if process_details_to_collect != app.process_details_to_collect
+        && collection_thread_ctrl_sender
+            .send(ThreadControlEvent::UpdateProcessDetailsToCollect(
+                process_details_to_collect,
+            ))
+            .is_ok()
+    {
+        app.process_details_to_collect = process_details_to_collect;
+    }
+}
+
+/// Tells the collection thread which processes to collect the connections of, like when the
+/// connections dialog is opened or closed.
+#[cfg(target_os = "linux")]
+pub fn update_connection_pids(
+    app: &mut App, collection_thread_ctrl_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) {
+    let connection_pids = app.get_connection_pids();
+    if connection_pids != app.connection_pids
+        && collection_thread_ctrl_sender
+            .send(ThreadControlEvent::UpdateConnectionPids(
+                connection_pids.clone(),
+            ))
+            .is_ok()
+    {
+        app.connection_pids = connection_pids;
+    }
+}
+
 pub fn handle_force_redraws(app: &mut App) {
     // Currently we use an Option... because we might want to future-proof this
     // if we eventually get widget-specific redrawing!
@@ -320,6 +364,7 @@ pub fn handle_force_redraws(app: &mut App) {
             app.is_frozen,
         );
         app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
+        app.canvas_data.kernel_stat_rates = app.data_collection.kernel_stat_rates;
         app.cpu_state.force_update = None;
     }
 
@@ -330,6 +375,47 @@ pub fn handle_force_redraws(app: &mut App) {
         app.mem_state.force_update = None;
     }
 
+    if app.pressure_state.force_update.is_some() {
+        app.canvas_data.pressure_data = convert_pressure_data(&app.data_collection, app.is_frozen);
+        app.pressure_state.force_update = None;
+    }
+
+    if app.load_avg_state.force_update.is_some() {
+        app.canvas_data.load_avg_graph_data =
+            convert_load_avg_data(&app.data_collection, app.is_frozen);
+        app.load_avg_state.force_update = None;
+    }
+
+    if app.cpu_freq_state.force_update.is_some() {
+        app.canvas_data.cpu_freq_data = convert_cpu_freq_data(&app.data_collection, app.is_frozen);
+        app.cpu_freq_state.force_update = None;
+    }
+
+    if app.disk_util_state.force_update.is_some() {
+        app.canvas_data.disk_util_data =
+            convert_disk_util_data(&app.data_collection, app.is_frozen);
+        app.disk_util_state.force_update = None;
+    }
+
+    if app.disk_io_state.force_update.is_some() {
+        app.canvas_data.disk_io_data = convert_disk_io_data(&app.data_collection, app.is_frozen);
+        app.disk_io_state.force_update = None;
+    }
+
+    if app.temp_graph_state.force_update.is_some() {
+        app.canvas_data.temp_graph_data = convert_temp_graph_data(
+            &app.data_collection,
+            app.is_frozen,
+            &app.app_config_fields.temp_graph_filter,
+        );
+        app.temp_graph_state.force_update = None;
+    }
+
+    if let Some(widget_id) = app.socket_state.force_update {
+        update_final_socket_list(app, widget_id);
+        app.socket_state.force_update = None;
+    }
+
     if app.net_state.force_update.is_some() {
         let (rx, tx) = get_rx_tx_data_points(
             &app.data_collection,
@@ -375,15 +461,19 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                     .is_invalid_or_blank_search(),
                 process_state.is_using_command,
                 process_state.is_grouped,
+                process_state.grouping_type,
                 process_state.is_tree_mode,
             )
         });
 
-    if let Some((is_invalid_or_blank, is_using_command, is_grouped, is_tree)) = process_states {
+    if let Some((is_invalid_or_blank, is_using_command, is_grouped, grouping_type, is_tree)) =
+        process_states
+    {
         if !app.is_frozen {
             convert_process_data(
                 &app.data_collection,
                 &mut app.canvas_data.single_process_data,
+                app.app_config_fields.show_exited_processes,
                 #[cfg(target_family = "unix")]
                 &mut app.user_table,
             );
@@ -409,6 +499,11 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                 .single_process_data
                 .iter()
                 .filter_map(|(_pid, process)| {
+                    // Exited processes would skew the totals of their group.
+                    if is_grouped && process.is_exited {
+                        return None;
+                    }
+
                     if !is_invalid_or_blank {
                         if let Some(process_filter) = process_filter {
                             if process_filter.check(process, is_using_command) {
@@ -434,9 +529,15 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                     is_using_command,
                     &proc_widget_state.process_sorting_type,
                     proc_widget_state.is_process_sort_descending,
+                    proc_widget_state.show_tree_totals,
                 )
             } else if is_grouped {
-                group_process_data(&filtered_process_data, is_using_command)
+                group_process_data(
+                    &filtered_process_data,
+                    is_using_command,
+                    grouping_type,
+                    &app.canvas_data.single_process_data,
+                )
             } else {
                 filtered_process_data
             };
@@ -446,6 +547,17 @@ fn update_final_process_list(app: &mut App, widget_id: u64) {
                 sort_process_data(&mut finalized_process_data, proc_widget_state);
             }
 
+            if proc_widget_state.is_following {
+                proc_widget_state.update_followed_position(
+                    app.canvas_data
+                        .finalized_process_data_map
+                        .get(&widget_id)
+                        .map(|data| data.as_slice()),
+                    &finalized_process_data,
+                    &app.canvas_data.single_process_data,
+                );
+            }
+
             if proc_widget_state.scroll_state.current_scroll_position
                 >= finalized_process_data.len()
             {
@@ -525,6 +637,41 @@ fn sort_process_data(
                 });
             }
         }
+        ProcessSorting::Pss
+        | ProcessSorting::Uss
+        | ProcessSorting::SharedMem
+        | ProcessSorting::Swap => {
+            let sort_type = &proc_widget_state.process_sorting_type;
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.get_mem_breakdown_bytes(sort_type).unwrap_or(0),
+                    b.get_mem_breakdown_bytes(sort_type).unwrap_or(0),
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
+        ProcessSorting::Fds
+        | ProcessSorting::SocketFds
+        | ProcessSorting::PipeFds
+        | ProcessSorting::FileFds => {
+            let sort_type = &proc_widget_state.process_sorting_type;
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.get_fd_count(sort_type).unwrap_or(0),
+                    b.get_fd_count(sort_type).unwrap_or(0),
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
+        ProcessSorting::FdPercent => {
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.get_fd_limit_percent().unwrap_or(0.0),
+                    b.get_fd_limit_percent().unwrap_or(0.0),
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
         ProcessSorting::ReadPerSecond => {
             to_sort_vec.sort_by(|a, b| {
                 utils::gen_util::get_ordering(
@@ -591,6 +738,93 @@ fn sort_process_data(
     }
 }
 
+#[allow(clippy::needless_collect)]
+pub fn update_all_socket_lists(app: &mut App) {
+    if !app.is_frozen {
+        let widget_ids = app
+            .socket_state
+            .widget_states
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        widget_ids.into_iter().for_each(|widget_id| {
+            update_final_socket_list(app, widget_id);
+        });
+    }
+}
+
+fn update_final_socket_list(app: &mut App, widget_id: u64) {
+    if !app.is_frozen {
+        app.canvas_data.socket_data = app.data_collection.socket_harvest.clone();
+    }
+
+    if let Some(socket_widget_state) = app.socket_state.widget_states.get_mut(&widget_id) {
+        let mut filtered_socket_data = app
+            .canvas_data
+            .socket_data
+            .iter()
+            .filter(|socket| socket_widget_state.matches_filter(socket))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        sort_socket_data(&mut filtered_socket_data, socket_widget_state);
+
+        // The list may have shrunk since the last update.
+        let scroll_state = &mut socket_widget_state.scroll_state;
+        if scroll_state.current_scroll_position >= filtered_socket_data.len() {
+            scroll_state.current_scroll_position = filtered_socket_data.len().saturating_sub(1);
+        }
+
+        app.canvas_data
+            .socket_data_map
+            .insert(widget_id, filtered_socket_data);
+    }
+}
+
+fn sort_socket_data(
+    to_sort_vec: &mut [data_harvester::network::sockets::SocketHarvest],
+    socket_widget_state: &app::SocketWidgetState,
+) {
+    use app::SocketSorting;
+
+    let is_descending = socket_widget_state.is_sort_descending;
+    to_sort_vec.sort_by(|a, b| match socket_widget_state.sorting_type {
+        SocketSorting::Protocol => {
+            utils::gen_util::get_ordering(a.protocol, b.protocol, is_descending)
+        }
+        SocketSorting::LocalAddress => {
+            utils::gen_util::get_ordering(a.local_address.ip(), b.local_address.ip(), is_descending)
+        }
+        SocketSorting::LocalPort => utils::gen_util::get_ordering(
+            a.local_address.port(),
+            b.local_address.port(),
+            is_descending,
+        ),
+        SocketSorting::RemoteAddress => utils::gen_util::get_ordering(
+            a.remote_address.ip(),
+            b.remote_address.ip(),
+            is_descending,
+        ),
+        SocketSorting::RemotePort => utils::gen_util::get_ordering(
+            a.remote_address.port(),
+            b.remote_address.port(),
+            is_descending,
+        ),
+        SocketSorting::State => utils::gen_util::get_ordering(a.state, b.state, is_descending),
+        SocketSorting::RecvQueue => {
+            utils::gen_util::get_ordering(a.rx_queue, b.rx_queue, is_descending)
+        }
+        SocketSorting::SendQueue => {
+            utils::gen_util::get_ordering(a.tx_queue, b.tx_queue, is_descending)
+        }
+        SocketSorting::Pid => utils::gen_util::get_ordering(a.pid, b.pid, is_descending),
+        SocketSorting::ProcessName => {
+            utils::gen_util::get_ordering(&a.process_name, &b.process_name, is_descending)
+        }
+    });
+}
+
 pub fn create_input_thread(
     sender: std::sync::mpsc::Sender<
         BottomEvent<crossterm::event::KeyEvent, crossterm::event::MouseEvent>,
@@ -646,7 +880,17 @@ pub fn create_collection_thread(
     let temp_type = app_config_fields.temperature_type.clone();
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let show_average_cpu = app_config_fields.show_average_cpu;
-    let update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
+    let mut collection_intervals = app_config_fields.collection_intervals;
+    #[cfg(target_os = "linux")]
+    let process_details_to_collect =
+        data_harvester::processes::ProcessDetailsToCollect::from_columns(
+            &app_config_fields.extra_process_columns,
+        );
+    #[cfg(target_os = "linux")]
+    let (proc_root, sys_root) = (
+        app_config_fields.proc_root.clone(),
+        app_config_fields.sys_root.clone(),
+    );
 
     thread::spawn(move || {
         let mut data_state = data_harvester::DataCollector::new(filters);
@@ -655,6 +899,13 @@ pub fn create_collection_thread(
         data_state.set_temperature_type(temp_type);
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_show_average_cpu(show_average_cpu);
+        data_state.set_collection_intervals(collection_intervals);
+        #[cfg(target_os = "linux")]
+        {
+            data_state.set_process_details_to_collect(process_details_to_collect);
+            data_state.set_proc_root(proc_root);
+            data_state.set_sys_root(sys_root);
+        }
 
         data_state.init();
 
@@ -668,7 +919,9 @@ pub fn create_collection_thread(
                 }
             }
 
-            let mut update_time = update_rate_in_milliseconds;
+            // Wake up often enough for the source with the shortest interval; the rest are skipped
+            // until they're due.
+            let mut update_time = collection_intervals.shortest();
             if let Ok(message) = control_receiver.try_recv() {
                 // trace!("Received message in collection thread: {:?}", message);
                 match message {
@@ -680,6 +933,14 @@ pub fn create_collection_thread(
                         data_state
                             .set_use_current_cpu_total(app_config_fields.use_current_cpu_total);
                         data_state.set_show_average_cpu(app_config_fields.show_average_cpu);
+                        collection_intervals = app_config_fields.collection_intervals;
+                        data_state.set_collection_intervals(collection_intervals);
+                        #[cfg(target_os = "linux")]
+                        data_state.set_process_details_to_collect(
+                            data_harvester::processes::ProcessDetailsToCollect::from_columns(
+                                &app_config_fields.extra_process_columns,
+                            ),
+                        );
                     }
                     ThreadControlEvent::UpdateUsedWidgets(used_widget_set) => {
                         data_state.set_collected_data(*used_widget_set);
@@ -687,6 +948,16 @@ pub fn create_collection_thread(
                     ThreadControlEvent::UpdateUpdateTime(new_time) => {
                         update_time = new_time;
                     }
+                    #[cfg(target_os = "linux")]
+                    ThreadControlEvent::UpdateProcessDetailsToCollect(
+                        process_details_to_collect,
+                    ) => {
+                        data_state.set_process_details_to_collect(process_details_to_collect);
+                    }
+                    #[cfg(target_os = "linux")]
+                    ThreadControlEvent::UpdateConnectionPids(connection_pids) => {
+                        data_state.set_connection_pids(connection_pids);
+                    }
                 }
             }
             futures::executor::block_on(data_state.update_data());
@@ -10,9 +10,19 @@ use std::{
 };
 
 use crate::{
-    app::{layout_manager::*, *},
+    app::{
+        alerts::{Alert, Alerts},
+        layout_manager::*,
+        watches::Watches,
+        *,
+    },
     canvas::ColourScheme,
     constants::*,
+    data_conversion::ProcessGroupingType,
+    data_harvester::{
+        processes::{self, ProcessSorting},
+        DataSource,
+    },
     units::data_units::DataUnit,
     utils::error::{self, BottomError},
 };
@@ -34,6 +44,11 @@ pub struct Config {
     pub mount_filter: Option<IgnoreList>,
     pub temp_filter: Option<IgnoreList>,
     pub net_filter: Option<IgnoreList>,
+    pub processes: Option<ProcessesConfig>,
+    pub temp_graph: Option<TempGraphConfig>,
+    pub collection_intervals: Option<CollectionIntervalsConfig>,
+    pub alerts: Option<Vec<AlertConfig>>,
+    pub watches: Option<WatchesConfig>,
 }
 
 impl Config {
@@ -71,6 +86,9 @@ pub struct ConfigFlags {
     #[builder(default, setter(strip_option))]
     pub group_processes: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub group_by: Option<String>,
+
     #[builder(default, setter(strip_option))]
     pub case_sensitive: Option<bool>,
 
@@ -150,9 +168,18 @@ pub struct ConfigFlags {
     #[builder(default, setter(strip_option))]
     pub tree: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub tree_totals: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub follow: Option<bool>,
+
     #[builder(default, setter(strip_option))]
     show_table_scroll_position: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub show_exited: Option<bool>,
+
     #[builder(default, setter(strip_option))]
     pub process_command: Option<bool>,
 
@@ -167,6 +194,12 @@ pub struct ConfigFlags {
 
     #[builder(default, setter(strip_option))]
     pub network_use_binary_prefix: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub proc_root: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub sys_root: Option<String>,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -229,6 +262,59 @@ fn default_as_true() -> bool {
     true
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessesConfig {
+    /// Optional columns to show in the process widget, on top of the default ones.
+    pub extra_columns: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TempGraphConfig {
+    /// Which sensors to draw in the temperature graph.  Works like the other filters.
+    pub sensor_filter: Option<IgnoreList>,
+    /// Temperatures to draw horizontal lines at, in the configured temperature unit.
+    pub thresholds: Option<Vec<f64>>,
+}
+
+/// How often each data source is collected in milliseconds, if not at the refresh rate.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CollectionIntervalsConfig {
+    pub cpu: Option<u64>,
+    pub memory: Option<u64>,
+    pub network: Option<u64>,
+    pub processes: Option<u64>,
+    pub temperature: Option<u64>,
+    pub disk: Option<u64>,
+    pub battery: Option<u64>,
+}
+
+/// An alert rule, like `cpu.avg > 90 for 30s`, along with what to do when it fires.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AlertConfig {
+    /// Names the alert in logs and commands.  Defaults to the rule.
+    pub name: Option<String>,
+    pub rule: String,
+    /// Whether to mark the widgets showing the rule's data while firing.  Defaults to true.
+    pub highlight: Option<bool>,
+    /// Whether to ring the terminal bell when firing.
+    pub bell: Option<bool>,
+    /// A file to append to when firing and resolving.
+    pub log: Option<String>,
+    /// A command to run when firing and resolving.
+    pub command: Option<String>,
+}
+
+/// Process queries to watch from startup, along with where to report what happens to the
+/// matching processes.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct WatchesConfig {
+    pub queries: Option<Vec<String>>,
+    /// A file to append every event to.
+    pub log: Option<String>,
+    /// A command to run on every event.
+    pub command: Option<String>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IgnoreList {
     #[serde(default = "default_as_true")]
@@ -257,6 +343,8 @@ pub fn build_app(
 
     // For processes
     let is_grouped = get_app_grouping(matches, config);
+    let grouping_type =
+        get_grouping_type(matches, config).context("Update 'group_by' in your config file.")?;
     let is_case_sensitive = get_app_case_sensitive(matches, config);
     let is_match_whole_word = get_app_match_whole_word(matches, config);
     let is_use_regex = get_app_use_regex(matches, config);
@@ -268,6 +356,15 @@ pub fn build_app(
     let mut proc_state_map: HashMap<u64, ProcWidgetState> = HashMap::new();
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::new();
     let mut disk_state_map: HashMap<u64, DiskWidgetState> = HashMap::new();
+    let mut socket_state_map: HashMap<u64, SocketWidgetState> = HashMap::new();
+    let mut pressure_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
+    let mut disk_util_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
+    let mut disk_io_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
+    let mut temp_graph_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
+    let mut sensor_state_map: HashMap<u64, SensorWidgetState> = HashMap::new();
+    let mut net_interface_state_map: HashMap<u64, NetInterfaceWidgetState> = HashMap::new();
+    let mut cpu_freq_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
+    let mut load_avg_state_map: HashMap<u64, GraphWidgetState> = HashMap::new();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::new();
 
     let autohide_timer = if autohide_time {
@@ -283,9 +380,40 @@ pub fn build_app(
 
     let show_memory_as_values = get_mem_as_value(matches, config);
     let is_default_tree = get_is_default_tree(matches, config);
+    let is_default_follow = get_is_default_follow(matches, config);
+    let show_tree_totals = get_show_tree_totals(matches, config);
+    let extra_process_columns =
+        get_extra_process_columns(config).context("Update 'extra_columns' in your config file.")?;
     let is_default_command = get_is_default_process_command(matches, config);
+    let proc_widget_config = ProcWidgetConfig {
+        is_case_sensitive,
+        is_match_whole_word,
+        is_use_regex,
+        is_grouped,
+        show_memory_as_values,
+        is_tree_mode: is_default_tree,
+        is_using_command: is_default_command,
+        is_following: is_default_follow,
+        grouping_type,
+        show_tree_totals,
+        extra_columns: extra_process_columns.clone(),
+    };
     let is_advanced_kill = !get_is_advanced_kill_disabled(matches, config);
 
+    let (temp_graph_filter, temp_graph_thresholds) = if let Some(temp_graph) = &config.temp_graph {
+        (
+            get_ignore_list(&temp_graph.sensor_filter)
+                .context("Update 'temp_graph.sensor_filter' in your config file")?,
+            temp_graph.thresholds.clone().unwrap_or_default(),
+        )
+    } else {
+        (None, vec![])
+    };
+
+    let alerts = get_alerts(config).context("Update 'alerts' in your config file.")?;
+    let watches =
+        Watches::from_config(&config.watches).context("Update 'watches' in your config file.")?;
+
     let network_unit_type = get_network_unit_type(matches, config);
     let network_scale_type = get_network_scale_type(matches, config);
     let network_use_binary_prefix = get_network_use_binary_prefix(matches, config);
@@ -355,15 +483,7 @@ pub fn build_app(
                         Proc => {
                             proc_state_map.insert(
                                 widget.widget_id,
-                                ProcWidgetState::init(
-                                    is_case_sensitive,
-                                    is_match_whole_word,
-                                    is_use_regex,
-                                    is_grouped,
-                                    show_memory_as_values,
-                                    is_default_tree,
-                                    is_default_command,
-                                ),
+                                ProcWidgetState::init(&proc_widget_config),
                             );
                         }
                         Disk => {
@@ -372,6 +492,59 @@ pub fn build_app(
                         Temp => {
                             temp_state_map.insert(widget.widget_id, TempWidgetState::init());
                         }
+                        Sensor => {
+                            sensor_state_map.insert(widget.widget_id, SensorWidgetState::init());
+                        }
+                        NetInterface => {
+                            net_interface_state_map
+                                .insert(widget.widget_id, NetInterfaceWidgetState::init());
+                        }
+                        Socket => {
+                            socket_state_map.insert(
+                                widget.widget_id,
+                                SocketWidgetState::init(
+                                    is_case_sensitive,
+                                    is_match_whole_word,
+                                    is_use_regex,
+                                ),
+                            );
+                        }
+                        Pressure => {
+                            pressure_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        DiskUtil => {
+                            disk_util_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        DiskIo => {
+                            disk_io_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        TempGraph => {
+                            temp_graph_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        CpuFreq => {
+                            cpu_freq_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        LoadAvg => {
+                            load_avg_state_map.insert(
+                                widget.widget_id,
+                                GraphWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
                         Battery => {
                             battery_state_map
                                 .insert(widget.widget_id, BatteryWidgetState::default());
@@ -408,9 +581,14 @@ pub fn build_app(
         None
     };
 
+    let update_rate_in_milliseconds = get_update_rate_in_milliseconds(matches, config)
+        .context("Update 'rate' in your config file.")?;
+    let collection_intervals = get_collection_intervals(config, update_rate_in_milliseconds)
+        .context("Update 'collection_intervals' in your config file.")?;
+
     let app_config_fields = AppConfigFields {
-        update_rate_in_milliseconds: get_update_rate_in_milliseconds(matches, config)
-            .context("Update 'rate' in your config file.")?,
+        update_rate_in_milliseconds,
+        collection_intervals,
         temperature_type: get_temperature(matches, config)
             .context("Update 'temperature_type' in your config file.")?,
         show_average_cpu: get_show_average_cpu(matches, config),
@@ -434,21 +612,50 @@ pub fn build_app(
         no_write: false,
         show_table_scroll_position: get_show_table_scroll_position(matches, config),
         is_advanced_kill,
+        show_exited_processes: get_show_exited_processes(matches, config),
+        extra_process_columns,
+        temp_graph_filter,
+        temp_graph_thresholds,
         network_scale_type,
         network_unit_type,
         network_use_binary_prefix,
+        proc_root: get_proc_root(matches, config),
+        sys_root: get_sys_root(matches, config),
     };
 
-    let used_widgets = UsedWidgets {
+    let mut used_widgets = UsedWidgets {
         use_cpu: used_widget_set.get(&Cpu).is_some() || used_widget_set.get(&BasicCpu).is_some(),
         use_mem: used_widget_set.get(&Mem).is_some() || used_widget_set.get(&BasicMem).is_some(),
         use_net: used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some(),
         use_proc: used_widget_set.get(&Proc).is_some(),
-        use_disk: used_widget_set.get(&Disk).is_some(),
-        use_temp: used_widget_set.get(&Temp).is_some(),
+        use_disk: used_widget_set.get(&Disk).is_some()
+            || used_widget_set.contains(&DiskUtil)
+            || used_widget_set.contains(&DiskIo),
+        use_temp: used_widget_set.get(&Temp).is_some() || used_widget_set.contains(&TempGraph),
+        use_socket: used_widget_set.contains(&Socket),
+        use_pressure: used_widget_set.contains(&Pressure),
+        use_sensor: used_widget_set.contains(&Sensor),
+        use_net_interface: used_widget_set.contains(&NetInterface),
+        use_cpu_freq: used_widget_set.contains(&CpuFreq),
+        use_load_avg: used_widget_set.contains(&LoadAvg),
         use_battery: used_widget_set.get(&Battery).is_some(),
     };
 
+    // Alerts need their data collected even if no widget shows it.
+    for alert in &alerts {
+        match alert.source() {
+            DataSource::Cpu => used_widgets.use_cpu = true,
+            DataSource::Memory => used_widgets.use_mem = true,
+            DataSource::Temperature => used_widgets.use_temp = true,
+            DataSource::Disk => used_widgets.use_disk = true,
+            DataSource::Processes => used_widgets.use_proc = true,
+            _ => {}
+        }
+    }
+    if !watches.is_empty() {
+        used_widgets.use_proc = true;
+    }
+
     let disk_filter =
         get_ignore_list(&config.disk_filter).context("Update 'disk_filter' in your config file")?;
     let mount_filter = get_ignore_list(&config.mount_filter)
@@ -507,12 +714,22 @@ pub fn build_app(
         .net_state(NetState::init(net_state_map))
         .proc_state(ProcState::init(proc_state_map))
         .disk_state(DiskState::init(disk_state_map))
+        .socket_state(SocketState::init(socket_state_map))
+        .pressure_state(GraphState::init(pressure_state_map))
+        .disk_util_state(GraphState::init(disk_util_state_map))
+        .disk_io_state(GraphState::init(disk_io_state_map))
+        .temp_graph_state(GraphState::init(temp_graph_state_map))
+        .cpu_freq_state(GraphState::init(cpu_freq_state_map))
+        .load_avg_state(GraphState::init(load_avg_state_map))
         .temp_state(TempState::init(temp_state_map))
+        .sensor_state(SensorState::init(sensor_state_map))
+        .net_interface_state(NetInterfaceState::init(net_interface_state_map))
         .battery_state(BatteryState::init(battery_state_map))
         .basic_table_widget_state(basic_table_widget_state)
         .current_widget(widget_map.get(&initial_widget_id).unwrap().clone()) // TODO: [UNWRAP] - many of the unwraps are fine (like this one) but do a once-over and/or switch to expect?
         .widget_map(widget_map)
         .used_widgets(used_widgets)
+        .alerts(Alerts::new(alerts, watches))
         .filters(DataFilters {
             disk_filter,
             mount_filter,
@@ -617,6 +834,43 @@ fn get_update_rate_in_milliseconds(
     Ok(update_rate_in_milliseconds)
 }
 
+fn get_collection_intervals(
+    config: &Config, update_rate_in_milliseconds: u64,
+) -> error::Result<data_harvester::CollectionIntervals> {
+    let mut collection_intervals =
+        data_harvester::CollectionIntervals::uniform(update_rate_in_milliseconds);
+
+    if let Some(config_intervals) = &config.collection_intervals {
+        for (interval, config_interval) in [
+            (&mut collection_intervals.cpu, config_intervals.cpu),
+            (&mut collection_intervals.memory, config_intervals.memory),
+            (&mut collection_intervals.network, config_intervals.network),
+            (
+                &mut collection_intervals.processes,
+                config_intervals.processes,
+            ),
+            (
+                &mut collection_intervals.temperature,
+                config_intervals.temperature,
+            ),
+            (&mut collection_intervals.disk, config_intervals.disk),
+            (&mut collection_intervals.battery, config_intervals.battery),
+        ] {
+            if let Some(config_interval) = config_interval {
+                if config_interval < 250 {
+                    return Err(BottomError::ConfigError(
+                        "set your collection intervals to be at least 250 milliseconds."
+                            .to_string(),
+                    ));
+                }
+                *interval = config_interval;
+            }
+        }
+    }
+
+    Ok(collection_intervals)
+}
+
 fn get_temperature(
     matches: &clap::ArgMatches, config: &Config,
 ) -> error::Result<data_harvester::temperature::TemperatureType> {
@@ -776,6 +1030,19 @@ pub fn get_app_grouping(matches: &clap::ArgMatches, config: &Config) -> bool {
     false
 }
 
+fn get_grouping_type(
+    matches: &clap::ArgMatches, config: &Config,
+) -> error::Result<ProcessGroupingType> {
+    if let Some(grouping_type) = matches.value_of("group_by") {
+        return ProcessGroupingType::from_str(grouping_type);
+    } else if let Some(flags) = &config.flags {
+        if let Some(grouping_type) = &flags.group_by {
+            return ProcessGroupingType::from_str(grouping_type);
+        }
+    }
+    Ok(ProcessGroupingType::Name)
+}
+
 pub fn get_app_case_sensitive(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("case_sensitive") {
         return true;
@@ -1024,6 +1291,48 @@ fn get_is_default_tree(matches: &clap::ArgMatches, config: &Config) -> bool {
     false
 }
 
+fn get_extra_process_columns(config: &Config) -> error::Result<Vec<ProcessSorting>> {
+    if let Some(processes) = &config.processes {
+        if let Some(extra_columns) = &processes.extra_columns {
+            return extra_columns
+                .iter()
+                .map(|column| processes::parse_extra_column(column))
+                .collect();
+        }
+    }
+    Ok(vec![])
+}
+
+fn get_alerts(config: &Config) -> error::Result<Vec<Alert>> {
+    if let Some(alerts) = &config.alerts {
+        alerts.iter().map(Alert::from_config).collect()
+    } else {
+        Ok(vec![])
+    }
+}
+
+fn get_show_tree_totals(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("tree_totals") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(tree_totals) = flags.tree_totals {
+            return tree_totals;
+        }
+    }
+    false
+}
+
+fn get_is_default_follow(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("follow") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(follow) = flags.follow {
+            return follow;
+        }
+    }
+    false
+}
+
 fn get_show_table_scroll_position(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("show_table_scroll_position") {
         return true;
@@ -1035,6 +1344,17 @@ fn get_show_table_scroll_position(matches: &clap::ArgMatches, config: &Config) -
     false
 }
 
+fn get_show_exited_processes(matches: &clap::ArgMatches, config: &Config) -> bool {
+    if matches.is_present("show_exited") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(show_exited) = flags.show_exited {
+            return show_exited;
+        }
+    }
+    false
+}
+
 fn get_is_default_process_command(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("process_command") {
         return true;
@@ -1085,6 +1405,28 @@ fn get_network_scale_type(matches: &clap::ArgMatches, config: &Config) -> AxisSc
     AxisScaling::Linear
 }
 
+fn get_proc_root(matches: &clap::ArgMatches, config: &Config) -> PathBuf {
+    if let Some(proc_root) = matches.value_of("proc_root") {
+        return PathBuf::from(proc_root);
+    } else if let Some(flags) = &config.flags {
+        if let Some(proc_root) = &flags.proc_root {
+            return PathBuf::from(proc_root);
+        }
+    }
+    PathBuf::from("/proc")
+}
+
+fn get_sys_root(matches: &clap::ArgMatches, config: &Config) -> PathBuf {
+    if let Some(sys_root) = matches.value_of("sys_root") {
+        return PathBuf::from(sys_root);
+    } else if let Some(flags) = &config.flags {
+        if let Some(sys_root) = &flags.sys_root {
+            return PathBuf::from(sys_root);
+        }
+    }
+    PathBuf::from("/sys")
+}
+
 fn get_network_use_binary_prefix(matches: &clap::ArgMatches, config: &Config) -> bool {
     if matches.is_present("network_use_binary_prefix") {
         return true;
@@ -143,3 +143,35 @@ fn test_invalid_default_widget_count() {
         .failure()
         .stderr(predicate::str::contains("invalid number"));
 }
+
+#[test]
+fn test_invalid_group_by() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_group_by.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid grouping type"));
+}
+
+#[test]
+fn test_invalid_extra_process_columns() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_extra_process_columns.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid extra process column"));
+}
+
+#[test]
+fn test_invalid_collection_interval() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_collection_interval.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "collection intervals to be at least 250 milliseconds",
+        ));
+}